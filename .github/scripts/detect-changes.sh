@@ -16,12 +16,30 @@ for crate in ./crates/*; do
     crate_name=$(basename "$crate")
     echo "Found directory: $crate_name"
     echo "Looking for pattern: crates/$crate_name/"
+    if ! grep -q "^\[package.metadata.component\]" "$crate/Cargo.toml"; then
+      echo "Skipping library crate: $crate_name"
+      continue
+    fi
     if echo "$changed_files" | grep -q "crates/$crate_name/"; then
       echo "Found changes in $crate_name"
       changed_crates+=("$crate_name")
-    else
-      echo "No changes found in $crate_name"
+      continue
+    fi
+    # Components are also republished when the shared WIT dependencies change
+    if grep -q 'path = "\.\./\.\./wit/' "$crate/Cargo.toml" && echo "$changed_files" | grep -q "^wit/"; then
+      echo "Found changes in wit, used by $crate_name"
+      changed_crates+=("$crate_name")
+      continue
     fi
+    # Components are also republished when a workspace library they depend on changes
+    for dependency in $(grep -o 'path = "\.\./[^./"]*"' "$crate/Cargo.toml" | cut -d'/' -f2 | tr -d '"'); do
+      if echo "$changed_files" | grep -q "crates/$dependency/"; then
+        echo "Found changes in $dependency, used by $crate_name"
+        changed_crates+=("$crate_name")
+        continue 2
+      fi
+    done
+    echo "No changes found in $crate_name"
   else
     echo "Not a directory: $crate"
   fi
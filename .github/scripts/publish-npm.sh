@@ -38,7 +38,10 @@ npm install @bytecodealliance/preview2-shim
 
 # Generate README.md
 cd ../..
-wit-bindgen markdown crates/$1/wit/world.wit --html-in-md
+wit="target/wit/$1"
+rm -rf $wit && mkdir -p $wit
+cp crates/$1/wit/*.wit $wit/ && cp -r wit/deps $wit/
+wit-bindgen markdown $wit --world $1 --html-in-md
 mv $1.md npm/$1/README.md
 
 # Transpile component
@@ -1,572 +1,4205 @@
 // Generated by `wit-bindgen` 0.41.0. DO NOT EDIT!
 // Options used:
 //   * runtime_path: "wit_bindgen_rt"
-#[derive(Clone)]
-pub struct Message {
-    pub content: _rt::String,
-    pub channel_id: _rt::String,
-    pub guild_id: Option<_rt::String>,
-}
-impl ::core::fmt::Debug for Message {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("Message")
-            .field("content", &self.content)
-            .field("channel-id", &self.channel_id)
-            .field("guild-id", &self.guild_id)
-            .finish()
-    }
-}
-#[derive(Clone)]
-pub struct Webhook {
-    pub id: _rt::String,
-    pub token: _rt::String,
-    pub url: _rt::String,
-}
-impl ::core::fmt::Debug for Webhook {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("Webhook")
-            .field("id", &self.id)
-            .field("token", &self.token)
-            .field("url", &self.url)
-            .finish()
-    }
-}
-#[derive(Clone)]
-pub struct User {
-    pub id: _rt::String,
-    pub username: _rt::String,
-    pub discriminator: _rt::String,
-    pub avatar: Option<_rt::String>,
-}
-impl ::core::fmt::Debug for User {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("User")
-            .field("id", &self.id)
-            .field("username", &self.username)
-            .field("discriminator", &self.discriminator)
-            .field("avatar", &self.avatar)
-            .finish()
-    }
-}
-#[derive(Clone)]
-pub struct Channel {
-    pub id: _rt::String,
-    pub name: _rt::String,
-    pub ty: u32,
-    pub guild_id: Option<_rt::String>,
-}
-impl ::core::fmt::Debug for Channel {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("Channel")
-            .field("id", &self.id)
-            .field("name", &self.name)
-            .field("ty", &self.ty)
-            .field("guild-id", &self.guild_id)
-            .finish()
-    }
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn _export_create_webhook_cabi<T: Guest>(
-    arg0: *mut u8,
-    arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
-    arg4: *mut u8,
-    arg5: usize,
-) -> *mut u8 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let len2 = arg5;
-    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
-    let result3 = T::create_webhook(
-        _rt::string_lift(bytes0),
-        _rt::string_lift(bytes1),
-        _rt::string_lift(bytes2),
-    );
-    let ptr4 = (&raw mut _RET_AREA.0).cast::<u8>();
-    let Webhook { id: id5, token: token5, url: url5 } = result3;
-    let vec6 = (id5.into_bytes()).into_boxed_slice();
-    let ptr6 = vec6.as_ptr().cast::<u8>();
-    let len6 = vec6.len();
-    ::core::mem::forget(vec6);
-    *ptr4.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
-    *ptr4.add(0).cast::<*mut u8>() = ptr6.cast_mut();
-    let vec7 = (token5.into_bytes()).into_boxed_slice();
-    let ptr7 = vec7.as_ptr().cast::<u8>();
-    let len7 = vec7.len();
-    ::core::mem::forget(vec7);
-    *ptr4.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
-    *ptr4.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr7
-        .cast_mut();
-    let vec8 = (url5.into_bytes()).into_boxed_slice();
-    let ptr8 = vec8.as_ptr().cast::<u8>();
-    let len8 = vec8.len();
-    ::core::mem::forget(vec8);
-    *ptr4.add(5 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
-    *ptr4.add(4 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr8
-        .cast_mut();
-    ptr4
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn __post_return_create_webhook<T: Guest>(arg0: *mut u8) {
-    let l0 = *arg0.add(0).cast::<*mut u8>();
-    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l0, l1, 1);
-    let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-    let l3 = *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l2, l3, 1);
-    let l4 = *arg0.add(4 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-    let l5 = *arg0.add(5 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l4, l5, 1);
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn _export_delete_webhook_cabi<T: Guest>(
-    arg0: *mut u8,
-    arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
-    arg4: *mut u8,
-    arg5: usize,
-) -> i32 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let len2 = arg5;
-    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
-    let result3 = T::delete_webhook(
-        _rt::string_lift(bytes0),
-        _rt::string_lift(bytes1),
-        _rt::string_lift(bytes2),
-    );
-    match result3 {
-        true => 1,
-        false => 0,
-    }
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn _export_delete_message_cabi<T: Guest>(
-    arg0: *mut u8,
-    arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
-    arg4: *mut u8,
-    arg5: usize,
-) -> i32 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let len2 = arg5;
-    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
-    let result3 = T::delete_message(
-        _rt::string_lift(bytes0),
-        _rt::string_lift(bytes1),
-        _rt::string_lift(bytes2),
-    );
-    match result3 {
-        true => 1,
-        false => 0,
-    }
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn _export_edit_message_cabi<T: Guest>(
-    arg0: *mut u8,
-    arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
-    arg4: *mut u8,
-    arg5: usize,
-    arg6: *mut u8,
-    arg7: usize,
-) -> i32 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let len2 = arg5;
-    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
-    let len3 = arg7;
-    let bytes3 = _rt::Vec::from_raw_parts(arg6.cast(), len3, len3);
-    let result4 = T::edit_message(
-        _rt::string_lift(bytes0),
-        _rt::string_lift(bytes1),
-        _rt::string_lift(bytes2),
-        _rt::string_lift(bytes3),
-    );
-    match result4 {
-        true => 1,
-        false => 0,
-    }
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn _export_get_channel_cabi<T: Guest>(
-    arg0: *mut u8,
-    arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
-) -> *mut u8 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let result2 = T::get_channel(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
-    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
-    let Channel { id: id4, name: name4, ty: ty4, guild_id: guild_id4 } = result2;
-    let vec5 = (id4.into_bytes()).into_boxed_slice();
-    let ptr5 = vec5.as_ptr().cast::<u8>();
-    let len5 = vec5.len();
-    ::core::mem::forget(vec5);
-    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
-    *ptr3.add(0).cast::<*mut u8>() = ptr5.cast_mut();
-    let vec6 = (name4.into_bytes()).into_boxed_slice();
-    let ptr6 = vec6.as_ptr().cast::<u8>();
-    let len6 = vec6.len();
-    ::core::mem::forget(vec6);
-    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
-    *ptr3.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr6
-        .cast_mut();
-    *ptr3.add(4 * ::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(ty4);
-    match guild_id4 {
-        Some(e) => {
-            *ptr3.add(5 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
-                as u8;
-            let vec7 = (e.into_bytes()).into_boxed_slice();
-            let ptr7 = vec7.as_ptr().cast::<u8>();
-            let len7 = vec7.len();
-            ::core::mem::forget(vec7);
-            *ptr3.add(7 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
-            *ptr3.add(6 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr7
-                .cast_mut();
-        }
-        None => {
-            *ptr3.add(5 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
-                as u8;
-        }
-    };
-    ptr3
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn __post_return_get_channel<T: Guest>(arg0: *mut u8) {
-    let l0 = *arg0.add(0).cast::<*mut u8>();
-    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l0, l1, 1);
-    let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-    let l3 = *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l2, l3, 1);
-    let l4 = i32::from(*arg0.add(5 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
-    match l4 {
-        0 => {}
-        _ => {
-            let l5 = *arg0
-                .add(6 * ::core::mem::size_of::<*const u8>())
-                .cast::<*mut u8>();
-            let l6 = *arg0.add(7 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
-            _rt::cabi_dealloc(l5, l6, 1);
+#[rustfmt::skip]
+#[allow(dead_code, clippy::all)]
+pub mod wasi {
+    pub mod config {
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+        pub mod store {
+            #[used]
+            #[doc(hidden)]
+            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            use super::super::super::_rt;
+            /// An error type that encapsulates the different errors that can occur fetching configuration values.
+            #[derive(Clone)]
+            pub enum Error {
+                /// This indicates an error from an "upstream" config source.
+                /// As this could be almost _anything_ (such as Vault, Kubernetes ConfigMaps, KeyValue buckets, etc),
+                /// the error message is a string.
+                Upstream(_rt::String),
+                /// This indicates an error from an I/O operation.
+                /// As this could be almost _anything_ (such as a file read, network connection, etc),
+                /// the error message is a string.
+                /// Depending on how this ends up being consumed,
+                /// we may consider moving this to use the `wasi:io/error` type instead.
+                /// For simplicity right now in supporting multiple implementations, it is being left as a string.
+                Io(_rt::String),
+            }
+            impl ::core::fmt::Debug for Error {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        Error::Upstream(e) => {
+                            f.debug_tuple("Error::Upstream").field(e).finish()
+                        }
+                        Error::Io(e) => f.debug_tuple("Error::Io").field(e).finish(),
+                    }
+                }
+            }
+            impl ::core::fmt::Display for Error {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    write!(f, "{:?}", self)
+                }
+            }
+            impl std::error::Error for Error {}
+            #[allow(unused_unsafe, clippy::all)]
+            /// Gets a configuration value of type `string` associated with the `key`.
+            ///
+            /// The value is returned as an `option<string>`. If the key is not found,
+            /// `Ok(none)` is returned. If an error occurs, an `Err(error)` is returned.
+            pub fn get(key: &str) -> Result<Option<_rt::String>, Error> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 4
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let vec0 = key;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "wasi:config/store@0.2.0-draft")]
+                    unsafe extern "C" {
+                        #[link_name = "get"]
+                        fn wit_import2(_: *mut u8, _: usize, _: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import2(_: *mut u8, _: usize, _: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import2(ptr0.cast_mut(), len0, ptr1) };
+                    let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+                    let result16 = match l3 {
+                        0 => {
+                            let e = {
+                                let l4 = i32::from(
+                                    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                );
+                                match l4 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l5 = *ptr1
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l6 = *ptr1
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len7 = l6;
+                                            let bytes7 = _rt::Vec::from_raw_parts(
+                                                l5.cast(),
+                                                len7,
+                                                len7,
+                                            );
+                                            _rt::string_lift(bytes7)
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                }
+                            };
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l8 = i32::from(
+                                    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                );
+                                let v15 = match l8 {
+                                    0 => {
+                                        let e15 = {
+                                            let l9 = *ptr1
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l10 = *ptr1
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len11 = l10;
+                                            let bytes11 = _rt::Vec::from_raw_parts(
+                                                l9.cast(),
+                                                len11,
+                                                len11,
+                                            );
+                                            _rt::string_lift(bytes11)
+                                        };
+                                        Error::Upstream(e15)
+                                    }
+                                    n => {
+                                        debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                        let e15 = {
+                                            let l12 = *ptr1
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l13 = *ptr1
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len14 = l13;
+                                            let bytes14 = _rt::Vec::from_raw_parts(
+                                                l12.cast(),
+                                                len14,
+                                                len14,
+                                            );
+                                            _rt::string_lift(bytes14)
+                                        };
+                                        Error::Io(e15)
+                                    }
+                                };
+                                v15
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result16
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Gets a list of configuration key-value pairs of type `string`.
+            ///
+            /// If an error occurs, an `Err(error)` is returned.
+            pub fn get_all() -> Result<_rt::Vec<(_rt::String, _rt::String)>, Error> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 4
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "wasi:config/store@0.2.0-draft")]
+                    unsafe extern "C" {
+                        #[link_name = "get-all"]
+                        fn wit_import1(_: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                    let result20 = match l2 {
+                        0 => {
+                            let e = {
+                                let l3 = *ptr0
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l4 = *ptr0
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let base11 = l3;
+                                let len11 = l4;
+                                let mut result11 = _rt::Vec::with_capacity(len11);
+                                for i in 0..len11 {
+                                    let base = base11
+                                        .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                                    let e11 = {
+                                        let l5 = *base.add(0).cast::<*mut u8>();
+                                        let l6 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len7 = l6;
+                                        let bytes7 = _rt::Vec::from_raw_parts(
+                                            l5.cast(),
+                                            len7,
+                                            len7,
+                                        );
+                                        let l8 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l9 = *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len10 = l9;
+                                        let bytes10 = _rt::Vec::from_raw_parts(
+                                            l8.cast(),
+                                            len10,
+                                            len10,
+                                        );
+                                        (_rt::string_lift(bytes7), _rt::string_lift(bytes10))
+                                    };
+                                    result11.push(e11);
+                                }
+                                _rt::cabi_dealloc(
+                                    base11,
+                                    len11 * (4 * ::core::mem::size_of::<*const u8>()),
+                                    ::core::mem::size_of::<*const u8>(),
+                                );
+                                result11
+                            };
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l12 = i32::from(
+                                    *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                );
+                                let v19 = match l12 {
+                                    0 => {
+                                        let e19 = {
+                                            let l13 = *ptr0
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l14 = *ptr0
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len15 = l14;
+                                            let bytes15 = _rt::Vec::from_raw_parts(
+                                                l13.cast(),
+                                                len15,
+                                                len15,
+                                            );
+                                            _rt::string_lift(bytes15)
+                                        };
+                                        Error::Upstream(e19)
+                                    }
+                                    n => {
+                                        debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                        let e19 = {
+                                            let l16 = *ptr0
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l17 = *ptr0
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len18 = l17;
+                                            let bytes18 = _rt::Vec::from_raw_parts(
+                                                l16.cast(),
+                                                len18,
+                                                len18,
+                                            );
+                                            _rt::string_lift(bytes18)
+                                        };
+                                        Error::Io(e19)
+                                    }
+                                };
+                                v19
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result20
+                }
+            }
         }
     }
 }
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn _export_get_user_cabi<T: Guest>(
-    arg0: *mut u8,
-    arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
-) -> *mut u8 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let result2 = T::get_user(_rt::string_lift(bytes0), _rt::string_lift(bytes1));
-    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
-    let User {
-        id: id4,
-        username: username4,
-        discriminator: discriminator4,
-        avatar: avatar4,
-    } = result2;
-    let vec5 = (id4.into_bytes()).into_boxed_slice();
-    let ptr5 = vec5.as_ptr().cast::<u8>();
-    let len5 = vec5.len();
-    ::core::mem::forget(vec5);
-    *ptr3.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
-    *ptr3.add(0).cast::<*mut u8>() = ptr5.cast_mut();
-    let vec6 = (username4.into_bytes()).into_boxed_slice();
-    let ptr6 = vec6.as_ptr().cast::<u8>();
-    let len6 = vec6.len();
-    ::core::mem::forget(vec6);
-    *ptr3.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
-    *ptr3.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr6
-        .cast_mut();
-    let vec7 = (discriminator4.into_bytes()).into_boxed_slice();
-    let ptr7 = vec7.as_ptr().cast::<u8>();
-    let len7 = vec7.len();
-    ::core::mem::forget(vec7);
-    *ptr3.add(5 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
-    *ptr3.add(4 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr7
-        .cast_mut();
-    match avatar4 {
-        Some(e) => {
-            *ptr3.add(6 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (1i32)
-                as u8;
-            let vec8 = (e.into_bytes()).into_boxed_slice();
-            let ptr8 = vec8.as_ptr().cast::<u8>();
-            let len8 = vec8.len();
-            ::core::mem::forget(vec8);
-            *ptr3.add(8 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
-            *ptr3.add(7 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr8
-                .cast_mut();
-        }
-        None => {
-            *ptr3.add(6 * ::core::mem::size_of::<*const u8>()).cast::<u8>() = (0i32)
-                as u8;
-        }
-    };
-    ptr3
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn __post_return_get_user<T: Guest>(arg0: *mut u8) {
-    let l0 = *arg0.add(0).cast::<*mut u8>();
-    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l0, l1, 1);
-    let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-    let l3 = *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l2, l3, 1);
-    let l4 = *arg0.add(4 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-    let l5 = *arg0.add(5 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l4, l5, 1);
-    let l6 = i32::from(*arg0.add(6 * ::core::mem::size_of::<*const u8>()).cast::<u8>());
-    match l6 {
-        0 => {}
-        _ => {
-            let l7 = *arg0
-                .add(7 * ::core::mem::size_of::<*const u8>())
-                .cast::<*mut u8>();
-            let l8 = *arg0.add(8 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
-            _rt::cabi_dealloc(l7, l8, 1);
+#[rustfmt::skip]
+#[allow(dead_code, clippy::all)]
+pub mod wassemble {
+    pub mod discord {
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+        pub mod types {
+            #[used]
+            #[doc(hidden)]
+            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            use super::super::super::_rt;
+            /// How failed requests are retried
+            ///
+            /// Rate-limited responses are retried after the `retry_after` Discord sends, other
+            /// retryable failures with exponential backoff.
+            #[derive(Clone)]
+            pub struct RetryConfig {
+                /// Total number of attempts including the first one, 1 disables retries
+                pub max_attempts: u32,
+                /// Delay before the first retry in milliseconds, doubled for every further retry
+                pub initial_delay_ms: u32,
+                /// Longest delay to wait in milliseconds; a longer `retry_after` is returned as `rate-limited`
+                pub max_delay_ms: u32,
+                /// Statuses retried with backoff; POST and PATCH requests are only retried for 429
+                pub retry_statuses: _rt::Vec<u16>,
+            }
+            impl ::core::fmt::Debug for RetryConfig {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("RetryConfig")
+                        .field("max-attempts", &self.max_attempts)
+                        .field("initial-delay-ms", &self.initial_delay_ms)
+                        .field("max-delay-ms", &self.max_delay_ms)
+                        .field("retry-statuses", &self.retry_statuses)
+                        .finish()
+                }
+            }
+            /// Settings a client is constructed with
+            #[derive(Clone)]
+            pub struct Config {
+                /// Bot token, sent as `Authorization: Bot <token>`
+                /// When empty, builds with the `wasi-config` feature read the `discord_bot_token` key of
+                /// `wasi:config/store` instead
+                pub token: _rt::String,
+                /// API base URL, defaults to https://discord.com/api/v10
+                pub base_url: Option<_rt::String>,
+                /// Value of the `User-Agent` header, defaults to "DiscordBot (<repository>, <version>)"
+                pub user_agent: Option<_rt::String>,
+                /// Timeout for establishing a connection, in milliseconds
+                pub connect_timeout_ms: Option<u32>,
+                /// How failed requests are retried, defaults to 3 attempts for 429, 502, 503 and 504
+                pub retry: Option<RetryConfig>,
+            }
+            impl ::core::fmt::Debug for Config {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Config")
+                        .field("token", &self.token)
+                        .field("base-url", &self.base_url)
+                        .field("user-agent", &self.user_agent)
+                        .field("connect-timeout-ms", &self.connect_timeout_ms)
+                        .field("retry", &self.retry)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub struct Message {
+                pub content: _rt::String,
+                pub channel_id: _rt::String,
+                pub guild_id: Option<_rt::String>,
+            }
+            impl ::core::fmt::Debug for Message {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Message")
+                        .field("content", &self.content)
+                        .field("channel-id", &self.channel_id)
+                        .field("guild-id", &self.guild_id)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub struct Webhook {
+                pub id: _rt::String,
+                pub token: _rt::String,
+                pub url: _rt::String,
+            }
+            impl ::core::fmt::Debug for Webhook {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Webhook")
+                        .field("id", &self.id)
+                        .field("token", &self.token)
+                        .field("url", &self.url)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub struct User {
+                pub id: _rt::String,
+                pub username: _rt::String,
+                pub discriminator: _rt::String,
+                pub avatar: Option<_rt::String>,
+            }
+            impl ::core::fmt::Debug for User {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("User")
+                        .field("id", &self.id)
+                        .field("username", &self.username)
+                        .field("discriminator", &self.discriminator)
+                        .field("avatar", &self.avatar)
+                        .finish()
+                }
+            }
+            /// A message as returned by the Discord API
+            #[derive(Clone)]
+            pub struct ChannelMessage {
+                pub id: _rt::String,
+                pub channel_id: _rt::String,
+                pub author: User,
+                pub content: _rt::String,
+                /// ISO8601 timestamp of when the message was sent
+                pub timestamp: _rt::String,
+                /// ISO8601 timestamp of the last edit, if the message was edited
+                pub edited_timestamp: Option<_rt::String>,
+            }
+            impl ::core::fmt::Debug for ChannelMessage {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("ChannelMessage")
+                        .field("id", &self.id)
+                        .field("channel-id", &self.channel_id)
+                        .field("author", &self.author)
+                        .field("content", &self.content)
+                        .field("timestamp", &self.timestamp)
+                        .field("edited-timestamp", &self.edited_timestamp)
+                        .finish()
+                }
+            }
+            #[derive(Clone)]
+            pub struct Channel {
+                pub id: _rt::String,
+                pub name: _rt::String,
+                pub ty: u32,
+                pub guild_id: Option<_rt::String>,
+            }
+            impl ::core::fmt::Debug for Channel {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Channel")
+                        .field("id", &self.id)
+                        .field("name", &self.name)
+                        .field("ty", &self.ty)
+                        .field("guild-id", &self.guild_id)
+                        .finish()
+                }
+            }
+            /// Error payload returned by the Discord API alongside a non-success status
+            #[derive(Clone)]
+            pub struct ApiError {
+                /// HTTP status code of the response
+                pub status: u16,
+                /// Discord's JSON error code, e.g. 10003 (unknown channel) or 50013 (missing permissions)
+                pub code: u32,
+                /// Human-readable error message
+                pub message: _rt::String,
+                /// The nested `errors` object of form body validation failures, as JSON
+                pub errors: Option<_rt::String>,
+            }
+            impl ::core::fmt::Debug for ApiError {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("ApiError")
+                        .field("status", &self.status)
+                        .field("code", &self.code)
+                        .field("message", &self.message)
+                        .field("errors", &self.errors)
+                        .finish()
+                }
+            }
+            /// Body and headers of a 429 Too Many Requests response
+            #[derive(Clone)]
+            pub struct RateLimit {
+                /// The `message` field of the error body
+                pub message: _rt::String,
+                /// The `retry_after` field of the error body, in milliseconds
+                pub retry_after_ms: u64,
+                /// Whether the global rate limit was exceeded rather than a per-route one
+                pub global: bool,
+                /// Value of `x-ratelimit-scope`: "user", "global" or "shared"
+                pub scope: Option<_rt::String>,
+                /// Value of `x-ratelimit-bucket`
+                pub bucket: Option<_rt::String>,
+            }
+            impl ::core::fmt::Debug for RateLimit {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("RateLimit")
+                        .field("message", &self.message)
+                        .field("retry-after-ms", &self.retry_after_ms)
+                        .field("global", &self.global)
+                        .field("scope", &self.scope)
+                        .field("bucket", &self.bucket)
+                        .finish()
+                }
+            }
+            /// Rate limit counters of the most recent response
+            #[derive(Clone)]
+            pub struct RateLimitStatus {
+                /// Value of `x-ratelimit-bucket`, shared by routes with a common limit
+                pub bucket: Option<_rt::String>,
+                /// Value of `x-ratelimit-limit`
+                pub limit: u32,
+                /// Value of `x-ratelimit-remaining`
+                pub remaining: u32,
+                /// Value of `x-ratelimit-reset-after`, in milliseconds
+                pub reset_after_ms: u64,
+            }
+            impl ::core::fmt::Debug for RateLimitStatus {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("RateLimitStatus")
+                        .field("bucket", &self.bucket)
+                        .field("limit", &self.limit)
+                        .field("remaining", &self.remaining)
+                        .field("reset-after-ms", &self.reset_after_ms)
+                        .finish()
+                }
+            }
+            /// Error types that can occur during Discord API operations
+            #[derive(Clone)]
+            pub enum DiscordError {
+                /// Failed to send HTTP request
+                RequestFailed(_rt::String),
+                /// Failed to get response body
+                ResponseBodyError(_rt::String),
+                /// Failed to parse response as UTF-8
+                Utf8Error(_rt::String),
+                /// Failed to parse JSON response
+                JsonParseError(_rt::String),
+                /// Failed to read a value from `wasi:config/store`
+                ConfigError(_rt::String),
+                /// The API responded with a non-success status
+                ApiError(ApiError),
+                /// A rate limit was exceeded and retrying would take longer than allowed
+                RateLimited(RateLimit),
+            }
+            impl ::core::fmt::Debug for DiscordError {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        DiscordError::RequestFailed(e) => {
+                            f.debug_tuple("DiscordError::RequestFailed")
+                                .field(e)
+                                .finish()
+                        }
+                        DiscordError::ResponseBodyError(e) => {
+                            f.debug_tuple("DiscordError::ResponseBodyError")
+                                .field(e)
+                                .finish()
+                        }
+                        DiscordError::Utf8Error(e) => {
+                            f.debug_tuple("DiscordError::Utf8Error").field(e).finish()
+                        }
+                        DiscordError::JsonParseError(e) => {
+                            f.debug_tuple("DiscordError::JsonParseError")
+                                .field(e)
+                                .finish()
+                        }
+                        DiscordError::ConfigError(e) => {
+                            f.debug_tuple("DiscordError::ConfigError").field(e).finish()
+                        }
+                        DiscordError::ApiError(e) => {
+                            f.debug_tuple("DiscordError::ApiError").field(e).finish()
+                        }
+                        DiscordError::RateLimited(e) => {
+                            f.debug_tuple("DiscordError::RateLimited").field(e).finish()
+                        }
+                    }
+                }
+            }
+            impl ::core::fmt::Display for DiscordError {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    write!(f, "{:?}", self)
+                }
+            }
+            impl std::error::Error for DiscordError {}
         }
     }
 }
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn _export_send_message_cabi<T: Guest>(
-    arg0: *mut u8,
-    arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
-    arg4: *mut u8,
-    arg5: usize,
-    arg6: i32,
-    arg7: *mut u8,
-    arg8: usize,
-) -> *mut u8 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let len2 = arg5;
-    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
-    let result4 = T::send_message(
-        _rt::string_lift(bytes0),
-        Message {
-            content: _rt::string_lift(bytes1),
-            channel_id: _rt::string_lift(bytes2),
-            guild_id: match arg6 {
-                0 => None,
-                1 => {
-                    let e = {
-                        let len3 = arg8;
-                        let bytes3 = _rt::Vec::from_raw_parts(arg7.cast(), len3, len3);
-                        _rt::string_lift(bytes3)
+#[rustfmt::skip]
+#[allow(dead_code, clippy::all)]
+pub mod exports {
+    pub mod wassemble {
+        pub mod discord {
+            #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+            pub mod api {
+                #[used]
+                #[doc(hidden)]
+                static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type Config = super::super::super::super::wassemble::discord::types::Config;
+                pub type Message = super::super::super::super::wassemble::discord::types::Message;
+                pub type Webhook = super::super::super::super::wassemble::discord::types::Webhook;
+                pub type User = super::super::super::super::wassemble::discord::types::User;
+                pub type ChannelMessage = super::super::super::super::wassemble::discord::types::ChannelMessage;
+                pub type Channel = super::super::super::super::wassemble::discord::types::Channel;
+                pub type RateLimitStatus = super::super::super::super::wassemble::discord::types::RateLimitStatus;
+                pub type DiscordError = super::super::super::super::wassemble::discord::types::DiscordError;
+                /// A Discord API client holding its bot token and connection settings
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct Client {
+                    handle: _rt::Resource<Client>,
+                }
+                type _ClientRep<T> = Option<T>;
+                impl Client {
+                    /// Creates a new resource from the specified representation.
+                    ///
+                    /// This function will create a new resource handle by moving `val` onto
+                    /// the heap and then passing that heap pointer to the component model to
+                    /// create a handle. The owned handle is then returned as `Client`.
+                    pub fn new<T: GuestClient>(val: T) -> Self {
+                        Self::type_guard::<T>();
+                        let val: _ClientRep<T> = Some(val);
+                        let ptr: *mut _ClientRep<T> = _rt::Box::into_raw(
+                            _rt::Box::new(val),
+                        );
+                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
+                    }
+                    /// Gets access to the underlying `T` which represents this resource.
+                    pub fn get<T: GuestClient>(&self) -> &T {
+                        let ptr = unsafe { &*self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    /// Gets mutable access to the underlying `T` which represents this
+                    /// resource.
+                    pub fn get_mut<T: GuestClient>(&mut self) -> &mut T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_mut().unwrap()
+                    }
+                    /// Consumes this resource and returns the underlying `T`.
+                    pub fn into_inner<T: GuestClient>(self) -> T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.take().unwrap()
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn from_handle(handle: u32) -> Self {
+                        Self {
+                            handle: unsafe { _rt::Resource::from_handle(handle) },
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub fn take_handle(&self) -> u32 {
+                        _rt::Resource::take_handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    pub fn handle(&self) -> u32 {
+                        _rt::Resource::handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    fn type_guard<T: 'static>() {
+                        use core::any::TypeId;
+                        static mut LAST_TYPE: Option<TypeId> = None;
+                        unsafe {
+                            assert!(! cfg!(target_feature = "atomics"));
+                            let id = TypeId::of::<T>();
+                            match LAST_TYPE {
+                                Some(ty) => {
+                                    assert!(
+                                        ty == id, "cannot use two types with this resource type"
+                                    )
+                                }
+                                None => LAST_TYPE = Some(id),
+                            }
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
+                        Self::type_guard::<T>();
+                        let _ = unsafe {
+                            _rt::Box::from_raw(handle as *mut _ClientRep<T>)
+                        };
+                    }
+                    fn as_ptr<T: GuestClient>(&self) -> *mut _ClientRep<T> {
+                        Client::type_guard::<T>();
+                        T::_resource_rep(self.handle()).cast()
+                    }
+                }
+                /// A borrowed version of [`Client`] which represents a borrowed value
+                /// with the lifetime `'a`.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct ClientBorrow<'a> {
+                    rep: *mut u8,
+                    _marker: core::marker::PhantomData<&'a Client>,
+                }
+                impl<'a> ClientBorrow<'a> {
+                    #[doc(hidden)]
+                    pub unsafe fn lift(rep: usize) -> Self {
+                        Self {
+                            rep: rep as *mut u8,
+                            _marker: core::marker::PhantomData,
+                        }
+                    }
+                    /// Gets access to the underlying `T` in this resource.
+                    pub fn get<T: GuestClient>(&self) -> &T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    fn as_ptr<T: 'static>(&self) -> *mut _ClientRep<T> {
+                        Client::type_guard::<T>();
+                        self.rep.cast()
+                    }
+                }
+                unsafe impl _rt::WasmResource for Client {
+                    #[inline]
+                    unsafe fn drop(_handle: u32) {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unreachable!();
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]wassemble:discord/api@0.2.0"
+                            )]
+                            unsafe extern "C" {
+                                #[link_name = "[resource-drop]client"]
+                                fn drop(_: u32);
+                            }
+                            unsafe { drop(_handle) };
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_constructor_client_cabi<T: GuestClient>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                    arg2: i32,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: i32,
+                    arg6: *mut u8,
+                    arg7: usize,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: i32,
+                    arg13: i32,
+                    arg14: *mut u8,
+                    arg15: usize,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let result4 = Client::new(
+                        T::new(super::super::super::super::wassemble::discord::types::Config {
+                            token: _rt::string_lift(bytes0),
+                            base_url: match arg2 {
+                                0 => None,
+                                1 => {
+                                    let e = {
+                                        let len1 = arg4;
+                                        let bytes1 = _rt::Vec::from_raw_parts(
+                                            arg3.cast(),
+                                            len1,
+                                            len1,
+                                        );
+                                        _rt::string_lift(bytes1)
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            user_agent: match arg5 {
+                                0 => None,
+                                1 => {
+                                    let e = {
+                                        let len2 = arg7;
+                                        let bytes2 = _rt::Vec::from_raw_parts(
+                                            arg6.cast(),
+                                            len2,
+                                            len2,
+                                        );
+                                        _rt::string_lift(bytes2)
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            connect_timeout_ms: match arg8 {
+                                0 => None,
+                                1 => {
+                                    let e = arg9 as u32;
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            retry: match arg10 {
+                                0 => None,
+                                1 => {
+                                    let e = {
+                                        let len3 = arg15;
+                                        super::super::super::super::wassemble::discord::types::RetryConfig {
+                                            max_attempts: arg11 as u32,
+                                            initial_delay_ms: arg12 as u32,
+                                            max_delay_ms: arg13 as u32,
+                                            retry_statuses: _rt::Vec::from_raw_parts(
+                                                arg14.cast(),
+                                                len3,
+                                                len3,
+                                            ),
+                                        }
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                        }),
+                    );
+                    (result4).take_handle() as i32
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_create_webhook_cabi<T: GuestClient>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let len1 = arg4;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                    let result2 = T::create_webhook(
+                        unsafe { ClientBorrow::lift(arg0 as u32 as usize) }.get(),
+                        _rt::string_lift(bytes0),
+                        _rt::string_lift(bytes1),
+                    );
+                    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result2 {
+                        Ok(e) => {
+                            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+                            let super::super::super::super::wassemble::discord::types::Webhook {
+                                id: id4,
+                                token: token4,
+                                url: url4,
+                            } = e;
+                            let vec5 = (id4.into_bytes()).into_boxed_slice();
+                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                            let len5 = vec5.len();
+                            ::core::mem::forget(vec5);
+                            *ptr3
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len5;
+                            *ptr3.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                            let vec6 = (token4.into_bytes()).into_boxed_slice();
+                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                            let len6 = vec6.len();
+                            ::core::mem::forget(vec6);
+                            *ptr3
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len6;
+                            *ptr3
+                                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr6.cast_mut();
+                            let vec7 = (url4.into_bytes()).into_boxed_slice();
+                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                            let len7 = vec7.len();
+                            ::core::mem::forget(vec7);
+                            *ptr3
+                                .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len7;
+                            *ptr3
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr7.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::wassemble::discord::types::DiscordError as V20;
+                            match e {
+                                V20::RequestFailed(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len8;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                V20::ResponseBodyError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len9;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                V20::Utf8Error(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (2i32) as u8;
+                                    let vec10 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len10;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                V20::JsonParseError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (3i32) as u8;
+                                    let vec11 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len11;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                V20::ConfigError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (4i32) as u8;
+                                    let vec12 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len12;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr12.cast_mut();
+                                }
+                                V20::ApiError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (5i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::ApiError {
+                                        status: status13,
+                                        code: code13,
+                                        message: message13,
+                                        errors: errors13,
+                                    } = e;
+                                    *ptr3.add(16).cast::<u16>() = (_rt::as_i32(status13))
+                                        as u16;
+                                    *ptr3.add(20).cast::<i32>() = _rt::as_i32(code13);
+                                    let vec14 = (message13.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr3
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len14;
+                                    *ptr3.add(24).cast::<*mut u8>() = ptr14.cast_mut();
+                                    match errors13 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec15 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr15 = vec15.as_ptr().cast::<u8>();
+                                            let len15 = vec15.len();
+                                            ::core::mem::forget(vec15);
+                                            *ptr3
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len15;
+                                            *ptr3
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr15.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V20::RateLimited(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (6i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::RateLimit {
+                                        message: message16,
+                                        retry_after_ms: retry_after_ms16,
+                                        global: global16,
+                                        scope: scope16,
+                                        bucket: bucket16,
+                                    } = e;
+                                    let vec17 = (message16.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len17;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr17.cast_mut();
+                                    *ptr3
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i64>() = _rt::as_i64(retry_after_ms16);
+                                    *ptr3
+                                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (match global16 {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
+                                    match scope16 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec18 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr18 = vec18.as_ptr().cast::<u8>();
+                                            let len18 = vec18.len();
+                                            ::core::mem::forget(vec18);
+                                            *ptr3
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len18;
+                                            *ptr3
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr18.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match bucket16 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec19 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr19 = vec19.as_ptr().cast::<u8>();
+                                            let len19 = vec19.len();
+                                            ::core::mem::forget(vec19);
+                                            *ptr3
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len19;
+                                            *ptr3
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr19.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                        }
                     };
-                    Some(e)
-                }
-                _ => _rt::invalid_enum_discriminant(),
-            },
-        },
-    );
-    let ptr5 = (&raw mut _RET_AREA.0).cast::<u8>();
-    let vec6 = (result4.into_bytes()).into_boxed_slice();
-    let ptr6 = vec6.as_ptr().cast::<u8>();
-    let len6 = vec6.len();
-    ::core::mem::forget(vec6);
-    *ptr5.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
-    *ptr5.add(0).cast::<*mut u8>() = ptr6.cast_mut();
-    ptr5
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn __post_return_send_message<T: Guest>(arg0: *mut u8) {
-    let l0 = *arg0.add(0).cast::<*mut u8>();
-    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l0, l1, 1);
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn _export_send_webhook_message_cabi<T: Guest>(
-    arg0: *mut u8,
-    arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
-    arg4: *mut u8,
-    arg5: usize,
-    arg6: *mut u8,
-    arg7: usize,
-    arg8: *mut u8,
-    arg9: usize,
-) -> *mut u8 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let len2 = arg5;
-    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
-    let len3 = arg7;
-    let bytes3 = _rt::Vec::from_raw_parts(arg6.cast(), len3, len3);
-    let len4 = arg9;
-    let bytes4 = _rt::Vec::from_raw_parts(arg8.cast(), len4, len4);
-    let result5 = T::send_webhook_message(
-        _rt::string_lift(bytes0),
-        Webhook {
-            id: _rt::string_lift(bytes1),
-            token: _rt::string_lift(bytes2),
-            url: _rt::string_lift(bytes3),
-        },
-        _rt::string_lift(bytes4),
-    );
-    let ptr6 = (&raw mut _RET_AREA.0).cast::<u8>();
-    let vec7 = (result5.into_bytes()).into_boxed_slice();
-    let ptr7 = vec7.as_ptr().cast::<u8>();
-    let len7 = vec7.len();
-    ::core::mem::forget(vec7);
-    *ptr6.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
-    *ptr6.add(0).cast::<*mut u8>() = ptr7.cast_mut();
-    ptr6
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn __post_return_send_webhook_message<T: Guest>(arg0: *mut u8) {
-    let l0 = *arg0.add(0).cast::<*mut u8>();
-    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l0, l1, 1);
-}
-pub trait Guest {
-    fn create_webhook(
-        token: _rt::String,
-        channel_id: _rt::String,
-        name: _rt::String,
-    ) -> Webhook;
-    fn delete_webhook(
-        token: _rt::String,
-        webhook_id: _rt::String,
-        webhook_token: _rt::String,
-    ) -> bool;
-    fn delete_message(
-        token: _rt::String,
-        channel_id: _rt::String,
-        message_id: _rt::String,
-    ) -> bool;
-    fn edit_message(
-        token: _rt::String,
-        channel_id: _rt::String,
-        message_id: _rt::String,
-        content: _rt::String,
-    ) -> bool;
-    fn get_channel(token: _rt::String, channel_id: _rt::String) -> Channel;
-    fn get_user(token: _rt::String, user_id: _rt::String) -> User;
-    fn send_message(token: _rt::String, message: Message) -> _rt::String;
-    fn send_webhook_message(
-        token: _rt::String,
-        webhook: Webhook,
-        content: _rt::String,
-    ) -> _rt::String;
-}
-#[doc(hidden)]
-macro_rules! __export_world_discord_cabi {
-    ($ty:ident with_types_in $($path_to_types:tt)*) => {
-        const _ : () = { #[unsafe (export_name = "create-webhook")] unsafe extern "C" fn
-        export_create_webhook(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3 :
-        usize, arg4 : * mut u8, arg5 : usize,) -> * mut u8 { unsafe {
-        $($path_to_types)*:: _export_create_webhook_cabi::<$ty > (arg0, arg1, arg2, arg3,
-        arg4, arg5) } } #[unsafe (export_name = "cabi_post_create-webhook")] unsafe
-        extern "C" fn _post_return_create_webhook(arg0 : * mut u8,) { unsafe {
-        $($path_to_types)*:: __post_return_create_webhook::<$ty > (arg0) } } #[unsafe
-        (export_name = "delete-webhook")] unsafe extern "C" fn export_delete_webhook(arg0
-        : * mut u8, arg1 : usize, arg2 : * mut u8, arg3 : usize, arg4 : * mut u8, arg5 :
-        usize,) -> i32 { unsafe { $($path_to_types)*:: _export_delete_webhook_cabi::<$ty
-        > (arg0, arg1, arg2, arg3, arg4, arg5) } } #[unsafe (export_name =
-        "delete-message")] unsafe extern "C" fn export_delete_message(arg0 : * mut u8,
-        arg1 : usize, arg2 : * mut u8, arg3 : usize, arg4 : * mut u8, arg5 : usize,) ->
-        i32 { unsafe { $($path_to_types)*:: _export_delete_message_cabi::<$ty > (arg0,
-        arg1, arg2, arg3, arg4, arg5) } } #[unsafe (export_name = "edit-message")] unsafe
-        extern "C" fn export_edit_message(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8,
-        arg3 : usize, arg4 : * mut u8, arg5 : usize, arg6 : * mut u8, arg7 : usize,) ->
-        i32 { unsafe { $($path_to_types)*:: _export_edit_message_cabi::<$ty > (arg0,
-        arg1, arg2, arg3, arg4, arg5, arg6, arg7) } } #[unsafe (export_name =
-        "get-channel")] unsafe extern "C" fn export_get_channel(arg0 : * mut u8, arg1 :
-        usize, arg2 : * mut u8, arg3 : usize,) -> * mut u8 { unsafe {
-        $($path_to_types)*:: _export_get_channel_cabi::<$ty > (arg0, arg1, arg2, arg3) }
-        } #[unsafe (export_name = "cabi_post_get-channel")] unsafe extern "C" fn
-        _post_return_get_channel(arg0 : * mut u8,) { unsafe { $($path_to_types)*::
-        __post_return_get_channel::<$ty > (arg0) } } #[unsafe (export_name = "get-user")]
-        unsafe extern "C" fn export_get_user(arg0 : * mut u8, arg1 : usize, arg2 : * mut
-        u8, arg3 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
-        _export_get_user_cabi::<$ty > (arg0, arg1, arg2, arg3) } } #[unsafe (export_name
-        = "cabi_post_get-user")] unsafe extern "C" fn _post_return_get_user(arg0 : * mut
-        u8,) { unsafe { $($path_to_types)*:: __post_return_get_user::<$ty > (arg0) } }
-        #[unsafe (export_name = "send-message")] unsafe extern "C" fn
-        export_send_message(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3 : usize,
-        arg4 : * mut u8, arg5 : usize, arg6 : i32, arg7 : * mut u8, arg8 : usize,) -> *
-        mut u8 { unsafe { $($path_to_types)*:: _export_send_message_cabi::<$ty > (arg0,
-        arg1, arg2, arg3, arg4, arg5, arg6, arg7, arg8) } } #[unsafe (export_name =
-        "cabi_post_send-message")] unsafe extern "C" fn _post_return_send_message(arg0 :
-        * mut u8,) { unsafe { $($path_to_types)*:: __post_return_send_message::<$ty >
-        (arg0) } } #[unsafe (export_name = "send-webhook-message")] unsafe extern "C" fn
-        export_send_webhook_message(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3
-        : usize, arg4 : * mut u8, arg5 : usize, arg6 : * mut u8, arg7 : usize, arg8 : *
-        mut u8, arg9 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
-        _export_send_webhook_message_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4, arg5,
-        arg6, arg7, arg8, arg9) } } #[unsafe (export_name =
-        "cabi_post_send-webhook-message")] unsafe extern "C" fn
-        _post_return_send_webhook_message(arg0 : * mut u8,) { unsafe {
-        $($path_to_types)*:: __post_return_send_webhook_message::<$ty > (arg0) } } };
-    };
+                    ptr3
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_create_webhook<T: GuestClient>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0
+                                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l4 = *arg0
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                            let l5 = *arg0
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l6 = *arg0
+                                .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l5, l6, 1);
+                        }
+                        _ => {
+                            let l7 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l7 {
+                                0 => {
+                                    let l8 = *arg0.add(16).cast::<*mut u8>();
+                                    let l9 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                1 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                                2 => {
+                                    let l12 = *arg0.add(16).cast::<*mut u8>();
+                                    let l13 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                }
+                                3 => {
+                                    let l14 = *arg0.add(16).cast::<*mut u8>();
+                                    let l15 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                }
+                                4 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l16, l17, 1);
+                                }
+                                5 => {
+                                    let l18 = *arg0.add(24).cast::<*mut u8>();
+                                    let l19 = *arg0
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l18, l19, 1);
+                                    let l20 = i32::from(
+                                        *arg0
+                                            .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l20 {
+                                        0 => {}
+                                        _ => {
+                                            let l21 = *arg0
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l22 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l21, l22, 1);
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    let l23 = *arg0.add(16).cast::<*mut u8>();
+                                    let l24 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l23, l24, 1);
+                                    let l25 = i32::from(
+                                        *arg0
+                                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l25 {
+                                        0 => {}
+                                        _ => {
+                                            let l26 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l27 = *arg0
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l26, l27, 1);
+                                        }
+                                    }
+                                    let l28 = i32::from(
+                                        *arg0
+                                            .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l28 {
+                                        0 => {}
+                                        _ => {
+                                            let l29 = *arg0
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l30 = *arg0
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l29, l30, 1);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_delete_webhook_cabi<T: GuestClient>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let len1 = arg4;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                    let result2 = T::delete_webhook(
+                        unsafe { ClientBorrow::lift(arg0 as u32 as usize) }.get(),
+                        _rt::string_lift(bytes0),
+                        _rt::string_lift(bytes1),
+                    );
+                    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result2 {
+                        Ok(_) => {
+                            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::wassemble::discord::types::DiscordError as V16;
+                            match e {
+                                V16::RequestFailed(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len4;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                                V16::ResponseBodyError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len5;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                }
+                                V16::Utf8Error(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (2i32) as u8;
+                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len6;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                                V16::JsonParseError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (3i32) as u8;
+                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len7;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                                V16::ConfigError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (4i32) as u8;
+                                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len8;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                V16::ApiError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (5i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::ApiError {
+                                        status: status9,
+                                        code: code9,
+                                        message: message9,
+                                        errors: errors9,
+                                    } = e;
+                                    *ptr3.add(16).cast::<u16>() = (_rt::as_i32(status9)) as u16;
+                                    *ptr3.add(20).cast::<i32>() = _rt::as_i32(code9);
+                                    let vec10 = (message9.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr3
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len10;
+                                    *ptr3.add(24).cast::<*mut u8>() = ptr10.cast_mut();
+                                    match errors9 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec11 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr11 = vec11.as_ptr().cast::<u8>();
+                                            let len11 = vec11.len();
+                                            ::core::mem::forget(vec11);
+                                            *ptr3
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len11;
+                                            *ptr3
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr11.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V16::RateLimited(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (6i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::RateLimit {
+                                        message: message12,
+                                        retry_after_ms: retry_after_ms12,
+                                        global: global12,
+                                        scope: scope12,
+                                        bucket: bucket12,
+                                    } = e;
+                                    let vec13 = (message12.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len13;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                    *ptr3
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i64>() = _rt::as_i64(retry_after_ms12);
+                                    *ptr3
+                                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (match global12 {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
+                                    match scope12 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec14 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                                            let len14 = vec14.len();
+                                            ::core::mem::forget(vec14);
+                                            *ptr3
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len14;
+                                            *ptr3
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr14.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match bucket12 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec15 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr15 = vec15.as_ptr().cast::<u8>();
+                                            let len15 = vec15.len();
+                                            ::core::mem::forget(vec15);
+                                            *ptr3
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len15;
+                                            *ptr3
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr15.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                        }
+                    };
+                    ptr3
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_delete_webhook<T: GuestClient>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                1 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                2 => {
+                                    let l6 = *arg0.add(16).cast::<*mut u8>();
+                                    let l7 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                3 => {
+                                    let l8 = *arg0.add(16).cast::<*mut u8>();
+                                    let l9 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                4 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                                5 => {
+                                    let l12 = *arg0.add(24).cast::<*mut u8>();
+                                    let l13 = *arg0
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                    let l14 = i32::from(
+                                        *arg0
+                                            .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l14 {
+                                        0 => {}
+                                        _ => {
+                                            let l15 = *arg0
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l16 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l15, l16, 1);
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    let l17 = *arg0.add(16).cast::<*mut u8>();
+                                    let l18 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l17, l18, 1);
+                                    let l19 = i32::from(
+                                        *arg0
+                                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l19 {
+                                        0 => {}
+                                        _ => {
+                                            let l20 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l21 = *arg0
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l20, l21, 1);
+                                        }
+                                    }
+                                    let l22 = i32::from(
+                                        *arg0
+                                            .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l22 {
+                                        0 => {}
+                                        _ => {
+                                            let l23 = *arg0
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l24 = *arg0
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l23, l24, 1);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_delete_message_cabi<T: GuestClient>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let len1 = arg4;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                    let result2 = T::delete_message(
+                        unsafe { ClientBorrow::lift(arg0 as u32 as usize) }.get(),
+                        _rt::string_lift(bytes0),
+                        _rt::string_lift(bytes1),
+                    );
+                    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result2 {
+                        Ok(_) => {
+                            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::wassemble::discord::types::DiscordError as V16;
+                            match e {
+                                V16::RequestFailed(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len4;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                                V16::ResponseBodyError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len5;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                }
+                                V16::Utf8Error(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (2i32) as u8;
+                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len6;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                                V16::JsonParseError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (3i32) as u8;
+                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len7;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                                V16::ConfigError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (4i32) as u8;
+                                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len8;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                V16::ApiError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (5i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::ApiError {
+                                        status: status9,
+                                        code: code9,
+                                        message: message9,
+                                        errors: errors9,
+                                    } = e;
+                                    *ptr3.add(16).cast::<u16>() = (_rt::as_i32(status9)) as u16;
+                                    *ptr3.add(20).cast::<i32>() = _rt::as_i32(code9);
+                                    let vec10 = (message9.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr3
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len10;
+                                    *ptr3.add(24).cast::<*mut u8>() = ptr10.cast_mut();
+                                    match errors9 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec11 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr11 = vec11.as_ptr().cast::<u8>();
+                                            let len11 = vec11.len();
+                                            ::core::mem::forget(vec11);
+                                            *ptr3
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len11;
+                                            *ptr3
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr11.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V16::RateLimited(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (6i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::RateLimit {
+                                        message: message12,
+                                        retry_after_ms: retry_after_ms12,
+                                        global: global12,
+                                        scope: scope12,
+                                        bucket: bucket12,
+                                    } = e;
+                                    let vec13 = (message12.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len13;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                    *ptr3
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i64>() = _rt::as_i64(retry_after_ms12);
+                                    *ptr3
+                                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (match global12 {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
+                                    match scope12 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec14 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                                            let len14 = vec14.len();
+                                            ::core::mem::forget(vec14);
+                                            *ptr3
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len14;
+                                            *ptr3
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr14.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match bucket12 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec15 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr15 = vec15.as_ptr().cast::<u8>();
+                                            let len15 = vec15.len();
+                                            ::core::mem::forget(vec15);
+                                            *ptr3
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len15;
+                                            *ptr3
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr15.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                        }
+                    };
+                    ptr3
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_delete_message<T: GuestClient>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                1 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                2 => {
+                                    let l6 = *arg0.add(16).cast::<*mut u8>();
+                                    let l7 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                3 => {
+                                    let l8 = *arg0.add(16).cast::<*mut u8>();
+                                    let l9 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                4 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                                5 => {
+                                    let l12 = *arg0.add(24).cast::<*mut u8>();
+                                    let l13 = *arg0
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                    let l14 = i32::from(
+                                        *arg0
+                                            .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l14 {
+                                        0 => {}
+                                        _ => {
+                                            let l15 = *arg0
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l16 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l15, l16, 1);
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    let l17 = *arg0.add(16).cast::<*mut u8>();
+                                    let l18 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l17, l18, 1);
+                                    let l19 = i32::from(
+                                        *arg0
+                                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l19 {
+                                        0 => {}
+                                        _ => {
+                                            let l20 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l21 = *arg0
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l20, l21, 1);
+                                        }
+                                    }
+                                    let l22 = i32::from(
+                                        *arg0
+                                            .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l22 {
+                                        0 => {}
+                                        _ => {
+                                            let l23 = *arg0
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l24 = *arg0
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l23, l24, 1);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_edit_message_cabi<T: GuestClient>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: *mut u8,
+                    arg6: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let len1 = arg4;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                    let len2 = arg6;
+                    let bytes2 = _rt::Vec::from_raw_parts(arg5.cast(), len2, len2);
+                    let result3 = T::edit_message(
+                        unsafe { ClientBorrow::lift(arg0 as u32 as usize) }.get(),
+                        _rt::string_lift(bytes0),
+                        _rt::string_lift(bytes1),
+                        _rt::string_lift(bytes2),
+                    );
+                    let ptr4 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result3 {
+                        Ok(e) => {
+                            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+                            let super::super::super::super::wassemble::discord::types::ChannelMessage {
+                                id: id5,
+                                channel_id: channel_id5,
+                                author: author5,
+                                content: content5,
+                                timestamp: timestamp5,
+                                edited_timestamp: edited_timestamp5,
+                            } = e;
+                            let vec6 = (id5.into_bytes()).into_boxed_slice();
+                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                            let len6 = vec6.len();
+                            ::core::mem::forget(vec6);
+                            *ptr4
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len6;
+                            *ptr4.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+                            let vec7 = (channel_id5.into_bytes()).into_boxed_slice();
+                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                            let len7 = vec7.len();
+                            ::core::mem::forget(vec7);
+                            *ptr4
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len7;
+                            *ptr4
+                                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr7.cast_mut();
+                            let super::super::super::super::wassemble::discord::types::User {
+                                id: id8,
+                                username: username8,
+                                discriminator: discriminator8,
+                                avatar: avatar8,
+                            } = author5;
+                            let vec9 = (id8.into_bytes()).into_boxed_slice();
+                            let ptr9 = vec9.as_ptr().cast::<u8>();
+                            let len9 = vec9.len();
+                            ::core::mem::forget(vec9);
+                            *ptr4
+                                .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len9;
+                            *ptr4
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr9.cast_mut();
+                            let vec10 = (username8.into_bytes()).into_boxed_slice();
+                            let ptr10 = vec10.as_ptr().cast::<u8>();
+                            let len10 = vec10.len();
+                            ::core::mem::forget(vec10);
+                            *ptr4
+                                .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len10;
+                            *ptr4
+                                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr10.cast_mut();
+                            let vec11 = (discriminator8.into_bytes()).into_boxed_slice();
+                            let ptr11 = vec11.as_ptr().cast::<u8>();
+                            let len11 = vec11.len();
+                            ::core::mem::forget(vec11);
+                            *ptr4
+                                .add(8 + 9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len11;
+                            *ptr4
+                                .add(8 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr11.cast_mut();
+                            match avatar8 {
+                                Some(e) => {
+                                    *ptr4
+                                        .add(8 + 10 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    let vec12 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr4
+                                        .add(8 + 12 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len12;
+                                    *ptr4
+                                        .add(8 + 11 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr12.cast_mut();
+                                }
+                                None => {
+                                    *ptr4
+                                        .add(8 + 10 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            let vec13 = (content5.into_bytes()).into_boxed_slice();
+                            let ptr13 = vec13.as_ptr().cast::<u8>();
+                            let len13 = vec13.len();
+                            ::core::mem::forget(vec13);
+                            *ptr4
+                                .add(8 + 14 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len13;
+                            *ptr4
+                                .add(8 + 13 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr13.cast_mut();
+                            let vec14 = (timestamp5.into_bytes()).into_boxed_slice();
+                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                            let len14 = vec14.len();
+                            ::core::mem::forget(vec14);
+                            *ptr4
+                                .add(8 + 16 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len14;
+                            *ptr4
+                                .add(8 + 15 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr14.cast_mut();
+                            match edited_timestamp5 {
+                                Some(e) => {
+                                    *ptr4
+                                        .add(8 + 17 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    let vec15 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr4
+                                        .add(8 + 19 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len15;
+                                    *ptr4
+                                        .add(8 + 18 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr15.cast_mut();
+                                }
+                                None => {
+                                    *ptr4
+                                        .add(8 + 17 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                        Err(e) => {
+                            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::wassemble::discord::types::DiscordError as V28;
+                            match e {
+                                V28::RequestFailed(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec16 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                    let len16 = vec16.len();
+                                    ::core::mem::forget(vec16);
+                                    *ptr4
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len16;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr16.cast_mut();
+                                }
+                                V28::ResponseBodyError(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec17 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr4
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len17;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr17.cast_mut();
+                                }
+                                V28::Utf8Error(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (2i32) as u8;
+                                    let vec18 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                                    let len18 = vec18.len();
+                                    ::core::mem::forget(vec18);
+                                    *ptr4
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len18;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr18.cast_mut();
+                                }
+                                V28::JsonParseError(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (3i32) as u8;
+                                    let vec19 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                    let len19 = vec19.len();
+                                    ::core::mem::forget(vec19);
+                                    *ptr4
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len19;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr19.cast_mut();
+                                }
+                                V28::ConfigError(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (4i32) as u8;
+                                    let vec20 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                    let len20 = vec20.len();
+                                    ::core::mem::forget(vec20);
+                                    *ptr4
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len20;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr20.cast_mut();
+                                }
+                                V28::ApiError(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (5i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::ApiError {
+                                        status: status21,
+                                        code: code21,
+                                        message: message21,
+                                        errors: errors21,
+                                    } = e;
+                                    *ptr4.add(16).cast::<u16>() = (_rt::as_i32(status21))
+                                        as u16;
+                                    *ptr4.add(20).cast::<i32>() = _rt::as_i32(code21);
+                                    let vec22 = (message21.into_bytes()).into_boxed_slice();
+                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                    let len22 = vec22.len();
+                                    ::core::mem::forget(vec22);
+                                    *ptr4
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len22;
+                                    *ptr4.add(24).cast::<*mut u8>() = ptr22.cast_mut();
+                                    match errors21 {
+                                        Some(e) => {
+                                            *ptr4
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec23 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr23 = vec23.as_ptr().cast::<u8>();
+                                            let len23 = vec23.len();
+                                            ::core::mem::forget(vec23);
+                                            *ptr4
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len23;
+                                            *ptr4
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr23.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr4
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V28::RateLimited(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (6i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::RateLimit {
+                                        message: message24,
+                                        retry_after_ms: retry_after_ms24,
+                                        global: global24,
+                                        scope: scope24,
+                                        bucket: bucket24,
+                                    } = e;
+                                    let vec25 = (message24.into_bytes()).into_boxed_slice();
+                                    let ptr25 = vec25.as_ptr().cast::<u8>();
+                                    let len25 = vec25.len();
+                                    ::core::mem::forget(vec25);
+                                    *ptr4
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len25;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr25.cast_mut();
+                                    *ptr4
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i64>() = _rt::as_i64(retry_after_ms24);
+                                    *ptr4
+                                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (match global24 {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
+                                    match scope24 {
+                                        Some(e) => {
+                                            *ptr4
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec26 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr26 = vec26.as_ptr().cast::<u8>();
+                                            let len26 = vec26.len();
+                                            ::core::mem::forget(vec26);
+                                            *ptr4
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len26;
+                                            *ptr4
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr26.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr4
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match bucket24 {
+                                        Some(e) => {
+                                            *ptr4
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec27 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr27 = vec27.as_ptr().cast::<u8>();
+                                            let len27 = vec27.len();
+                                            ::core::mem::forget(vec27);
+                                            *ptr4
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len27;
+                                            *ptr4
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr27.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr4
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                        }
+                    };
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_edit_message<T: GuestClient>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0
+                                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l4 = *arg0
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                            let l5 = *arg0
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l6 = *arg0
+                                .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l5, l6, 1);
+                            let l7 = *arg0
+                                .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l8 = *arg0
+                                .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l7, l8, 1);
+                            let l9 = *arg0
+                                .add(8 + 8 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l10 = *arg0
+                                .add(8 + 9 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l9, l10, 1);
+                            let l11 = i32::from(
+                                *arg0
+                                    .add(8 + 10 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            match l11 {
+                                0 => {}
+                                _ => {
+                                    let l12 = *arg0
+                                        .add(8 + 11 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l13 = *arg0
+                                        .add(8 + 12 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                }
+                            }
+                            let l14 = *arg0
+                                .add(8 + 13 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l15 = *arg0
+                                .add(8 + 14 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l14, l15, 1);
+                            let l16 = *arg0
+                                .add(8 + 15 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l17 = *arg0
+                                .add(8 + 16 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l16, l17, 1);
+                            let l18 = i32::from(
+                                *arg0
+                                    .add(8 + 17 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            match l18 {
+                                0 => {}
+                                _ => {
+                                    let l19 = *arg0
+                                        .add(8 + 18 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l20 = *arg0
+                                        .add(8 + 19 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l19, l20, 1);
+                                }
+                            }
+                        }
+                        _ => {
+                            let l21 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l21 {
+                                0 => {
+                                    let l22 = *arg0.add(16).cast::<*mut u8>();
+                                    let l23 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l22, l23, 1);
+                                }
+                                1 => {
+                                    let l24 = *arg0.add(16).cast::<*mut u8>();
+                                    let l25 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l24, l25, 1);
+                                }
+                                2 => {
+                                    let l26 = *arg0.add(16).cast::<*mut u8>();
+                                    let l27 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l26, l27, 1);
+                                }
+                                3 => {
+                                    let l28 = *arg0.add(16).cast::<*mut u8>();
+                                    let l29 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l28, l29, 1);
+                                }
+                                4 => {
+                                    let l30 = *arg0.add(16).cast::<*mut u8>();
+                                    let l31 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l30, l31, 1);
+                                }
+                                5 => {
+                                    let l32 = *arg0.add(24).cast::<*mut u8>();
+                                    let l33 = *arg0
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l32, l33, 1);
+                                    let l34 = i32::from(
+                                        *arg0
+                                            .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l34 {
+                                        0 => {}
+                                        _ => {
+                                            let l35 = *arg0
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l36 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l35, l36, 1);
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    let l37 = *arg0.add(16).cast::<*mut u8>();
+                                    let l38 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l37, l38, 1);
+                                    let l39 = i32::from(
+                                        *arg0
+                                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l39 {
+                                        0 => {}
+                                        _ => {
+                                            let l40 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l41 = *arg0
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l40, l41, 1);
+                                        }
+                                    }
+                                    let l42 = i32::from(
+                                        *arg0
+                                            .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l42 {
+                                        0 => {}
+                                        _ => {
+                                            let l43 = *arg0
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l44 = *arg0
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l43, l44, 1);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_get_channel_cabi<T: GuestClient>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::get_channel(
+                        unsafe { ClientBorrow::lift(arg0 as u32 as usize) }.get(),
+                        _rt::string_lift(bytes0),
+                    );
+                    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            let super::super::super::super::wassemble::discord::types::Channel {
+                                id: id3,
+                                name: name3,
+                                ty: ty3,
+                                guild_id: guild_id3,
+                            } = e;
+                            let vec4 = (id3.into_bytes()).into_boxed_slice();
+                            let ptr4 = vec4.as_ptr().cast::<u8>();
+                            let len4 = vec4.len();
+                            ::core::mem::forget(vec4);
+                            *ptr2
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len4;
+                            *ptr2.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+                            let vec5 = (name3.into_bytes()).into_boxed_slice();
+                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                            let len5 = vec5.len();
+                            ::core::mem::forget(vec5);
+                            *ptr2
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len5;
+                            *ptr2
+                                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr5.cast_mut();
+                            *ptr2
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(ty3);
+                            match guild_id3 {
+                                Some(e) => {
+                                    *ptr2
+                                        .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr2
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len6;
+                                    *ptr2
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                                None => {
+                                    *ptr2
+                                        .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::wassemble::discord::types::DiscordError as V19;
+                            match e {
+                                V19::RequestFailed(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr2
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len7;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                                V19::ResponseBodyError(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr2
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len8;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                V19::Utf8Error(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (2i32) as u8;
+                                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr2
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len9;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                V19::JsonParseError(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (3i32) as u8;
+                                    let vec10 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr2
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len10;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                V19::ConfigError(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (4i32) as u8;
+                                    let vec11 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr2
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len11;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                V19::ApiError(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (5i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::ApiError {
+                                        status: status12,
+                                        code: code12,
+                                        message: message12,
+                                        errors: errors12,
+                                    } = e;
+                                    *ptr2.add(16).cast::<u16>() = (_rt::as_i32(status12))
+                                        as u16;
+                                    *ptr2.add(20).cast::<i32>() = _rt::as_i32(code12);
+                                    let vec13 = (message12.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr2
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len13;
+                                    *ptr2.add(24).cast::<*mut u8>() = ptr13.cast_mut();
+                                    match errors12 {
+                                        Some(e) => {
+                                            *ptr2
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec14 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                                            let len14 = vec14.len();
+                                            ::core::mem::forget(vec14);
+                                            *ptr2
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len14;
+                                            *ptr2
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr14.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr2
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V19::RateLimited(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (6i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::RateLimit {
+                                        message: message15,
+                                        retry_after_ms: retry_after_ms15,
+                                        global: global15,
+                                        scope: scope15,
+                                        bucket: bucket15,
+                                    } = e;
+                                    let vec16 = (message15.into_bytes()).into_boxed_slice();
+                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                    let len16 = vec16.len();
+                                    ::core::mem::forget(vec16);
+                                    *ptr2
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len16;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr16.cast_mut();
+                                    *ptr2
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i64>() = _rt::as_i64(retry_after_ms15);
+                                    *ptr2
+                                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (match global15 {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
+                                    match scope15 {
+                                        Some(e) => {
+                                            *ptr2
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec17 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr17 = vec17.as_ptr().cast::<u8>();
+                                            let len17 = vec17.len();
+                                            ::core::mem::forget(vec17);
+                                            *ptr2
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len17;
+                                            *ptr2
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr17.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr2
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match bucket15 {
+                                        Some(e) => {
+                                            *ptr2
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec18 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr18 = vec18.as_ptr().cast::<u8>();
+                                            let len18 = vec18.len();
+                                            ::core::mem::forget(vec18);
+                                            *ptr2
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len18;
+                                            *ptr2
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr18.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr2
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_get_channel<T: GuestClient>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0
+                                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l4 = *arg0
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                            let l5 = i32::from(
+                                *arg0
+                                    .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            match l5 {
+                                0 => {}
+                                _ => {
+                                    let l6 = *arg0
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l7 = *arg0
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                            }
+                        }
+                        _ => {
+                            let l8 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l8 {
+                                0 => {
+                                    let l9 = *arg0.add(16).cast::<*mut u8>();
+                                    let l10 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l9, l10, 1);
+                                }
+                                1 => {
+                                    let l11 = *arg0.add(16).cast::<*mut u8>();
+                                    let l12 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l11, l12, 1);
+                                }
+                                2 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l13, l14, 1);
+                                }
+                                3 => {
+                                    let l15 = *arg0.add(16).cast::<*mut u8>();
+                                    let l16 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l15, l16, 1);
+                                }
+                                4 => {
+                                    let l17 = *arg0.add(16).cast::<*mut u8>();
+                                    let l18 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l17, l18, 1);
+                                }
+                                5 => {
+                                    let l19 = *arg0.add(24).cast::<*mut u8>();
+                                    let l20 = *arg0
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l19, l20, 1);
+                                    let l21 = i32::from(
+                                        *arg0
+                                            .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l21 {
+                                        0 => {}
+                                        _ => {
+                                            let l22 = *arg0
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l23 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l22, l23, 1);
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    let l24 = *arg0.add(16).cast::<*mut u8>();
+                                    let l25 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l24, l25, 1);
+                                    let l26 = i32::from(
+                                        *arg0
+                                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l26 {
+                                        0 => {}
+                                        _ => {
+                                            let l27 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l28 = *arg0
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l27, l28, 1);
+                                        }
+                                    }
+                                    let l29 = i32::from(
+                                        *arg0
+                                            .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l29 {
+                                        0 => {}
+                                        _ => {
+                                            let l30 = *arg0
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l31 = *arg0
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l30, l31, 1);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_get_user_cabi<T: GuestClient>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let result1 = T::get_user(
+                        unsafe { ClientBorrow::lift(arg0 as u32 as usize) }.get(),
+                        _rt::string_lift(bytes0),
+                    );
+                    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result1 {
+                        Ok(e) => {
+                            *ptr2.add(0).cast::<u8>() = (0i32) as u8;
+                            let super::super::super::super::wassemble::discord::types::User {
+                                id: id3,
+                                username: username3,
+                                discriminator: discriminator3,
+                                avatar: avatar3,
+                            } = e;
+                            let vec4 = (id3.into_bytes()).into_boxed_slice();
+                            let ptr4 = vec4.as_ptr().cast::<u8>();
+                            let len4 = vec4.len();
+                            ::core::mem::forget(vec4);
+                            *ptr2
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len4;
+                            *ptr2.add(8).cast::<*mut u8>() = ptr4.cast_mut();
+                            let vec5 = (username3.into_bytes()).into_boxed_slice();
+                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                            let len5 = vec5.len();
+                            ::core::mem::forget(vec5);
+                            *ptr2
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len5;
+                            *ptr2
+                                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr5.cast_mut();
+                            let vec6 = (discriminator3.into_bytes()).into_boxed_slice();
+                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                            let len6 = vec6.len();
+                            ::core::mem::forget(vec6);
+                            *ptr2
+                                .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len6;
+                            *ptr2
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr6.cast_mut();
+                            match avatar3 {
+                                Some(e) => {
+                                    *ptr2
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (1i32) as u8;
+                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr2
+                                        .add(8 + 8 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len7;
+                                    *ptr2
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                                None => {
+                                    *ptr2
+                                        .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                        Err(e) => {
+                            *ptr2.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::wassemble::discord::types::DiscordError as V20;
+                            match e {
+                                V20::RequestFailed(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr2
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len8;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                V20::ResponseBodyError(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr2
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len9;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                V20::Utf8Error(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (2i32) as u8;
+                                    let vec10 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr2
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len10;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                V20::JsonParseError(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (3i32) as u8;
+                                    let vec11 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr2
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len11;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                V20::ConfigError(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (4i32) as u8;
+                                    let vec12 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr2
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len12;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr12.cast_mut();
+                                }
+                                V20::ApiError(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (5i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::ApiError {
+                                        status: status13,
+                                        code: code13,
+                                        message: message13,
+                                        errors: errors13,
+                                    } = e;
+                                    *ptr2.add(16).cast::<u16>() = (_rt::as_i32(status13))
+                                        as u16;
+                                    *ptr2.add(20).cast::<i32>() = _rt::as_i32(code13);
+                                    let vec14 = (message13.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr2
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len14;
+                                    *ptr2.add(24).cast::<*mut u8>() = ptr14.cast_mut();
+                                    match errors13 {
+                                        Some(e) => {
+                                            *ptr2
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec15 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr15 = vec15.as_ptr().cast::<u8>();
+                                            let len15 = vec15.len();
+                                            ::core::mem::forget(vec15);
+                                            *ptr2
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len15;
+                                            *ptr2
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr15.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr2
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V20::RateLimited(e) => {
+                                    *ptr2.add(8).cast::<u8>() = (6i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::RateLimit {
+                                        message: message16,
+                                        retry_after_ms: retry_after_ms16,
+                                        global: global16,
+                                        scope: scope16,
+                                        bucket: bucket16,
+                                    } = e;
+                                    let vec17 = (message16.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr2
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len17;
+                                    *ptr2.add(16).cast::<*mut u8>() = ptr17.cast_mut();
+                                    *ptr2
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i64>() = _rt::as_i64(retry_after_ms16);
+                                    *ptr2
+                                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (match global16 {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
+                                    match scope16 {
+                                        Some(e) => {
+                                            *ptr2
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec18 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr18 = vec18.as_ptr().cast::<u8>();
+                                            let len18 = vec18.len();
+                                            ::core::mem::forget(vec18);
+                                            *ptr2
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len18;
+                                            *ptr2
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr18.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr2
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match bucket16 {
+                                        Some(e) => {
+                                            *ptr2
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec19 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr19 = vec19.as_ptr().cast::<u8>();
+                                            let len19 = vec19.len();
+                                            ::core::mem::forget(vec19);
+                                            *ptr2
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len19;
+                                            *ptr2
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr19.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr2
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                        }
+                    };
+                    ptr2
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_get_user<T: GuestClient>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0
+                                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l4 = *arg0
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                            let l5 = *arg0
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l6 = *arg0
+                                .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l5, l6, 1);
+                            let l7 = i32::from(
+                                *arg0
+                                    .add(8 + 6 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<u8>(),
+                            );
+                            match l7 {
+                                0 => {}
+                                _ => {
+                                    let l8 = *arg0
+                                        .add(8 + 7 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l9 = *arg0
+                                        .add(8 + 8 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                            }
+                        }
+                        _ => {
+                            let l10 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l10 {
+                                0 => {
+                                    let l11 = *arg0.add(16).cast::<*mut u8>();
+                                    let l12 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l11, l12, 1);
+                                }
+                                1 => {
+                                    let l13 = *arg0.add(16).cast::<*mut u8>();
+                                    let l14 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l13, l14, 1);
+                                }
+                                2 => {
+                                    let l15 = *arg0.add(16).cast::<*mut u8>();
+                                    let l16 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l15, l16, 1);
+                                }
+                                3 => {
+                                    let l17 = *arg0.add(16).cast::<*mut u8>();
+                                    let l18 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l17, l18, 1);
+                                }
+                                4 => {
+                                    let l19 = *arg0.add(16).cast::<*mut u8>();
+                                    let l20 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l19, l20, 1);
+                                }
+                                5 => {
+                                    let l21 = *arg0.add(24).cast::<*mut u8>();
+                                    let l22 = *arg0
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l21, l22, 1);
+                                    let l23 = i32::from(
+                                        *arg0
+                                            .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l23 {
+                                        0 => {}
+                                        _ => {
+                                            let l24 = *arg0
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l25 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l24, l25, 1);
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    let l26 = *arg0.add(16).cast::<*mut u8>();
+                                    let l27 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l26, l27, 1);
+                                    let l28 = i32::from(
+                                        *arg0
+                                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l28 {
+                                        0 => {}
+                                        _ => {
+                                            let l29 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l30 = *arg0
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l29, l30, 1);
+                                        }
+                                    }
+                                    let l31 = i32::from(
+                                        *arg0
+                                            .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l31 {
+                                        0 => {}
+                                        _ => {
+                                            let l32 = *arg0
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l33 = *arg0
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l32, l33, 1);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_send_message_cabi<T: GuestClient>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: i32,
+                    arg6: *mut u8,
+                    arg7: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let len1 = arg4;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                    let result3 = T::send_message(
+                        unsafe { ClientBorrow::lift(arg0 as u32 as usize) }.get(),
+                        super::super::super::super::wassemble::discord::types::Message {
+                            content: _rt::string_lift(bytes0),
+                            channel_id: _rt::string_lift(bytes1),
+                            guild_id: match arg5 {
+                                0 => None,
+                                1 => {
+                                    let e = {
+                                        let len2 = arg7;
+                                        let bytes2 = _rt::Vec::from_raw_parts(
+                                            arg6.cast(),
+                                            len2,
+                                            len2,
+                                        );
+                                        _rt::string_lift(bytes2)
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                        },
+                    );
+                    let ptr4 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result3 {
+                        Ok(e) => {
+                            *ptr4.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec5 = (e.into_bytes()).into_boxed_slice();
+                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                            let len5 = vec5.len();
+                            ::core::mem::forget(vec5);
+                            *ptr4
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len5;
+                            *ptr4.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr4.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::wassemble::discord::types::DiscordError as V18;
+                            match e {
+                                V18::RequestFailed(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr4
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len6;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                                V18::ResponseBodyError(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr4
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len7;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                                V18::Utf8Error(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (2i32) as u8;
+                                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr4
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len8;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                V18::JsonParseError(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (3i32) as u8;
+                                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr4
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len9;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                V18::ConfigError(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (4i32) as u8;
+                                    let vec10 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr4
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len10;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                V18::ApiError(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (5i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::ApiError {
+                                        status: status11,
+                                        code: code11,
+                                        message: message11,
+                                        errors: errors11,
+                                    } = e;
+                                    *ptr4.add(16).cast::<u16>() = (_rt::as_i32(status11))
+                                        as u16;
+                                    *ptr4.add(20).cast::<i32>() = _rt::as_i32(code11);
+                                    let vec12 = (message11.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr4
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len12;
+                                    *ptr4.add(24).cast::<*mut u8>() = ptr12.cast_mut();
+                                    match errors11 {
+                                        Some(e) => {
+                                            *ptr4
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec13 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr13 = vec13.as_ptr().cast::<u8>();
+                                            let len13 = vec13.len();
+                                            ::core::mem::forget(vec13);
+                                            *ptr4
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len13;
+                                            *ptr4
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr13.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr4
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V18::RateLimited(e) => {
+                                    *ptr4.add(8).cast::<u8>() = (6i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::RateLimit {
+                                        message: message14,
+                                        retry_after_ms: retry_after_ms14,
+                                        global: global14,
+                                        scope: scope14,
+                                        bucket: bucket14,
+                                    } = e;
+                                    let vec15 = (message14.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr4
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len15;
+                                    *ptr4.add(16).cast::<*mut u8>() = ptr15.cast_mut();
+                                    *ptr4
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i64>() = _rt::as_i64(retry_after_ms14);
+                                    *ptr4
+                                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (match global14 {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
+                                    match scope14 {
+                                        Some(e) => {
+                                            *ptr4
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec16 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr16 = vec16.as_ptr().cast::<u8>();
+                                            let len16 = vec16.len();
+                                            ::core::mem::forget(vec16);
+                                            *ptr4
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len16;
+                                            *ptr4
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr16.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr4
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match bucket14 {
+                                        Some(e) => {
+                                            *ptr4
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec17 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr17 = vec17.as_ptr().cast::<u8>();
+                                            let len17 = vec17.len();
+                                            ::core::mem::forget(vec17);
+                                            *ptr4
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len17;
+                                            *ptr4
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr17.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr4
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                        }
+                    };
+                    ptr4
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_send_message<T: GuestClient>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                        _ => {
+                            let l3 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l3 {
+                                0 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                1 => {
+                                    let l6 = *arg0.add(16).cast::<*mut u8>();
+                                    let l7 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                2 => {
+                                    let l8 = *arg0.add(16).cast::<*mut u8>();
+                                    let l9 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                3 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                                4 => {
+                                    let l12 = *arg0.add(16).cast::<*mut u8>();
+                                    let l13 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                }
+                                5 => {
+                                    let l14 = *arg0.add(24).cast::<*mut u8>();
+                                    let l15 = *arg0
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                    let l16 = i32::from(
+                                        *arg0
+                                            .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l16 {
+                                        0 => {}
+                                        _ => {
+                                            let l17 = *arg0
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l18 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l17, l18, 1);
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    let l19 = *arg0.add(16).cast::<*mut u8>();
+                                    let l20 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l19, l20, 1);
+                                    let l21 = i32::from(
+                                        *arg0
+                                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l21 {
+                                        0 => {}
+                                        _ => {
+                                            let l22 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l23 = *arg0
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l22, l23, 1);
+                                        }
+                                    }
+                                    let l24 = i32::from(
+                                        *arg0
+                                            .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l24 {
+                                        0 => {}
+                                        _ => {
+                                            let l25 = *arg0
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l26 = *arg0
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l25, l26, 1);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_send_webhook_message_cabi<
+                    T: GuestClient,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: *mut u8,
+                    arg6: usize,
+                    arg7: *mut u8,
+                    arg8: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let len1 = arg4;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                    let len2 = arg6;
+                    let bytes2 = _rt::Vec::from_raw_parts(arg5.cast(), len2, len2);
+                    let len3 = arg8;
+                    let bytes3 = _rt::Vec::from_raw_parts(arg7.cast(), len3, len3);
+                    let result4 = T::send_webhook_message(
+                        unsafe { ClientBorrow::lift(arg0 as u32 as usize) }.get(),
+                        super::super::super::super::wassemble::discord::types::Webhook {
+                            id: _rt::string_lift(bytes0),
+                            token: _rt::string_lift(bytes1),
+                            url: _rt::string_lift(bytes2),
+                        },
+                        _rt::string_lift(bytes3),
+                    );
+                    let ptr5 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result4 {
+                        Ok(e) => {
+                            *ptr5.add(0).cast::<u8>() = (0i32) as u8;
+                            let vec6 = (e.into_bytes()).into_boxed_slice();
+                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                            let len6 = vec6.len();
+                            ::core::mem::forget(vec6);
+                            *ptr5
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len6;
+                            *ptr5.add(8).cast::<*mut u8>() = ptr6.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr5.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::wassemble::discord::types::DiscordError as V19;
+                            match e {
+                                V19::RequestFailed(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len7;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                                V19::ResponseBodyError(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len8;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                V19::Utf8Error(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (2i32) as u8;
+                                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len9;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                V19::JsonParseError(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (3i32) as u8;
+                                    let vec10 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len10;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                V19::ConfigError(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (4i32) as u8;
+                                    let vec11 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len11;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                V19::ApiError(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (5i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::ApiError {
+                                        status: status12,
+                                        code: code12,
+                                        message: message12,
+                                        errors: errors12,
+                                    } = e;
+                                    *ptr5.add(16).cast::<u16>() = (_rt::as_i32(status12))
+                                        as u16;
+                                    *ptr5.add(20).cast::<i32>() = _rt::as_i32(code12);
+                                    let vec13 = (message12.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr5
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len13;
+                                    *ptr5.add(24).cast::<*mut u8>() = ptr13.cast_mut();
+                                    match errors12 {
+                                        Some(e) => {
+                                            *ptr5
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec14 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                                            let len14 = vec14.len();
+                                            ::core::mem::forget(vec14);
+                                            *ptr5
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len14;
+                                            *ptr5
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr14.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr5
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V19::RateLimited(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (6i32) as u8;
+                                    let super::super::super::super::wassemble::discord::types::RateLimit {
+                                        message: message15,
+                                        retry_after_ms: retry_after_ms15,
+                                        global: global15,
+                                        scope: scope15,
+                                        bucket: bucket15,
+                                    } = e;
+                                    let vec16 = (message15.into_bytes()).into_boxed_slice();
+                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                    let len16 = vec16.len();
+                                    ::core::mem::forget(vec16);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len16;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr16.cast_mut();
+                                    *ptr5
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<i64>() = _rt::as_i64(retry_after_ms15);
+                                    *ptr5
+                                        .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<u8>() = (match global15 {
+                                        true => 1,
+                                        false => 0,
+                                    }) as u8;
+                                    match scope15 {
+                                        Some(e) => {
+                                            *ptr5
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec17 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr17 = vec17.as_ptr().cast::<u8>();
+                                            let len17 = vec17.len();
+                                            ::core::mem::forget(vec17);
+                                            *ptr5
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len17;
+                                            *ptr5
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr17.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr5
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match bucket15 {
+                                        Some(e) => {
+                                            *ptr5
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec18 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr18 = vec18.as_ptr().cast::<u8>();
+                                            let len18 = vec18.len();
+                                            ::core::mem::forget(vec18);
+                                            *ptr5
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len18;
+                                            *ptr5
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr18.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr5
+                                                .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                        }
+                    };
+                    ptr5
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_send_webhook_message<
+                    T: GuestClient,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                        }
+                        _ => {
+                            let l3 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l3 {
+                                0 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                1 => {
+                                    let l6 = *arg0.add(16).cast::<*mut u8>();
+                                    let l7 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                2 => {
+                                    let l8 = *arg0.add(16).cast::<*mut u8>();
+                                    let l9 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                3 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                                4 => {
+                                    let l12 = *arg0.add(16).cast::<*mut u8>();
+                                    let l13 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                }
+                                5 => {
+                                    let l14 = *arg0.add(24).cast::<*mut u8>();
+                                    let l15 = *arg0
+                                        .add(24 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                    let l16 = i32::from(
+                                        *arg0
+                                            .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l16 {
+                                        0 => {}
+                                        _ => {
+                                            let l17 = *arg0
+                                                .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l18 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l17, l18, 1);
+                                        }
+                                    }
+                                }
+                                _ => {
+                                    let l19 = *arg0.add(16).cast::<*mut u8>();
+                                    let l20 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l19, l20, 1);
+                                    let l21 = i32::from(
+                                        *arg0
+                                            .add(24 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l21 {
+                                        0 => {}
+                                        _ => {
+                                            let l22 = *arg0
+                                                .add(24 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l23 = *arg0
+                                                .add(24 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l22, l23, 1);
+                                        }
+                                    }
+                                    let l24 = i32::from(
+                                        *arg0
+                                            .add(24 + 6 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l24 {
+                                        0 => {}
+                                        _ => {
+                                            let l25 = *arg0
+                                                .add(24 + 7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l26 = *arg0
+                                                .add(24 + 8 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l25, l26, 1);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_last_rate_limit_cabi<T: GuestClient>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::last_rate_limit(
+                        unsafe { ClientBorrow::lift(arg0 as u32 as usize) }.get(),
+                    );
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result0 {
+                        Some(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            let super::super::super::super::wassemble::discord::types::RateLimitStatus {
+                                bucket: bucket2,
+                                limit: limit2,
+                                remaining: remaining2,
+                                reset_after_ms: reset_after_ms2,
+                            } = e;
+                            match bucket2 {
+                                Some(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr1
+                                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len3;
+                                    *ptr1
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr3.cast_mut();
+                                }
+                                None => {
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            *ptr1
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(limit2);
+                            *ptr1
+                                .add(12 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(remaining2);
+                            *ptr1
+                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i64>() = _rt::as_i64(reset_after_ms2);
+                        }
+                        None => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_last_rate_limit<
+                    T: GuestClient,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => {}
+                                _ => {
+                                    let l2 = *arg0
+                                        .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l3 = *arg0
+                                        .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                pub trait Guest {
+                    type Client: GuestClient;
+                }
+                pub trait GuestClient: 'static {
+                    #[doc(hidden)]
+                    unsafe fn _resource_new(val: *mut u8) -> u32
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = val;
+                            unreachable!();
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]wassemble:discord/api@0.2.0"
+                            )]
+                            unsafe extern "C" {
+                                #[link_name = "[resource-new]client"]
+                                fn new(_: *mut u8) -> u32;
+                            }
+                            unsafe { new(val) }
+                        }
+                    }
+                    #[doc(hidden)]
+                    fn _resource_rep(handle: u32) -> *mut u8
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = handle;
+                            unreachable!();
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]wassemble:discord/api@0.2.0"
+                            )]
+                            unsafe extern "C" {
+                                #[link_name = "[resource-rep]client"]
+                                fn rep(_: u32) -> *mut u8;
+                            }
+                            unsafe { rep(handle) }
+                        }
+                    }
+                    fn new(config: Config) -> Self;
+                    fn create_webhook(
+                        &self,
+                        channel_id: _rt::String,
+                        name: _rt::String,
+                    ) -> Result<Webhook, DiscordError>;
+                    fn delete_webhook(
+                        &self,
+                        webhook_id: _rt::String,
+                        webhook_token: _rt::String,
+                    ) -> Result<(), DiscordError>;
+                    fn delete_message(
+                        &self,
+                        channel_id: _rt::String,
+                        message_id: _rt::String,
+                    ) -> Result<(), DiscordError>;
+                    fn edit_message(
+                        &self,
+                        channel_id: _rt::String,
+                        message_id: _rt::String,
+                        content: _rt::String,
+                    ) -> Result<ChannelMessage, DiscordError>;
+                    fn get_channel(
+                        &self,
+                        channel_id: _rt::String,
+                    ) -> Result<Channel, DiscordError>;
+                    fn get_user(
+                        &self,
+                        user_id: _rt::String,
+                    ) -> Result<User, DiscordError>;
+                    fn send_message(
+                        &self,
+                        message: Message,
+                    ) -> Result<_rt::String, DiscordError>;
+                    fn send_webhook_message(
+                        &self,
+                        webhook: Webhook,
+                        content: _rt::String,
+                    ) -> Result<_rt::String, DiscordError>;
+                    /// Rate limit counters of the most recent response, if it carried them
+                    fn last_rate_limit(&self) -> Option<RateLimitStatus>;
+                }
+                #[doc(hidden)]
+                macro_rules! __export_wassemble_discord_api_0_2_0_cabi {
+                    ($ty:ident with_types_in $($path_to_types:tt)*) => {
+                        const _ : () = { #[unsafe (export_name =
+                        "wassemble:discord/api@0.2.0#[constructor]client")] unsafe extern
+                        "C" fn export_constructor_client(arg0 : * mut u8, arg1 : usize,
+                        arg2 : i32, arg3 : * mut u8, arg4 : usize, arg5 : i32, arg6 : *
+                        mut u8, arg7 : usize, arg8 : i32, arg9 : i32, arg10 : i32, arg11
+                        : i32, arg12 : i32, arg13 : i32, arg14 : * mut u8, arg15 :
+                        usize,) -> i32 { unsafe { $($path_to_types)*::
+                        _export_constructor_client_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Client > (arg0, arg1, arg2, arg3, arg4, arg5, arg6,
+                        arg7, arg8, arg9, arg10, arg11, arg12, arg13, arg14, arg15) } }
+                        #[unsafe (export_name =
+                        "wassemble:discord/api@0.2.0#[method]client.create-webhook")]
+                        unsafe extern "C" fn export_method_client_create_webhook(arg0 : *
+                        mut u8, arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4 :
+                        usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_method_client_create_webhook_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0, arg1, arg2, arg3,
+                        arg4) } } #[unsafe (export_name =
+                        "cabi_post_wassemble:discord/api@0.2.0#[method]client.create-webhook")]
+                        unsafe extern "C" fn
+                        _post_return_method_client_create_webhook(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_method_client_create_webhook::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "wassemble:discord/api@0.2.0#[method]client.delete-webhook")]
+                        unsafe extern "C" fn export_method_client_delete_webhook(arg0 : *
+                        mut u8, arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4 :
+                        usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_method_client_delete_webhook_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0, arg1, arg2, arg3,
+                        arg4) } } #[unsafe (export_name =
+                        "cabi_post_wassemble:discord/api@0.2.0#[method]client.delete-webhook")]
+                        unsafe extern "C" fn
+                        _post_return_method_client_delete_webhook(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_method_client_delete_webhook::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "wassemble:discord/api@0.2.0#[method]client.delete-message")]
+                        unsafe extern "C" fn export_method_client_delete_message(arg0 : *
+                        mut u8, arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4 :
+                        usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_method_client_delete_message_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0, arg1, arg2, arg3,
+                        arg4) } } #[unsafe (export_name =
+                        "cabi_post_wassemble:discord/api@0.2.0#[method]client.delete-message")]
+                        unsafe extern "C" fn
+                        _post_return_method_client_delete_message(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_method_client_delete_message::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "wassemble:discord/api@0.2.0#[method]client.edit-message")]
+                        unsafe extern "C" fn export_method_client_edit_message(arg0 : *
+                        mut u8, arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4 :
+                        usize, arg5 : * mut u8, arg6 : usize,) -> * mut u8 { unsafe {
+                        $($path_to_types)*::
+                        _export_method_client_edit_message_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6) } } #[unsafe (export_name =
+                        "cabi_post_wassemble:discord/api@0.2.0#[method]client.edit-message")]
+                        unsafe extern "C" fn _post_return_method_client_edit_message(arg0
+                        : * mut u8,) { unsafe { $($path_to_types)*::
+                        __post_return_method_client_edit_message::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "wassemble:discord/api@0.2.0#[method]client.get-channel")] unsafe
+                        extern "C" fn export_method_client_get_channel(arg0 : * mut u8,
+                        arg1 : * mut u8, arg2 : usize,) -> * mut u8 { unsafe {
+                        $($path_to_types)*::
+                        _export_method_client_get_channel_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0, arg1, arg2) } }
+                        #[unsafe (export_name =
+                        "cabi_post_wassemble:discord/api@0.2.0#[method]client.get-channel")]
+                        unsafe extern "C" fn _post_return_method_client_get_channel(arg0
+                        : * mut u8,) { unsafe { $($path_to_types)*::
+                        __post_return_method_client_get_channel::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "wassemble:discord/api@0.2.0#[method]client.get-user")] unsafe
+                        extern "C" fn export_method_client_get_user(arg0 : * mut u8, arg1
+                        : * mut u8, arg2 : usize,) -> * mut u8 { unsafe {
+                        $($path_to_types)*:: _export_method_client_get_user_cabi::<<$ty
+                        as $($path_to_types)*:: Guest >::Client > (arg0, arg1, arg2) } }
+                        #[unsafe (export_name =
+                        "cabi_post_wassemble:discord/api@0.2.0#[method]client.get-user")]
+                        unsafe extern "C" fn _post_return_method_client_get_user(arg0 : *
+                        mut u8,) { unsafe { $($path_to_types)*::
+                        __post_return_method_client_get_user::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "wassemble:discord/api@0.2.0#[method]client.send-message")]
+                        unsafe extern "C" fn export_method_client_send_message(arg0 : *
+                        mut u8, arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4 :
+                        usize, arg5 : i32, arg6 : * mut u8, arg7 : usize,) -> * mut u8 {
+                        unsafe { $($path_to_types)*::
+                        _export_method_client_send_message_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7) } } #[unsafe (export_name =
+                        "cabi_post_wassemble:discord/api@0.2.0#[method]client.send-message")]
+                        unsafe extern "C" fn _post_return_method_client_send_message(arg0
+                        : * mut u8,) { unsafe { $($path_to_types)*::
+                        __post_return_method_client_send_message::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "wassemble:discord/api@0.2.0#[method]client.send-webhook-message")]
+                        unsafe extern "C" fn
+                        export_method_client_send_webhook_message(arg0 : * mut u8, arg1 :
+                        * mut u8, arg2 : usize, arg3 : * mut u8, arg4 : usize, arg5 : *
+                        mut u8, arg6 : usize, arg7 : * mut u8, arg8 : usize,) -> * mut u8
+                        { unsafe { $($path_to_types)*::
+                        _export_method_client_send_webhook_message_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7, arg8) } } #[unsafe (export_name =
+                        "cabi_post_wassemble:discord/api@0.2.0#[method]client.send-webhook-message")]
+                        unsafe extern "C" fn
+                        _post_return_method_client_send_webhook_message(arg0 : * mut u8,)
+                        { unsafe { $($path_to_types)*::
+                        __post_return_method_client_send_webhook_message::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "wassemble:discord/api@0.2.0#[method]client.last-rate-limit")]
+                        unsafe extern "C" fn export_method_client_last_rate_limit(arg0 :
+                        * mut u8,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_method_client_last_rate_limit_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "cabi_post_wassemble:discord/api@0.2.0#[method]client.last-rate-limit")]
+                        unsafe extern "C" fn
+                        _post_return_method_client_last_rate_limit(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_method_client_last_rate_limit::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } const _ : () =
+                        { #[doc(hidden)] #[unsafe (export_name =
+                        "wassemble:discord/api@0.2.0#[dtor]client")]
+                        #[allow(non_snake_case)] unsafe extern "C" fn dtor(rep : * mut
+                        u8) { unsafe { $($path_to_types)*:: Client::dtor::< <$ty as
+                        $($path_to_types)*:: Guest >::Client > (rep) } } }; };
+                    };
+                }
+                #[doc(hidden)]
+                pub(crate) use __export_wassemble_discord_api_0_2_0_cabi;
+                #[repr(align(8))]
+                struct _RetArea(
+                    [::core::mem::MaybeUninit<
+                        u8,
+                    >; 8 + 20 * ::core::mem::size_of::<*const u8>()],
+                );
+                static mut _RET_AREA: _RetArea = _RetArea(
+                    [::core::mem::MaybeUninit::uninit(); 8
+                        + 20 * ::core::mem::size_of::<*const u8>()],
+                );
+            }
+        }
+    }
 }
-#[doc(hidden)]
-pub(crate) use __export_world_discord_cabi;
-#[cfg_attr(target_pointer_width = "64", repr(align(8)))]
-#[cfg_attr(target_pointer_width = "32", repr(align(4)))]
-struct _RetArea([::core::mem::MaybeUninit<u8>; 9 * ::core::mem::size_of::<*const u8>()]);
-static mut _RET_AREA: _RetArea = _RetArea(
-    [::core::mem::MaybeUninit::uninit(); 9 * ::core::mem::size_of::<*const u8>()],
-);
 #[rustfmt::skip]
 mod _rt {
     #![allow(dead_code, clippy::all)]
     pub use alloc_crate::string::String;
-    #[cfg(target_arch = "wasm32")]
-    pub fn run_ctors_once() {
-        wit_bindgen_rt::run_ctors_once();
-    }
     pub use alloc_crate::vec::Vec;
     pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
         if cfg!(debug_assertions) {
@@ -575,6 +4208,13 @@ mod _rt {
             String::from_utf8_unchecked(bytes)
         }
     }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            unsafe { core::hint::unreachable_unchecked() }
+        }
+    }
     pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
         if size == 0 {
             return;
@@ -582,6 +4222,85 @@ mod _rt {
         let layout = alloc::Layout::from_size_align_unchecked(size, align);
         alloc::dealloc(ptr, layout);
     }
+    use core::fmt;
+    use core::marker;
+    use core::sync::atomic::{AtomicU32, Ordering::Relaxed};
+    /// A type which represents a component model resource, either imported or
+    /// exported into this component.
+    ///
+    /// This is a low-level wrapper which handles the lifetime of the resource
+    /// (namely this has a destructor). The `T` provided defines the component model
+    /// intrinsics that this wrapper uses.
+    ///
+    /// One of the chief purposes of this type is to provide `Deref` implementations
+    /// to access the underlying data when it is owned.
+    ///
+    /// This type is primarily used in generated code for exported and imported
+    /// resources.
+    #[repr(transparent)]
+    pub struct Resource<T: WasmResource> {
+        handle: AtomicU32,
+        _marker: marker::PhantomData<T>,
+    }
+    /// A trait which all wasm resources implement, namely providing the ability to
+    /// drop a resource.
+    ///
+    /// This generally is implemented by generated code, not user-facing code.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe trait WasmResource {
+        /// Invokes the `[resource-drop]...` intrinsic.
+        unsafe fn drop(handle: u32);
+    }
+    impl<T: WasmResource> Resource<T> {
+        #[doc(hidden)]
+        pub unsafe fn from_handle(handle: u32) -> Self {
+            debug_assert!(handle != u32::MAX);
+            Self {
+                handle: AtomicU32::new(handle),
+                _marker: marker::PhantomData,
+            }
+        }
+        /// Takes ownership of the handle owned by `resource`.
+        ///
+        /// Note that this ideally would be `into_handle` taking `Resource<T>` by
+        /// ownership. The code generator does not enable that in all situations,
+        /// unfortunately, so this is provided instead.
+        ///
+        /// Also note that `take_handle` is in theory only ever called on values
+        /// owned by a generated function. For example a generated function might
+        /// take `Resource<T>` as an argument but then call `take_handle` on a
+        /// reference to that argument. In that sense the dynamic nature of
+        /// `take_handle` should only be exposed internally to generated code, not
+        /// to user code.
+        #[doc(hidden)]
+        pub fn take_handle(resource: &Resource<T>) -> u32 {
+            resource.handle.swap(u32::MAX, Relaxed)
+        }
+        #[doc(hidden)]
+        pub fn handle(resource: &Resource<T>) -> u32 {
+            resource.handle.load(Relaxed)
+        }
+    }
+    impl<T: WasmResource> fmt::Debug for Resource<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Resource").field("handle", &self.handle).finish()
+        }
+    }
+    impl<T: WasmResource> Drop for Resource<T> {
+        fn drop(&mut self) {
+            unsafe {
+                match self.handle.load(Relaxed) {
+                    u32::MAX => {}
+                    other => T::drop(other),
+                }
+            }
+        }
+    }
+    pub use alloc_crate::boxed::Box;
+    #[cfg(target_arch = "wasm32")]
+    pub fn run_ctors_once() {
+        wit_bindgen_rt::run_ctors_once();
+    }
     pub fn as_i32<T: AsI32>(t: T) -> i32 {
         t.as_i32()
     }
@@ -641,11 +4360,27 @@ mod _rt {
             self as i32
         }
     }
-    pub unsafe fn invalid_enum_discriminant<T>() -> T {
-        if cfg!(debug_assertions) {
-            panic!("invalid enum discriminant")
-        } else {
-            unsafe { core::hint::unreachable_unchecked() }
+    pub fn as_i64<T: AsI64>(t: T) -> i64 {
+        t.as_i64()
+    }
+    pub trait AsI64 {
+        fn as_i64(self) -> i64;
+    }
+    impl<'a, T: Copy + AsI64> AsI64 for &'a T {
+        fn as_i64(self) -> i64 {
+            (*self).as_i64()
+        }
+    }
+    impl AsI64 for i64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+    }
+    impl AsI64 for u64 {
+        #[inline]
+        fn as_i64(self) -> i64 {
+            self as i64
         }
     }
     extern crate alloc as alloc_crate;
@@ -669,39 +4404,70 @@ mod _rt {
 /// ```
 #[allow(unused_macros)]
 #[doc(hidden)]
-macro_rules! __export_discord_impl {
+macro_rules! __export_discord_with_config_impl {
     ($ty:ident) => {
         self::export!($ty with_types_in self);
     };
     ($ty:ident with_types_in $($path_to_types_root:tt)*) => {
-        $($path_to_types_root)*:: __export_world_discord_cabi!($ty with_types_in
-        $($path_to_types_root)*);
+        $($path_to_types_root)*::
+        exports::wassemble::discord::api::__export_wassemble_discord_api_0_2_0_cabi!($ty
+        with_types_in $($path_to_types_root)*:: exports::wassemble::discord::api);
     };
 }
 #[doc(inline)]
-pub(crate) use __export_discord_impl as export;
+pub(crate) use __export_discord_with_config_impl as export;
 #[cfg(target_arch = "wasm32")]
 #[unsafe(
-    link_section = "component-type:wit-bindgen:0.41.0:wassemble:discord@0.1.0:discord:encoded world"
+    link_section = "component-type:wit-bindgen:0.41.0:wassemble:discord@0.2.0:discord-with-config:encoded world"
 )]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 731] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xdd\x04\x01A\x02\x01\
-A\x19\x01ks\x01r\x03\x07contents\x0achannel-ids\x08guild-id\0\x03\0\x07message\x03\
-\0\x01\x01r\x03\x02ids\x05tokens\x03urls\x03\0\x07webhook\x03\0\x03\x01r\x04\x02\
-ids\x08usernames\x0ddiscriminators\x06avatar\0\x03\0\x04user\x03\0\x05\x01r\x04\x02\
-ids\x04names\x02tyy\x08guild-id\0\x03\0\x07channel\x03\0\x07\x01@\x03\x05tokens\x0a\
-channel-ids\x04names\0\x04\x04\0\x0ecreate-webhook\x01\x09\x01@\x03\x05tokens\x0a\
-webhook-ids\x0dwebhook-tokens\0\x7f\x04\0\x0edelete-webhook\x01\x0a\x01@\x03\x05\
-tokens\x0achannel-ids\x0amessage-ids\0\x7f\x04\0\x0edelete-message\x01\x0b\x01@\x04\
-\x05tokens\x0achannel-ids\x0amessage-ids\x07contents\0\x7f\x04\0\x0cedit-message\
-\x01\x0c\x01@\x02\x05tokens\x0achannel-ids\0\x08\x04\0\x0bget-channel\x01\x0d\x01\
-@\x02\x05tokens\x07user-ids\0\x06\x04\0\x08get-user\x01\x0e\x01@\x02\x05tokens\x07\
-message\x02\0s\x04\0\x0csend-message\x01\x0f\x01@\x03\x05tokens\x07webhook\x04\x07\
-contents\0s\x04\0\x14send-webhook-message\x01\x10\x04\0\x1fwassemble:discord/dis\
-cord@0.1.0\x04\0\x0b\x0d\x01\0\x07discord\x03\0\0\0G\x09producers\x01\x0cprocess\
-ed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\x060.41.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 2059] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\x81\x0f\x01A\x02\x01\
+A\x0e\x01B\x0b\x01q\x02\x08upstream\x01s\0\x02io\x01s\0\x04\0\x05error\x03\0\0\x01\
+ks\x01j\x01\x02\x01\x01\x01@\x01\x03keys\0\x03\x04\0\x03get\x01\x04\x01o\x02ss\x01\
+p\x05\x01j\x01\x06\x01\x01\x01@\0\0\x07\x04\0\x07get-all\x01\x08\x03\0\x1dwasi:c\
+onfig/store@0.2.0-draft\x05\0\x01B\x1a\x01p{\x01r\x04\x0cmax-attemptsy\x10initia\
+l-delay-msy\x0cmax-delay-msy\x0eretry-statuses\0\x04\0\x0cretry-config\x03\0\x01\
+\x01ks\x01ky\x01k\x02\x01r\x05\x05tokens\x08base-url\x03\x0auser-agent\x03\x12co\
+nnect-timeout-ms\x04\x05retry\x05\x04\0\x06config\x03\0\x06\x01r\x03\x07contents\
+\x0achannel-ids\x08guild-id\x03\x04\0\x07message\x03\0\x08\x01r\x03\x02ids\x05to\
+kens\x03urls\x04\0\x07webhook\x03\0\x0a\x01r\x04\x02ids\x08usernames\x0ddiscrimi\
+nators\x06avatar\x03\x04\0\x04user\x03\0\x0c\x01r\x06\x02ids\x0achannel-ids\x06a\
+uthor\x0d\x07contents\x09timestamps\x10edited-timestamp\x03\x04\0\x0fchannel-mes\
+sage\x03\0\x0e\x01r\x04\x02ids\x04names\x02tyy\x08guild-id\x03\x04\0\x07channel\x03\
+\0\x10\x01r\x04\x06status{\x04codey\x07messages\x06errors\x03\x04\0\x09api-error\
+\x03\0\x12\x01r\x05\x07messages\x0eretry-after-msw\x06global\x7f\x05scope\x03\x06\
+bucket\x03\x04\0\x0arate-limit\x03\0\x14\x01r\x04\x06bucket\x03\x05limity\x09rem\
+ainingy\x0ereset-after-msw\x04\0\x11rate-limit-status\x03\0\x16\x01q\x07\x0erequ\
+est-failed\x01s\0\x13response-body-error\x01s\0\x0autf8-error\x01s\0\x10json-par\
+se-error\x01s\0\x0cconfig-error\x01s\0\x09api-error\x01\x13\0\x0crate-limited\x01\
+\x15\0\x04\0\x0ddiscord-error\x03\0\x18\x03\0\x1dwassemble:discord/types@0.2.0\x05\
+\x01\x02\x03\0\x01\x06config\x02\x03\0\x01\x07message\x02\x03\0\x01\x07webhook\x02\
+\x03\0\x01\x04user\x02\x03\0\x01\x0fchannel-message\x02\x03\0\x01\x07channel\x02\
+\x03\0\x01\x11rate-limit-status\x02\x03\0\x01\x0ddiscord-error\x01B.\x02\x03\x02\
+\x01\x02\x04\0\x06config\x03\0\0\x02\x03\x02\x01\x03\x04\0\x07message\x03\0\x02\x02\
+\x03\x02\x01\x04\x04\0\x07webhook\x03\0\x04\x02\x03\x02\x01\x05\x04\0\x04user\x03\
+\0\x06\x02\x03\x02\x01\x06\x04\0\x0fchannel-message\x03\0\x08\x02\x03\x02\x01\x07\
+\x04\0\x07channel\x03\0\x0a\x02\x03\x02\x01\x08\x04\0\x11rate-limit-status\x03\0\
+\x0c\x02\x03\x02\x01\x09\x04\0\x0ddiscord-error\x03\0\x0e\x04\0\x06client\x03\x01\
+\x01i\x10\x01@\x01\x06config\x01\0\x11\x04\0\x13[constructor]client\x01\x12\x01h\
+\x10\x01j\x01\x05\x01\x0f\x01@\x03\x04self\x13\x0achannel-ids\x04names\0\x14\x04\
+\0\x1d[method]client.create-webhook\x01\x15\x01j\0\x01\x0f\x01@\x03\x04self\x13\x0a\
+webhook-ids\x0dwebhook-tokens\0\x16\x04\0\x1d[method]client.delete-webhook\x01\x17\
+\x01@\x03\x04self\x13\x0achannel-ids\x0amessage-ids\0\x16\x04\0\x1d[method]clien\
+t.delete-message\x01\x18\x01j\x01\x09\x01\x0f\x01@\x04\x04self\x13\x0achannel-id\
+s\x0amessage-ids\x07contents\0\x19\x04\0\x1b[method]client.edit-message\x01\x1a\x01\
+j\x01\x0b\x01\x0f\x01@\x02\x04self\x13\x0achannel-ids\0\x1b\x04\0\x1a[method]cli\
+ent.get-channel\x01\x1c\x01j\x01\x07\x01\x0f\x01@\x02\x04self\x13\x07user-ids\0\x1d\
+\x04\0\x17[method]client.get-user\x01\x1e\x01j\x01s\x01\x0f\x01@\x02\x04self\x13\
+\x07message\x03\0\x1f\x04\0\x1b[method]client.send-message\x01\x20\x01@\x03\x04s\
+elf\x13\x07webhook\x05\x07contents\0\x1f\x04\0#[method]client.send-webhook-messa\
+ge\x01!\x01k\x0d\x01@\x01\x04self\x13\0\"\x04\0\x1e[method]client.last-rate-limi\
+t\x01#\x04\0\x1bwassemble:discord/api@0.2.0\x05\x0a\x04\0+wassemble:discord/disc\
+ord-with-config@0.2.0\x04\0\x0b\x19\x01\0\x13discord-with-config\x03\0\0\0G\x09p\
+roducers\x01\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10wit-bindgen-rust\
+\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
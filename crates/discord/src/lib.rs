@@ -1,14 +1,30 @@
-use bindings::Guest;
+use std::time::Duration;
+
+use http_core::{Backoff, Client, Error, HeaderMap, Response};
 use serde::{Deserialize, Serialize};
-use waki::Client;
 
-use crate::bindings::{Channel, Message, User, Webhook};
+#[cfg(feature = "wasi-config")]
+use crate::bindings::wasi::config::store;
+use crate::bindings::{
+    exports::wassemble::discord::api::{Guest, GuestClient},
+    wassemble::discord::types::{
+        ApiError, Channel, ChannelMessage, Config, DiscordError, Message, RateLimit,
+        RateLimitStatus, User, Webhook,
+    },
+};
 
 #[allow(warnings)]
 mod bindings;
 
 const DISCORD_API_BASE: &str = "https://discord.com/api/v10";
 
+/// Discord asks bots to identify themselves with this user agent format.
+const DISCORD_USER_AGENT: &str = concat!(
+    "DiscordBot (https://github.com/wassemble/components, ",
+    env!("CARGO_PKG_VERSION"),
+    ")"
+);
+
 // bump
 
 #[derive(Deserialize, Serialize)]
@@ -35,6 +51,16 @@ struct DiscordChannel {
     guild_id: Option<String>,
 }
 
+#[derive(Deserialize)]
+struct DiscordMessage {
+    id: String,
+    channel_id: String,
+    author: DiscordUser,
+    content: String,
+    timestamp: String,
+    edited_timestamp: Option<String>,
+}
+
 #[derive(Deserialize)]
 struct DiscordUser {
     id: String,
@@ -43,222 +69,276 @@ struct DiscordUser {
     avatar: Option<String>,
 }
 
+impl From<DiscordUser> for User {
+    fn from(user: DiscordUser) -> Self {
+        User {
+            id: user.id,
+            username: user.username,
+            discriminator: user.discriminator,
+            avatar: user.avatar,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct DiscordErrorBody {
+    #[serde(default)]
+    code: u32,
+    message: String,
+    errors: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct DiscordRateLimitBody {
+    #[serde(default)]
+    global: bool,
+    message: String,
+    retry_after: f64,
+}
+
+impl From<Error> for DiscordError {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::Build(message) | Error::Connect(message) | Error::Request(message) => {
+                DiscordError::RequestFailed(message)
+            }
+            Error::ResponseBody(message) => DiscordError::ResponseBodyError(message),
+            Error::Utf8(message) => DiscordError::Utf8Error(message),
+            Error::Json(message) => DiscordError::JsonParseError(message),
+            Error::Status(response) => error_from_response(&response),
+        }
+    }
+}
+
+fn error_from_response(response: &Response) -> DiscordError {
+    let status = response.status();
+    if status == 429
+        && let Ok(body) = response.json::<DiscordRateLimitBody>()
+    {
+        return DiscordError::RateLimited(RateLimit {
+            message: body.message,
+            retry_after_ms: (body.retry_after * 1000.0).ceil() as u64,
+            global: body.global,
+            scope: response.header("x-ratelimit-scope").map(str::to_string),
+            bucket: response.header("x-ratelimit-bucket").map(str::to_string),
+        });
+    }
+
+    let body = String::from_utf8_lossy(response.body());
+    let error = match serde_json::from_str::<DiscordErrorBody>(&body) {
+        Ok(error) => ApiError {
+            status,
+            code: error.code,
+            message: error.message,
+            errors: error.errors.map(|errors| errors.to_string()),
+        },
+        Err(_) => ApiError {
+            status,
+            code: 0,
+            message: body.to_string(),
+            errors: None,
+        },
+    };
+    DiscordError::ApiError(error)
+}
+
+/// Discord sends the precise delay as `retry_after` in the body of a 429; the
+/// `retry-after` header is rounded up to whole seconds.
+fn rate_limit_delay(response: &Response) -> Option<Duration> {
+    if response.status() == 429
+        && let Ok(body) = response.json::<DiscordRateLimitBody>()
+    {
+        return Duration::try_from_secs_f64(body.retry_after).ok();
+    }
+    http_core::retry_after(response)
+}
+
+fn rate_limit_status(headers: &HeaderMap) -> Option<RateLimitStatus> {
+    let header = |name| headers.get(name)?.to_str().ok();
+    let reset_after: f64 = header("x-ratelimit-reset-after")?.parse().ok()?;
+    Some(RateLimitStatus {
+        bucket: header("x-ratelimit-bucket").map(str::to_string),
+        limit: header("x-ratelimit-limit")?.parse().ok()?,
+        remaining: header("x-ratelimit-remaining")?.parse().ok()?,
+        reset_after_ms: (reset_after * 1000.0).ceil() as u64,
+    })
+}
+
+/// Returns `value`, or the `key` entry of `wasi:config/store` when `value` is empty.
+#[cfg(feature = "wasi-config")]
+fn config_fallback(value: String, key: &str) -> Result<Option<String>, DiscordError> {
+    if !value.is_empty() {
+        return Ok(Some(value));
+    }
+    store::get(key).map_err(|error| match error {
+        store::Error::Upstream(message) | store::Error::Io(message) => DiscordError::ConfigError(
+            format!("Failed to read {key} from wasi:config/store: {message}"),
+        ),
+    })
+}
+
+/// Returns `value`, or `None` when it is empty.
+#[cfg(not(feature = "wasi-config"))]
+fn config_fallback(value: String, _key: &str) -> Result<Option<String>, DiscordError> {
+    Ok(Some(value).filter(|value| !value.is_empty()))
+}
+
 struct Component;
 
 impl Guest for Component {
-    fn create_webhook(token: String, channel_id: String, name: String) -> Webhook {
-        let response = Client::new()
-            .post(&format!(
-                "{DISCORD_API_BASE}/channels/{channel_id}/webhooks"
-            ))
-            .header("Authorization", format!("Bot {token}"))
-            .header("Content-Type", "application/json")
-            .json(&serde_json::json!({
-                "name": name
-            }))
-            .send()
-            .map_err(|e| format!("Failed to send request: {e}"))
-            .unwrap();
+    type Client = DiscordClient;
+}
 
-        let body = response
-            .body()
-            .map_err(|e| format!("Failed to get response body: {e}"))
-            .unwrap();
+/// Backs the `client` resource, reusing one configured HTTP client for every call.
+///
+/// A failure to read credentials from `wasi:config/store` can't be reported by the
+/// constructor, so it is kept and returned from every method instead.
+struct DiscordClient {
+    http: Result<Client, DiscordError>,
+}
 
-        let body_str = String::from_utf8(body)
-            .map_err(|e| format!("Failed to parse response as UTF-8: {e}"))
-            .unwrap();
+impl DiscordClient {
+    fn build_http(config: Config) -> Result<Client, DiscordError> {
+        let mut http = Client::new(config.base_url.as_deref().unwrap_or(DISCORD_API_BASE)).header(
+            "User-Agent",
+            config.user_agent.as_deref().unwrap_or(DISCORD_USER_AGENT),
+        );
+        if let Some(token) = config_fallback(config.token, "discord_bot_token")? {
+            http = http.header("Authorization", format!("Bot {token}"));
+        }
+        if let Some(timeout) = config.connect_timeout_ms {
+            http = http.connect_timeout(Duration::from_millis(timeout.into()));
+        }
+        let mut backoff = Backoff::default().rate_limit_delay(rate_limit_delay);
+        if let Some(retry) = config.retry {
+            backoff = backoff
+                .max_attempts(retry.max_attempts)
+                .initial_delay(Duration::from_millis(retry.initial_delay_ms.into()))
+                .max_delay(Duration::from_millis(retry.max_delay_ms.into()))
+                .statuses(retry.retry_statuses);
+        }
+        Ok(http.retry_policy(backoff))
+    }
+
+    fn http(&self) -> Result<&Client, DiscordError> {
+        self.http.as_ref().map_err(Clone::clone)
+    }
+}
+
+impl GuestClient for DiscordClient {
+    fn new(config: Config) -> Self {
+        Self {
+            http: Self::build_http(config),
+        }
+    }
 
-        let webhook: DiscordWebhook = serde_json::from_str(&body_str).unwrap();
-        Webhook {
+    fn create_webhook(&self, channel_id: String, name: String) -> Result<Webhook, DiscordError> {
+        let webhook: DiscordWebhook = self
+            .http()?
+            .post(&format!("/channels/{channel_id}/webhooks"))
+            .json(&serde_json::json!({
+                "name": name
+            }))
+            .send_json()?;
+        Ok(Webhook {
             id: webhook.id,
             token: webhook.token,
             url: webhook.url,
-        }
+        })
     }
 
-    fn delete_webhook(token: String, webhook_id: String, webhook_token: String) -> bool {
-        let response = Client::new()
-            .delete(&format!(
-                "{DISCORD_API_BASE}/webhooks/{webhook_id}/{webhook_token}"
-            ))
-            .header("Authorization", format!("Bot {token}"))
-            .send()
-            .map_err(|e| format!("Failed to send request: {e}"))
-            .unwrap();
-
-        let body = response
-            .body()
-            .map_err(|e| format!("Failed to get response body: {e}"))
-            .unwrap();
-
-        let body_str = String::from_utf8(body)
-            .map_err(|e| format!("Failed to parse response as UTF-8: {e}"))
-            .unwrap();
-
-        body_str.contains("200")
+    fn delete_webhook(
+        &self,
+        webhook_id: String,
+        webhook_token: String,
+    ) -> Result<(), DiscordError> {
+        // Discord answers successful deletes with 204 No Content
+        self.http()?
+            .delete(&format!("/webhooks/{webhook_id}/{webhook_token}"))
+            .send()?;
+        Ok(())
     }
 
-    fn delete_message(token: String, channel_id: String, message_id: String) -> bool {
-        let response = Client::new()
-            .delete(&format!(
-                "{DISCORD_API_BASE}/channels/{channel_id}/messages/{message_id}"
-            ))
-            .header("Authorization", format!("Bot {token}"))
-            .send()
-            .map_err(|e| format!("Failed to send request: {e}"))
-            .unwrap();
-
-        let body = response
-            .body()
-            .map_err(|e| format!("Failed to get response body: {e}"))
-            .unwrap();
-
-        let body_str = String::from_utf8(body)
-            .map_err(|e| format!("Failed to parse response as UTF-8: {e}"))
-            .unwrap();
-
-        body_str.contains("200")
+    fn delete_message(&self, channel_id: String, message_id: String) -> Result<(), DiscordError> {
+        // Discord answers successful deletes with 204 No Content
+        self.http()?
+            .delete(&format!("/channels/{channel_id}/messages/{message_id}"))
+            .send()?;
+        Ok(())
     }
 
     fn edit_message(
-        token: String,
+        &self,
         channel_id: String,
         message_id: String,
         content: String,
-    ) -> bool {
-        let response = Client::new()
-            .patch(&format!(
-                "{DISCORD_API_BASE}/channels/{channel_id}/messages/{message_id}"
-            ))
-            .header("Authorization", format!("Bot {token}"))
-            .header("Content-Type", "application/json")
+    ) -> Result<ChannelMessage, DiscordError> {
+        let message: DiscordMessage = self
+            .http()?
+            .patch(&format!("/channels/{channel_id}/messages/{message_id}"))
             .json(&serde_json::json!({
                 "content": content
             }))
-            .send()
-            .map_err(|e| format!("Failed to send request: {e}"))
-            .unwrap();
-
-        let body = response
-            .body()
-            .map_err(|e| format!("Failed to get response body: {e}"))
-            .unwrap();
-
-        let body_str = String::from_utf8(body)
-            .map_err(|e| format!("Failed to parse response as UTF-8: {e}"))
-            .unwrap();
-
-        body_str.contains("200")
+            .send_json()?;
+        Ok(ChannelMessage {
+            id: message.id,
+            channel_id: message.channel_id,
+            author: message.author.into(),
+            content: message.content,
+            timestamp: message.timestamp,
+            edited_timestamp: message.edited_timestamp,
+        })
     }
 
-    fn get_channel(token: String, channel_id: String) -> Channel {
-        let response = Client::new()
-            .get(&format!("{DISCORD_API_BASE}/channels/{channel_id}"))
-            .header("Authorization", format!("Bot {token}"))
-            .send()
-            .map_err(|e| format!("Failed to send request: {e}"))
-            .unwrap();
-
-        let body = response
-            .body()
-            .map_err(|e| format!("Failed to get response body: {e}"))
-            .unwrap();
-
-        let body_str = String::from_utf8(body)
-            .map_err(|e| format!("Failed to parse response as UTF-8: {e}"))
-            .unwrap();
-
-        let channel: DiscordChannel = serde_json::from_str(&body_str).unwrap();
-        Channel {
+    fn get_channel(&self, channel_id: String) -> Result<Channel, DiscordError> {
+        let channel: DiscordChannel = self
+            .http()?
+            .get(&format!("/channels/{channel_id}"))
+            .send_json()?;
+        Ok(Channel {
             id: channel.id,
             name: channel.name,
             ty: channel.ty,
             guild_id: channel.guild_id,
-        }
+        })
     }
 
-    fn get_user(token: String, user_id: String) -> User {
-        let response = Client::new()
-            .get(&format!("{DISCORD_API_BASE}/users/{user_id}"))
-            .header("Authorization", format!("Bot {token}"))
-            .send()
-            .map_err(|e| format!("Failed to send request: {e}"))
-            .unwrap();
-
-        let body = response
-            .body()
-            .map_err(|e| format!("Failed to get response body: {e}"))
-            .unwrap();
-
-        let body_str = String::from_utf8(body)
-            .map_err(|e| format!("Failed to parse response as UTF-8: {e}"))
-            .unwrap();
-
-        let user: DiscordUser = serde_json::from_str(&body_str).unwrap();
-        User {
-            id: user.id,
-            username: user.username,
-            discriminator: user.discriminator,
-            avatar: user.avatar,
-        }
+    fn get_user(&self, user_id: String) -> Result<User, DiscordError> {
+        let user: DiscordUser = self.http()?.get(&format!("/users/{user_id}")).send_json()?;
+        Ok(user.into())
     }
 
-    fn send_message(token: String, message: Message) -> String {
-        let response = Client::new()
-            .post(&format!(
-                "{}/channels/{}/messages",
-                DISCORD_API_BASE, message.channel_id
-            ))
-            .header("Authorization", format!("Bot {token}"))
-            .header("Content-Type", "application/json")
+    fn send_message(&self, message: Message) -> Result<String, DiscordError> {
+        let message_data: DiscordResponse<()> = self
+            .http()?
+            .post(&format!("/channels/{}/messages", message.channel_id))
             .json(&serde_json::json!({
                 "content": message.content,
                 "guild_id": message.guild_id
             }))
-            .send()
-            .map_err(|e| format!("Failed to send request: {e}"))
-            .unwrap();
-
-        let body = response
-            .body()
-            .map_err(|e| format!("Failed to get response body: {e}"))
-            .unwrap();
-
-        let body_str = String::from_utf8(body)
-            .map_err(|e| format!("Failed to parse response as UTF-8: {e}"))
-            .unwrap();
-
-        let message_data: DiscordResponse<()> = serde_json::from_str(&body_str).unwrap();
-        message_data.id
+            .send_json()?;
+        Ok(message_data.id)
     }
 
-    fn send_webhook_message(token: String, webhook: Webhook, content: String) -> String {
-        let response = Client::new()
-            .post(&format!(
-                "{}/webhooks/{}/{}",
-                DISCORD_API_BASE, webhook.id, webhook.token
-            ))
-            .header("Authorization", format!("Bot {token}"))
-            .header("Content-Type", "application/json")
+    fn send_webhook_message(
+        &self,
+        webhook: Webhook,
+        content: String,
+    ) -> Result<String, DiscordError> {
+        let message_data: DiscordResponse<()> = self
+            .http()?
+            .post(&format!("/webhooks/{}/{}", webhook.id, webhook.token))
+            // without wait, Discord answers 204 with no message to read the ID from
+            .query("wait", "true")
             .json(&serde_json::json!({
                 "content": content
             }))
-            .send()
-            .map_err(|e| format!("Failed to send request: {e}"))
-            .unwrap();
-
-        let body = response
-            .body()
-            .map_err(|e| format!("Failed to get response body: {e}"))
-            .unwrap();
-
-        let body_str = String::from_utf8(body)
-            .map_err(|e| format!("Failed to parse response as UTF-8: {e}"))
-            .unwrap();
+            .send_json()?;
+        Ok(message_data.id)
+    }
 
-        let message_data: DiscordResponse<()> = serde_json::from_str(&body_str).unwrap();
-        message_data.id
+    fn last_rate_limit(&self) -> Option<RateLimitStatus> {
+        rate_limit_status(&self.http().ok()?.last_response_headers()?)
     }
 }
 
@@ -267,6 +347,7 @@ bindings::export!(Component with_types_in bindings);
 #[cfg(test)]
 mod tests {
     use super::*;
+
     #[test]
     fn test_discord_channel_struct() {
         let channel = Channel {
@@ -280,4 +361,135 @@ mod tests {
         assert_eq!(channel.ty, 0);
         assert_eq!(channel.guild_id, Some("guild1".to_string()));
     }
+
+    #[test]
+    fn test_discord_message_deserialization() {
+        let body = r#"{
+            "id": "1",
+            "channel_id": "2",
+            "author": {"id": "3", "username": "bot", "discriminator": "0000", "avatar": null},
+            "content": "edited",
+            "timestamp": "2024-01-01T00:00:00.000000+00:00",
+            "edited_timestamp": "2024-01-01T00:05:00.000000+00:00",
+            "tts": false
+        }"#;
+
+        let message: DiscordMessage = serde_json::from_str(body).unwrap();
+        assert_eq!(message.id, "1");
+        assert_eq!(message.author.username, "bot");
+        assert_eq!(
+            message.edited_timestamp.as_deref(),
+            Some("2024-01-01T00:05:00.000000+00:00")
+        );
+    }
+
+    #[test]
+    fn test_error_from_response_missing_permissions() {
+        let body = r#"{"message": "Missing Permissions", "code": 50013}"#;
+
+        let DiscordError::ApiError(error) =
+            error_from_response(&Response::new(403, HeaderMap::new(), body.into()))
+        else {
+            panic!("Expected an API error");
+        };
+        assert_eq!(error.status, 403);
+        assert_eq!(error.code, 50013);
+        assert_eq!(error.message, "Missing Permissions");
+        assert_eq!(error.errors, None);
+    }
+
+    #[test]
+    fn test_error_from_response_form_body_errors() {
+        let body = r#"{
+            "code": 50035,
+            "errors": {"content": {"_errors": [{"code": "BASE_TYPE_MAX_LENGTH", "message": "Must be 2000 or fewer in length."}]}},
+            "message": "Invalid Form Body"
+        }"#;
+
+        let DiscordError::ApiError(error) =
+            error_from_response(&Response::new(400, HeaderMap::new(), body.into()))
+        else {
+            panic!("Expected an API error");
+        };
+        assert_eq!(error.code, 50035);
+        let errors: serde_json::Value = serde_json::from_str(&error.errors.unwrap()).unwrap();
+        assert_eq!(
+            errors["content"]["_errors"][0]["code"],
+            "BASE_TYPE_MAX_LENGTH"
+        );
+    }
+
+    #[test]
+    fn test_error_from_response_non_json_body() {
+        let DiscordError::ApiError(error) =
+            error_from_response(&Response::new(502, HeaderMap::new(), "Bad Gateway".into()))
+        else {
+            panic!("Expected an API error");
+        };
+        assert_eq!(error.status, 502);
+        assert_eq!(error.code, 0);
+        assert_eq!(error.message, "Bad Gateway");
+    }
+
+    #[test]
+    fn test_error_from_response_rate_limited() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-scope", "user".parse().unwrap());
+        let body =
+            r#"{"message": "You are being rate limited.", "retry_after": 64.57, "global": false}"#;
+        let response = Response::new(429, headers, body.into());
+
+        let DiscordError::RateLimited(rate_limit) = error_from_response(&response) else {
+            panic!("Expected a rate limit error");
+        };
+        assert_eq!(rate_limit.retry_after_ms, 64570);
+        assert!(!rate_limit.global);
+        assert_eq!(rate_limit.scope.as_deref(), Some("user"));
+        assert_eq!(
+            rate_limit_delay(&response),
+            Some(Duration::from_secs_f64(64.57))
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_status() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-limit", "5".parse().unwrap());
+        headers.insert("x-ratelimit-remaining", "1".parse().unwrap());
+        headers.insert("x-ratelimit-reset-after", "1.25".parse().unwrap());
+        headers.insert("x-ratelimit-bucket", "abcd1234".parse().unwrap());
+
+        let status = rate_limit_status(&headers).unwrap();
+        assert_eq!(status.limit, 5);
+        assert_eq!(status.remaining, 1);
+        assert_eq!(status.reset_after_ms, 1250);
+        assert_eq!(status.bucket.as_deref(), Some("abcd1234"));
+    }
+
+    #[test]
+    fn test_client_base_url() {
+        let config = Config {
+            token: "token".to_string(),
+            base_url: None,
+            user_agent: None,
+            connect_timeout_ms: None,
+            retry: None,
+        };
+        assert_eq!(
+            DiscordClient::new(config.clone())
+                .http()
+                .unwrap()
+                .base_url(),
+            DISCORD_API_BASE
+        );
+
+        let config = Config {
+            base_url: Some("http://localhost:8080/api/v10".to_string()),
+            ..config
+        };
+        assert_eq!(
+            DiscordClient::new(config).http().unwrap().base_url(),
+            "http://localhost:8080/api/v10"
+        );
+    }
 }
@@ -1,344 +1,308 @@
 // Generated by `wit-bindgen` 0.41.0. DO NOT EDIT!
 // Options used:
 //   * runtime_path: "wit_bindgen_rt"
-pub type Issue = wassemble::github::types::Issue;
-pub type Repository = wassemble::github::types::Repository;
-pub type User = wassemble::github::types::User;
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn _export_create_issue_cabi<T: Guest>(
-    arg0: *mut u8,
-    arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
-    arg4: *mut u8,
-    arg5: usize,
-    arg6: *mut u8,
-    arg7: usize,
-    arg8: *mut u8,
-    arg9: usize,
-) -> *mut u8 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let len2 = arg5;
-    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
-    let len3 = arg7;
-    let bytes3 = _rt::Vec::from_raw_parts(arg6.cast(), len3, len3);
-    let len4 = arg9;
-    let bytes4 = _rt::Vec::from_raw_parts(arg8.cast(), len4, len4);
-    let result5 = T::create_issue(
-        _rt::string_lift(bytes0),
-        _rt::string_lift(bytes1),
-        _rt::string_lift(bytes2),
-        _rt::string_lift(bytes3),
-        _rt::string_lift(bytes4),
-    );
-    let ptr6 = (&raw mut _RET_AREA.0).cast::<u8>();
-    let wassemble::github::types::Issue {
-        body: body7,
-        number: number7,
-        title: title7,
-    } = result5;
-    let vec8 = (body7.into_bytes()).into_boxed_slice();
-    let ptr8 = vec8.as_ptr().cast::<u8>();
-    let len8 = vec8.len();
-    ::core::mem::forget(vec8);
-    *ptr6.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
-    *ptr6.add(0).cast::<*mut u8>() = ptr8.cast_mut();
-    *ptr6.add(2 * ::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(
-        number7,
-    );
-    let vec9 = (title7.into_bytes()).into_boxed_slice();
-    let ptr9 = vec9.as_ptr().cast::<u8>();
-    let len9 = vec9.len();
-    ::core::mem::forget(vec9);
-    *ptr6.add(4 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len9;
-    *ptr6.add(3 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr9
-        .cast_mut();
-    ptr6
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn __post_return_create_issue<T: Guest>(arg0: *mut u8) {
-    let l0 = *arg0.add(0).cast::<*mut u8>();
-    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l0, l1, 1);
-    let l2 = *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-    let l3 = *arg0.add(4 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l2, l3, 1);
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn _export_create_repository_cabi<T: Guest>(
-    arg0: *mut u8,
-    arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
-    arg4: *mut u8,
-    arg5: usize,
-) -> *mut u8 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let len2 = arg5;
-    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
-    let result3 = T::create_repository(
-        _rt::string_lift(bytes0),
-        _rt::string_lift(bytes1),
-        _rt::string_lift(bytes2),
-    );
-    let ptr4 = (&raw mut _RET_AREA.0).cast::<u8>();
-    let wassemble::github::types::Repository {
-        name: name5,
-        owner: owner5,
-        description: description5,
-    } = result3;
-    let vec6 = (name5.into_bytes()).into_boxed_slice();
-    let ptr6 = vec6.as_ptr().cast::<u8>();
-    let len6 = vec6.len();
-    ::core::mem::forget(vec6);
-    *ptr4.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len6;
-    *ptr4.add(0).cast::<*mut u8>() = ptr6.cast_mut();
-    let vec7 = (owner5.into_bytes()).into_boxed_slice();
-    let ptr7 = vec7.as_ptr().cast::<u8>();
-    let len7 = vec7.len();
-    ::core::mem::forget(vec7);
-    *ptr4.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len7;
-    *ptr4.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr7
-        .cast_mut();
-    let vec8 = (description5.into_bytes()).into_boxed_slice();
-    let ptr8 = vec8.as_ptr().cast::<u8>();
-    let len8 = vec8.len();
-    ::core::mem::forget(vec8);
-    *ptr4.add(5 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
-    *ptr4.add(4 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr8
-        .cast_mut();
-    ptr4
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn __post_return_create_repository<T: Guest>(arg0: *mut u8) {
-    let l0 = *arg0.add(0).cast::<*mut u8>();
-    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l0, l1, 1);
-    let l2 = *arg0.add(2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-    let l3 = *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l2, l3, 1);
-    let l4 = *arg0.add(4 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-    let l5 = *arg0.add(5 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l4, l5, 1);
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn _export_delete_repository_cabi<T: Guest>(
-    arg0: *mut u8,
-    arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
-    arg4: *mut u8,
-    arg5: usize,
-) -> i32 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let len2 = arg5;
-    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
-    let result3 = T::delete_repository(
-        _rt::string_lift(bytes0),
-        _rt::string_lift(bytes1),
-        _rt::string_lift(bytes2),
-    );
-    match result3 {
-        true => 1,
-        false => 0,
+#[rustfmt::skip]
+#[allow(dead_code, clippy::all)]
+pub mod wasi {
+    pub mod config {
+        #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+        pub mod store {
+            #[used]
+            #[doc(hidden)]
+            static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
+            use super::super::super::_rt;
+            /// An error type that encapsulates the different errors that can occur fetching configuration values.
+            #[derive(Clone)]
+            pub enum Error {
+                /// This indicates an error from an "upstream" config source.
+                /// As this could be almost _anything_ (such as Vault, Kubernetes ConfigMaps, KeyValue buckets, etc),
+                /// the error message is a string.
+                Upstream(_rt::String),
+                /// This indicates an error from an I/O operation.
+                /// As this could be almost _anything_ (such as a file read, network connection, etc),
+                /// the error message is a string.
+                /// Depending on how this ends up being consumed,
+                /// we may consider moving this to use the `wasi:io/error` type instead.
+                /// For simplicity right now in supporting multiple implementations, it is being left as a string.
+                Io(_rt::String),
+            }
+            impl ::core::fmt::Debug for Error {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        Error::Upstream(e) => {
+                            f.debug_tuple("Error::Upstream").field(e).finish()
+                        }
+                        Error::Io(e) => f.debug_tuple("Error::Io").field(e).finish(),
+                    }
+                }
+            }
+            impl ::core::fmt::Display for Error {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    write!(f, "{:?}", self)
+                }
+            }
+            impl std::error::Error for Error {}
+            #[allow(unused_unsafe, clippy::all)]
+            /// Gets a configuration value of type `string` associated with the `key`.
+            ///
+            /// The value is returned as an `option<string>`. If the key is not found,
+            /// `Ok(none)` is returned. If an error occurs, an `Err(error)` is returned.
+            pub fn get(key: &str) -> Result<Option<_rt::String>, Error> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 4
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let vec0 = key;
+                    let ptr0 = vec0.as_ptr().cast::<u8>();
+                    let len0 = vec0.len();
+                    let ptr1 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "wasi:config/store@0.2.0-draft")]
+                    unsafe extern "C" {
+                        #[link_name = "get"]
+                        fn wit_import2(_: *mut u8, _: usize, _: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import2(_: *mut u8, _: usize, _: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import2(ptr0.cast_mut(), len0, ptr1) };
+                    let l3 = i32::from(*ptr1.add(0).cast::<u8>());
+                    let result16 = match l3 {
+                        0 => {
+                            let e = {
+                                let l4 = i32::from(
+                                    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                );
+                                match l4 {
+                                    0 => None,
+                                    1 => {
+                                        let e = {
+                                            let l5 = *ptr1
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l6 = *ptr1
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len7 = l6;
+                                            let bytes7 = _rt::Vec::from_raw_parts(
+                                                l5.cast(),
+                                                len7,
+                                                len7,
+                                            );
+                                            _rt::string_lift(bytes7)
+                                        };
+                                        Some(e)
+                                    }
+                                    _ => _rt::invalid_enum_discriminant(),
+                                }
+                            };
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l8 = i32::from(
+                                    *ptr1.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                );
+                                let v15 = match l8 {
+                                    0 => {
+                                        let e15 = {
+                                            let l9 = *ptr1
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l10 = *ptr1
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len11 = l10;
+                                            let bytes11 = _rt::Vec::from_raw_parts(
+                                                l9.cast(),
+                                                len11,
+                                                len11,
+                                            );
+                                            _rt::string_lift(bytes11)
+                                        };
+                                        Error::Upstream(e15)
+                                    }
+                                    n => {
+                                        debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                        let e15 = {
+                                            let l12 = *ptr1
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l13 = *ptr1
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len14 = l13;
+                                            let bytes14 = _rt::Vec::from_raw_parts(
+                                                l12.cast(),
+                                                len14,
+                                                len14,
+                                            );
+                                            _rt::string_lift(bytes14)
+                                        };
+                                        Error::Io(e15)
+                                    }
+                                };
+                                v15
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result16
+                }
+            }
+            #[allow(unused_unsafe, clippy::all)]
+            /// Gets a list of configuration key-value pairs of type `string`.
+            ///
+            /// If an error occurs, an `Err(error)` is returned.
+            pub fn get_all() -> Result<_rt::Vec<(_rt::String, _rt::String)>, Error> {
+                unsafe {
+                    #[cfg_attr(target_pointer_width = "64", repr(align(8)))]
+                    #[cfg_attr(target_pointer_width = "32", repr(align(4)))]
+                    struct RetArea(
+                        [::core::mem::MaybeUninit<
+                            u8,
+                        >; 4 * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let mut ret_area = RetArea(
+                        [::core::mem::MaybeUninit::uninit(); 4
+                            * ::core::mem::size_of::<*const u8>()],
+                    );
+                    let ptr0 = ret_area.0.as_mut_ptr().cast::<u8>();
+                    #[cfg(target_arch = "wasm32")]
+                    #[link(wasm_import_module = "wasi:config/store@0.2.0-draft")]
+                    unsafe extern "C" {
+                        #[link_name = "get-all"]
+                        fn wit_import1(_: *mut u8);
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    unsafe extern "C" fn wit_import1(_: *mut u8) {
+                        unreachable!()
+                    }
+                    unsafe { wit_import1(ptr0) };
+                    let l2 = i32::from(*ptr0.add(0).cast::<u8>());
+                    let result20 = match l2 {
+                        0 => {
+                            let e = {
+                                let l3 = *ptr0
+                                    .add(::core::mem::size_of::<*const u8>())
+                                    .cast::<*mut u8>();
+                                let l4 = *ptr0
+                                    .add(2 * ::core::mem::size_of::<*const u8>())
+                                    .cast::<usize>();
+                                let base11 = l3;
+                                let len11 = l4;
+                                let mut result11 = _rt::Vec::with_capacity(len11);
+                                for i in 0..len11 {
+                                    let base = base11
+                                        .add(i * (4 * ::core::mem::size_of::<*const u8>()));
+                                    let e11 = {
+                                        let l5 = *base.add(0).cast::<*mut u8>();
+                                        let l6 = *base
+                                            .add(::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len7 = l6;
+                                        let bytes7 = _rt::Vec::from_raw_parts(
+                                            l5.cast(),
+                                            len7,
+                                            len7,
+                                        );
+                                        let l8 = *base
+                                            .add(2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<*mut u8>();
+                                        let l9 = *base
+                                            .add(3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<usize>();
+                                        let len10 = l9;
+                                        let bytes10 = _rt::Vec::from_raw_parts(
+                                            l8.cast(),
+                                            len10,
+                                            len10,
+                                        );
+                                        (_rt::string_lift(bytes7), _rt::string_lift(bytes10))
+                                    };
+                                    result11.push(e11);
+                                }
+                                _rt::cabi_dealloc(
+                                    base11,
+                                    len11 * (4 * ::core::mem::size_of::<*const u8>()),
+                                    ::core::mem::size_of::<*const u8>(),
+                                );
+                                result11
+                            };
+                            Ok(e)
+                        }
+                        1 => {
+                            let e = {
+                                let l12 = i32::from(
+                                    *ptr0.add(::core::mem::size_of::<*const u8>()).cast::<u8>(),
+                                );
+                                let v19 = match l12 {
+                                    0 => {
+                                        let e19 = {
+                                            let l13 = *ptr0
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l14 = *ptr0
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len15 = l14;
+                                            let bytes15 = _rt::Vec::from_raw_parts(
+                                                l13.cast(),
+                                                len15,
+                                                len15,
+                                            );
+                                            _rt::string_lift(bytes15)
+                                        };
+                                        Error::Upstream(e19)
+                                    }
+                                    n => {
+                                        debug_assert_eq!(n, 1, "invalid enum discriminant");
+                                        let e19 = {
+                                            let l16 = *ptr0
+                                                .add(2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l17 = *ptr0
+                                                .add(3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            let len18 = l17;
+                                            let bytes18 = _rt::Vec::from_raw_parts(
+                                                l16.cast(),
+                                                len18,
+                                                len18,
+                                            );
+                                            _rt::string_lift(bytes18)
+                                        };
+                                        Error::Io(e19)
+                                    }
+                                };
+                                v19
+                            };
+                            Err(e)
+                        }
+                        _ => _rt::invalid_enum_discriminant(),
+                    };
+                    result20
+                }
+            }
+        }
     }
 }
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn _export_get_user_cabi<T: Guest>(arg0: *mut u8, arg1: usize) -> *mut u8 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let result1 = T::get_user(_rt::string_lift(bytes0));
-    let ptr2 = (&raw mut _RET_AREA.0).cast::<u8>();
-    let wassemble::github::types::User {
-        login: login3,
-        id: id3,
-        avatar_url: avatar_url3,
-    } = result1;
-    let vec4 = (login3.into_bytes()).into_boxed_slice();
-    let ptr4 = vec4.as_ptr().cast::<u8>();
-    let len4 = vec4.len();
-    ::core::mem::forget(vec4);
-    *ptr2.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len4;
-    *ptr2.add(0).cast::<*mut u8>() = ptr4.cast_mut();
-    *ptr2.add(2 * ::core::mem::size_of::<*const u8>()).cast::<i64>() = _rt::as_i64(id3);
-    let vec5 = (avatar_url3.into_bytes()).into_boxed_slice();
-    let ptr5 = vec5.as_ptr().cast::<u8>();
-    let len5 = vec5.len();
-    ::core::mem::forget(vec5);
-    *ptr2.add(8 + 3 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len5;
-    *ptr2.add(8 + 2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr5
-        .cast_mut();
-    ptr2
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn __post_return_get_user<T: Guest>(arg0: *mut u8) {
-    let l0 = *arg0.add(0).cast::<*mut u8>();
-    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l0, l1, 1);
-    let l2 = *arg0.add(8 + 2 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-    let l3 = *arg0.add(8 + 3 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l2, l3, 1);
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn _export_update_issue_cabi<T: Guest>(
-    arg0: *mut u8,
-    arg1: usize,
-    arg2: *mut u8,
-    arg3: usize,
-    arg4: *mut u8,
-    arg5: usize,
-    arg6: i32,
-    arg7: *mut u8,
-    arg8: usize,
-    arg9: *mut u8,
-    arg10: usize,
-) -> *mut u8 {
-    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
-    let len0 = arg1;
-    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
-    let len1 = arg3;
-    let bytes1 = _rt::Vec::from_raw_parts(arg2.cast(), len1, len1);
-    let len2 = arg5;
-    let bytes2 = _rt::Vec::from_raw_parts(arg4.cast(), len2, len2);
-    let len3 = arg8;
-    let bytes3 = _rt::Vec::from_raw_parts(arg7.cast(), len3, len3);
-    let len4 = arg10;
-    let bytes4 = _rt::Vec::from_raw_parts(arg9.cast(), len4, len4);
-    let result5 = T::update_issue(
-        _rt::string_lift(bytes0),
-        _rt::string_lift(bytes1),
-        _rt::string_lift(bytes2),
-        arg6 as u32,
-        _rt::string_lift(bytes3),
-        _rt::string_lift(bytes4),
-    );
-    let ptr6 = (&raw mut _RET_AREA.0).cast::<u8>();
-    let wassemble::github::types::Issue {
-        body: body7,
-        number: number7,
-        title: title7,
-    } = result5;
-    let vec8 = (body7.into_bytes()).into_boxed_slice();
-    let ptr8 = vec8.as_ptr().cast::<u8>();
-    let len8 = vec8.len();
-    ::core::mem::forget(vec8);
-    *ptr6.add(::core::mem::size_of::<*const u8>()).cast::<usize>() = len8;
-    *ptr6.add(0).cast::<*mut u8>() = ptr8.cast_mut();
-    *ptr6.add(2 * ::core::mem::size_of::<*const u8>()).cast::<i32>() = _rt::as_i32(
-        number7,
-    );
-    let vec9 = (title7.into_bytes()).into_boxed_slice();
-    let ptr9 = vec9.as_ptr().cast::<u8>();
-    let len9 = vec9.len();
-    ::core::mem::forget(vec9);
-    *ptr6.add(4 * ::core::mem::size_of::<*const u8>()).cast::<usize>() = len9;
-    *ptr6.add(3 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>() = ptr9
-        .cast_mut();
-    ptr6
-}
-#[doc(hidden)]
-#[allow(non_snake_case)]
-pub unsafe fn __post_return_update_issue<T: Guest>(arg0: *mut u8) {
-    let l0 = *arg0.add(0).cast::<*mut u8>();
-    let l1 = *arg0.add(::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l0, l1, 1);
-    let l2 = *arg0.add(3 * ::core::mem::size_of::<*const u8>()).cast::<*mut u8>();
-    let l3 = *arg0.add(4 * ::core::mem::size_of::<*const u8>()).cast::<usize>();
-    _rt::cabi_dealloc(l2, l3, 1);
-}
-pub trait Guest {
-    fn create_issue(
-        token: _rt::String,
-        owner: _rt::String,
-        repo: _rt::String,
-        title: _rt::String,
-        body: _rt::String,
-    ) -> Issue;
-    fn create_repository(
-        token: _rt::String,
-        name: _rt::String,
-        description: _rt::String,
-    ) -> Repository;
-    fn delete_repository(
-        token: _rt::String,
-        owner: _rt::String,
-        repo: _rt::String,
-    ) -> bool;
-    fn get_user(token: _rt::String) -> User;
-    fn update_issue(
-        token: _rt::String,
-        owner: _rt::String,
-        repo: _rt::String,
-        number: u32,
-        title: _rt::String,
-        body: _rt::String,
-    ) -> Issue;
-}
-#[doc(hidden)]
-macro_rules! __export_world_github_cabi {
-    ($ty:ident with_types_in $($path_to_types:tt)*) => {
-        const _ : () = { #[unsafe (export_name = "create-issue")] unsafe extern "C" fn
-        export_create_issue(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3 : usize,
-        arg4 : * mut u8, arg5 : usize, arg6 : * mut u8, arg7 : usize, arg8 : * mut u8,
-        arg9 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
-        _export_create_issue_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4, arg5, arg6,
-        arg7, arg8, arg9) } } #[unsafe (export_name = "cabi_post_create-issue")] unsafe
-        extern "C" fn _post_return_create_issue(arg0 : * mut u8,) { unsafe {
-        $($path_to_types)*:: __post_return_create_issue::<$ty > (arg0) } } #[unsafe
-        (export_name = "create-repository")] unsafe extern "C" fn
-        export_create_repository(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3 :
-        usize, arg4 : * mut u8, arg5 : usize,) -> * mut u8 { unsafe {
-        $($path_to_types)*:: _export_create_repository_cabi::<$ty > (arg0, arg1, arg2,
-        arg3, arg4, arg5) } } #[unsafe (export_name = "cabi_post_create-repository")]
-        unsafe extern "C" fn _post_return_create_repository(arg0 : * mut u8,) { unsafe {
-        $($path_to_types)*:: __post_return_create_repository::<$ty > (arg0) } } #[unsafe
-        (export_name = "delete-repository")] unsafe extern "C" fn
-        export_delete_repository(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3 :
-        usize, arg4 : * mut u8, arg5 : usize,) -> i32 { unsafe { $($path_to_types)*::
-        _export_delete_repository_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4, arg5) } }
-        #[unsafe (export_name = "get-user")] unsafe extern "C" fn export_get_user(arg0 :
-        * mut u8, arg1 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
-        _export_get_user_cabi::<$ty > (arg0, arg1) } } #[unsafe (export_name =
-        "cabi_post_get-user")] unsafe extern "C" fn _post_return_get_user(arg0 : * mut
-        u8,) { unsafe { $($path_to_types)*:: __post_return_get_user::<$ty > (arg0) } }
-        #[unsafe (export_name = "update-issue")] unsafe extern "C" fn
-        export_update_issue(arg0 : * mut u8, arg1 : usize, arg2 : * mut u8, arg3 : usize,
-        arg4 : * mut u8, arg5 : usize, arg6 : i32, arg7 : * mut u8, arg8 : usize, arg9 :
-        * mut u8, arg10 : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
-        _export_update_issue_cabi::<$ty > (arg0, arg1, arg2, arg3, arg4, arg5, arg6,
-        arg7, arg8, arg9, arg10) } } #[unsafe (export_name = "cabi_post_update-issue")]
-        unsafe extern "C" fn _post_return_update_issue(arg0 : * mut u8,) { unsafe {
-        $($path_to_types)*:: __post_return_update_issue::<$ty > (arg0) } } };
-    };
-}
-#[doc(hidden)]
-pub(crate) use __export_world_github_cabi;
-#[repr(align(8))]
-struct _RetArea([::core::mem::MaybeUninit<u8>; 6 * ::core::mem::size_of::<*const u8>()]);
-static mut _RET_AREA: _RetArea = _RetArea(
-    [::core::mem::MaybeUninit::uninit(); 6 * ::core::mem::size_of::<*const u8>()],
-);
 #[rustfmt::skip]
 #[allow(dead_code, clippy::all)]
 pub mod wassemble {
@@ -349,6 +313,65 @@ pub mod wassemble {
             #[doc(hidden)]
             static __FORCE_SECTION_REF: fn() = super::super::super::__link_custom_section_describing_imports;
             use super::super::super::_rt;
+            /// How failed requests are retried
+            ///
+            /// Rate-limited responses are retried after the delay GitHub asks for, other
+            /// retryable failures with exponential backoff.
+            #[derive(Clone)]
+            pub struct RetryConfig {
+                /// Total number of attempts including the first one, 1 disables retries
+                pub max_attempts: u32,
+                /// Delay before the first retry in milliseconds, doubled for every further retry
+                pub initial_delay_ms: u32,
+                /// Longest delay to wait in milliseconds; a rate limit resetting later is returned as `rate-limited`
+                pub max_delay_ms: u32,
+                /// Statuses retried with backoff; POST and PATCH requests are only retried for 429
+                pub retry_statuses: _rt::Vec<u16>,
+            }
+            impl ::core::fmt::Debug for RetryConfig {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("RetryConfig")
+                        .field("max-attempts", &self.max_attempts)
+                        .field("initial-delay-ms", &self.initial_delay_ms)
+                        .field("max-delay-ms", &self.max_delay_ms)
+                        .field("retry-statuses", &self.retry_statuses)
+                        .finish()
+                }
+            }
+            /// Settings a client is constructed with
+            #[derive(Clone)]
+            pub struct Config {
+                /// Personal access token or GitHub App installation token
+                /// When empty, builds with the `wasi-config` feature read the `github_token` key of
+                /// `wasi:config/store` instead
+                pub token: _rt::String,
+                /// API base URL, defaults to https://api.github.com
+                /// GitHub Enterprise Server uses https://HOSTNAME/api/v3
+                pub base_url: Option<_rt::String>,
+                /// Value of the `User-Agent` header, defaults to "wassemble-components/<version>"
+                pub user_agent: Option<_rt::String>,
+                /// Timeout for establishing a connection, in milliseconds
+                pub connect_timeout_ms: Option<u32>,
+                /// How failed requests are retried, defaults to 3 attempts for 429, 502, 503 and 504
+                pub retry: Option<RetryConfig>,
+            }
+            impl ::core::fmt::Debug for Config {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("Config")
+                        .field("token", &self.token)
+                        .field("base-url", &self.base_url)
+                        .field("user-agent", &self.user_agent)
+                        .field("connect-timeout-ms", &self.connect_timeout_ms)
+                        .field("retry", &self.retry)
+                        .finish()
+                }
+            }
             #[derive(Clone)]
             pub struct Issue {
                 pub body: _rt::String,
@@ -403,6 +426,3547 @@ pub mod wassemble {
                         .finish()
                 }
             }
+            /// Error payload returned by the GitHub API alongside a non-success status
+            #[derive(Clone)]
+            pub struct ApiError {
+                /// HTTP status code of the response
+                pub status: u16,
+                /// The `message` field of the error body
+                pub message: _rt::String,
+                /// The `documentation_url` field of the error body, if any
+                pub documentation_url: Option<_rt::String>,
+            }
+            impl ::core::fmt::Debug for ApiError {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("ApiError")
+                        .field("status", &self.status)
+                        .field("message", &self.message)
+                        .field("documentation-url", &self.documentation_url)
+                        .finish()
+                }
+            }
+            /// A single entry of the `errors` array returned with 422 responses
+            #[derive(Clone)]
+            pub struct ValidationError {
+                /// The resource the error applies to, e.g. "Issue"
+                pub resource: Option<_rt::String>,
+                /// The field the error applies to, e.g. "title"
+                pub field: Option<_rt::String>,
+                /// Machine-readable error code, e.g. "missing_field" or "already_exists"
+                pub code: _rt::String,
+                /// Human-readable description for "custom" error codes
+                pub message: Option<_rt::String>,
+            }
+            impl ::core::fmt::Debug for ValidationError {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("ValidationError")
+                        .field("resource", &self.resource)
+                        .field("field", &self.field)
+                        .field("code", &self.code)
+                        .field("message", &self.message)
+                        .finish()
+                }
+            }
+            /// Body of a 422 Unprocessable Entity response
+            #[derive(Clone)]
+            pub struct ValidationFailure {
+                /// The `message` field of the error body
+                pub message: _rt::String,
+                /// The `documentation_url` field of the error body, if any
+                pub documentation_url: Option<_rt::String>,
+                /// Every validation error reported by GitHub
+                pub errors: _rt::Vec<ValidationError>,
+            }
+            impl ::core::fmt::Debug for ValidationFailure {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("ValidationFailure")
+                        .field("message", &self.message)
+                        .field("documentation-url", &self.documentation_url)
+                        .field("errors", &self.errors)
+                        .finish()
+                }
+            }
+            /// Rate limit details taken from the response headers
+            #[derive(Clone)]
+            pub struct RateLimit {
+                /// The `message` field of the error body
+                pub message: _rt::String,
+                /// Value of `x-ratelimit-limit`
+                pub limit: Option<u32>,
+                /// Value of `x-ratelimit-remaining`
+                pub remaining: Option<u32>,
+                /// Value of `x-ratelimit-reset`, in UTC epoch seconds
+                pub reset: Option<u64>,
+                /// Value of `retry-after`, in seconds
+                pub retry_after: Option<u64>,
+            }
+            impl ::core::fmt::Debug for RateLimit {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("RateLimit")
+                        .field("message", &self.message)
+                        .field("limit", &self.limit)
+                        .field("remaining", &self.remaining)
+                        .field("reset", &self.reset)
+                        .field("retry-after", &self.retry_after)
+                        .finish()
+                }
+            }
+            /// Rate limit counters of the most recent response
+            #[derive(Clone)]
+            pub struct RateLimitStatus {
+                /// Value of `x-ratelimit-limit`
+                pub limit: u32,
+                /// Value of `x-ratelimit-remaining`
+                pub remaining: u32,
+                /// Value of `x-ratelimit-used`
+                pub used: Option<u32>,
+                /// Value of `x-ratelimit-reset`, in UTC epoch seconds
+                pub reset: u64,
+                /// Value of `x-ratelimit-resource`, e.g. "core" or "search"
+                pub resource: Option<_rt::String>,
+            }
+            impl ::core::fmt::Debug for RateLimitStatus {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    f.debug_struct("RateLimitStatus")
+                        .field("limit", &self.limit)
+                        .field("remaining", &self.remaining)
+                        .field("used", &self.used)
+                        .field("reset", &self.reset)
+                        .field("resource", &self.resource)
+                        .finish()
+                }
+            }
+            /// Error types that can occur during GitHub API operations
+            #[derive(Clone)]
+            pub enum GithubError {
+                /// Failed to send HTTP request
+                RequestFailed(_rt::String),
+                /// Failed to get response body
+                ResponseBodyError(_rt::String),
+                /// Failed to parse response as UTF-8
+                Utf8Error(_rt::String),
+                /// Failed to parse JSON response
+                JsonParseError(_rt::String),
+                /// Failed to read a value from `wasi:config/store`
+                ConfigError(_rt::String),
+                /// The API responded with a non-success status
+                HttpError(ApiError),
+                /// The API rejected the request payload (422)
+                ValidationFailed(ValidationFailure),
+                /// The primary or secondary rate limit was exceeded
+                RateLimited(RateLimit),
+            }
+            impl ::core::fmt::Debug for GithubError {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    match self {
+                        GithubError::RequestFailed(e) => {
+                            f.debug_tuple("GithubError::RequestFailed").field(e).finish()
+                        }
+                        GithubError::ResponseBodyError(e) => {
+                            f.debug_tuple("GithubError::ResponseBodyError")
+                                .field(e)
+                                .finish()
+                        }
+                        GithubError::Utf8Error(e) => {
+                            f.debug_tuple("GithubError::Utf8Error").field(e).finish()
+                        }
+                        GithubError::JsonParseError(e) => {
+                            f.debug_tuple("GithubError::JsonParseError")
+                                .field(e)
+                                .finish()
+                        }
+                        GithubError::ConfigError(e) => {
+                            f.debug_tuple("GithubError::ConfigError").field(e).finish()
+                        }
+                        GithubError::HttpError(e) => {
+                            f.debug_tuple("GithubError::HttpError").field(e).finish()
+                        }
+                        GithubError::ValidationFailed(e) => {
+                            f.debug_tuple("GithubError::ValidationFailed")
+                                .field(e)
+                                .finish()
+                        }
+                        GithubError::RateLimited(e) => {
+                            f.debug_tuple("GithubError::RateLimited").field(e).finish()
+                        }
+                    }
+                }
+            }
+            impl ::core::fmt::Display for GithubError {
+                fn fmt(
+                    &self,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::fmt::Result {
+                    write!(f, "{:?}", self)
+                }
+            }
+            impl std::error::Error for GithubError {}
+        }
+    }
+}
+#[rustfmt::skip]
+#[allow(dead_code, clippy::all)]
+pub mod exports {
+    pub mod wassemble {
+        pub mod github {
+            #[allow(dead_code, async_fn_in_trait, unused_imports, clippy::all)]
+            pub mod api {
+                #[used]
+                #[doc(hidden)]
+                static __FORCE_SECTION_REF: fn() = super::super::super::super::__link_custom_section_describing_imports;
+                use super::super::super::super::_rt;
+                pub type Config = super::super::super::super::wassemble::github::types::Config;
+                pub type Issue = super::super::super::super::wassemble::github::types::Issue;
+                pub type Repository = super::super::super::super::wassemble::github::types::Repository;
+                pub type User = super::super::super::super::wassemble::github::types::User;
+                pub type RateLimitStatus = super::super::super::super::wassemble::github::types::RateLimitStatus;
+                pub type GithubError = super::super::super::super::wassemble::github::types::GithubError;
+                /// A GitHub API client holding its credentials and connection settings
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct Client {
+                    handle: _rt::Resource<Client>,
+                }
+                type _ClientRep<T> = Option<T>;
+                impl Client {
+                    /// Creates a new resource from the specified representation.
+                    ///
+                    /// This function will create a new resource handle by moving `val` onto
+                    /// the heap and then passing that heap pointer to the component model to
+                    /// create a handle. The owned handle is then returned as `Client`.
+                    pub fn new<T: GuestClient>(val: T) -> Self {
+                        Self::type_guard::<T>();
+                        let val: _ClientRep<T> = Some(val);
+                        let ptr: *mut _ClientRep<T> = _rt::Box::into_raw(
+                            _rt::Box::new(val),
+                        );
+                        unsafe { Self::from_handle(T::_resource_new(ptr.cast())) }
+                    }
+                    /// Gets access to the underlying `T` which represents this resource.
+                    pub fn get<T: GuestClient>(&self) -> &T {
+                        let ptr = unsafe { &*self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    /// Gets mutable access to the underlying `T` which represents this
+                    /// resource.
+                    pub fn get_mut<T: GuestClient>(&mut self) -> &mut T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_mut().unwrap()
+                    }
+                    /// Consumes this resource and returns the underlying `T`.
+                    pub fn into_inner<T: GuestClient>(self) -> T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.take().unwrap()
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn from_handle(handle: u32) -> Self {
+                        Self {
+                            handle: unsafe { _rt::Resource::from_handle(handle) },
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub fn take_handle(&self) -> u32 {
+                        _rt::Resource::take_handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    pub fn handle(&self) -> u32 {
+                        _rt::Resource::handle(&self.handle)
+                    }
+                    #[doc(hidden)]
+                    fn type_guard<T: 'static>() {
+                        use core::any::TypeId;
+                        static mut LAST_TYPE: Option<TypeId> = None;
+                        unsafe {
+                            assert!(! cfg!(target_feature = "atomics"));
+                            let id = TypeId::of::<T>();
+                            match LAST_TYPE {
+                                Some(ty) => {
+                                    assert!(
+                                        ty == id, "cannot use two types with this resource type"
+                                    )
+                                }
+                                None => LAST_TYPE = Some(id),
+                            }
+                        }
+                    }
+                    #[doc(hidden)]
+                    pub unsafe fn dtor<T: 'static>(handle: *mut u8) {
+                        Self::type_guard::<T>();
+                        let _ = unsafe {
+                            _rt::Box::from_raw(handle as *mut _ClientRep<T>)
+                        };
+                    }
+                    fn as_ptr<T: GuestClient>(&self) -> *mut _ClientRep<T> {
+                        Client::type_guard::<T>();
+                        T::_resource_rep(self.handle()).cast()
+                    }
+                }
+                /// A borrowed version of [`Client`] which represents a borrowed value
+                /// with the lifetime `'a`.
+                #[derive(Debug)]
+                #[repr(transparent)]
+                pub struct ClientBorrow<'a> {
+                    rep: *mut u8,
+                    _marker: core::marker::PhantomData<&'a Client>,
+                }
+                impl<'a> ClientBorrow<'a> {
+                    #[doc(hidden)]
+                    pub unsafe fn lift(rep: usize) -> Self {
+                        Self {
+                            rep: rep as *mut u8,
+                            _marker: core::marker::PhantomData,
+                        }
+                    }
+                    /// Gets access to the underlying `T` in this resource.
+                    pub fn get<T: GuestClient>(&self) -> &T {
+                        let ptr = unsafe { &mut *self.as_ptr::<T>() };
+                        ptr.as_ref().unwrap()
+                    }
+                    fn as_ptr<T: 'static>(&self) -> *mut _ClientRep<T> {
+                        Client::type_guard::<T>();
+                        self.rep.cast()
+                    }
+                }
+                unsafe impl _rt::WasmResource for Client {
+                    #[inline]
+                    unsafe fn drop(_handle: u32) {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        unreachable!();
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]wassemble:github/api@0.2.0"
+                            )]
+                            unsafe extern "C" {
+                                #[link_name = "[resource-drop]client"]
+                                fn drop(_: u32);
+                            }
+                            unsafe { drop(_handle) };
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_constructor_client_cabi<T: GuestClient>(
+                    arg0: *mut u8,
+                    arg1: usize,
+                    arg2: i32,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: i32,
+                    arg6: *mut u8,
+                    arg7: usize,
+                    arg8: i32,
+                    arg9: i32,
+                    arg10: i32,
+                    arg11: i32,
+                    arg12: i32,
+                    arg13: i32,
+                    arg14: *mut u8,
+                    arg15: usize,
+                ) -> i32 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg1;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg0.cast(), len0, len0);
+                    let result4 = Client::new(
+                        T::new(super::super::super::super::wassemble::github::types::Config {
+                            token: _rt::string_lift(bytes0),
+                            base_url: match arg2 {
+                                0 => None,
+                                1 => {
+                                    let e = {
+                                        let len1 = arg4;
+                                        let bytes1 = _rt::Vec::from_raw_parts(
+                                            arg3.cast(),
+                                            len1,
+                                            len1,
+                                        );
+                                        _rt::string_lift(bytes1)
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            user_agent: match arg5 {
+                                0 => None,
+                                1 => {
+                                    let e = {
+                                        let len2 = arg7;
+                                        let bytes2 = _rt::Vec::from_raw_parts(
+                                            arg6.cast(),
+                                            len2,
+                                            len2,
+                                        );
+                                        _rt::string_lift(bytes2)
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            connect_timeout_ms: match arg8 {
+                                0 => None,
+                                1 => {
+                                    let e = arg9 as u32;
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                            retry: match arg10 {
+                                0 => None,
+                                1 => {
+                                    let e = {
+                                        let len3 = arg15;
+                                        super::super::super::super::wassemble::github::types::RetryConfig {
+                                            max_attempts: arg11 as u32,
+                                            initial_delay_ms: arg12 as u32,
+                                            max_delay_ms: arg13 as u32,
+                                            retry_statuses: _rt::Vec::from_raw_parts(
+                                                arg14.cast(),
+                                                len3,
+                                                len3,
+                                            ),
+                                        }
+                                    };
+                                    Some(e)
+                                }
+                                _ => _rt::invalid_enum_discriminant(),
+                            },
+                        }),
+                    );
+                    (result4).take_handle() as i32
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_create_issue_cabi<T: GuestClient>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: *mut u8,
+                    arg6: usize,
+                    arg7: *mut u8,
+                    arg8: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let len1 = arg4;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                    let len2 = arg6;
+                    let bytes2 = _rt::Vec::from_raw_parts(arg5.cast(), len2, len2);
+                    let len3 = arg8;
+                    let bytes3 = _rt::Vec::from_raw_parts(arg7.cast(), len3, len3);
+                    let result4 = T::create_issue(
+                        unsafe { ClientBorrow::lift(arg0 as u32 as usize) }.get(),
+                        _rt::string_lift(bytes0),
+                        _rt::string_lift(bytes1),
+                        _rt::string_lift(bytes2),
+                        _rt::string_lift(bytes3),
+                    );
+                    let ptr5 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result4 {
+                        Ok(e) => {
+                            *ptr5.add(0).cast::<u8>() = (0i32) as u8;
+                            let super::super::super::super::wassemble::github::types::Issue {
+                                body: body6,
+                                number: number6,
+                                title: title6,
+                            } = e;
+                            let vec7 = (body6.into_bytes()).into_boxed_slice();
+                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                            let len7 = vec7.len();
+                            ::core::mem::forget(vec7);
+                            *ptr5
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len7;
+                            *ptr5.add(8).cast::<*mut u8>() = ptr7.cast_mut();
+                            *ptr5
+                                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(number6);
+                            let vec8 = (title6.into_bytes()).into_boxed_slice();
+                            let ptr8 = vec8.as_ptr().cast::<u8>();
+                            let len8 = vec8.len();
+                            ::core::mem::forget(vec8);
+                            *ptr5
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len8;
+                            *ptr5
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr8.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr5.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::wassemble::github::types::GithubError as V28;
+                            match e {
+                                V28::RequestFailed(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len9;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                V28::ResponseBodyError(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec10 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len10;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                V28::Utf8Error(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (2i32) as u8;
+                                    let vec11 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len11;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                V28::JsonParseError(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (3i32) as u8;
+                                    let vec12 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len12;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr12.cast_mut();
+                                }
+                                V28::ConfigError(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (4i32) as u8;
+                                    let vec13 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len13;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                }
+                                V28::HttpError(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (5i32) as u8;
+                                    let super::super::super::super::wassemble::github::types::ApiError {
+                                        status: status14,
+                                        message: message14,
+                                        documentation_url: documentation_url14,
+                                    } = e;
+                                    *ptr5.add(16).cast::<u16>() = (_rt::as_i32(status14))
+                                        as u16;
+                                    let vec15 = (message14.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr5
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len15;
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr15.cast_mut();
+                                    match documentation_url14 {
+                                        Some(e) => {
+                                            *ptr5
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec16 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr16 = vec16.as_ptr().cast::<u8>();
+                                            let len16 = vec16.len();
+                                            ::core::mem::forget(vec16);
+                                            *ptr5
+                                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len16;
+                                            *ptr5
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr16.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr5
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V28::ValidationFailed(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (6i32) as u8;
+                                    let super::super::super::super::wassemble::github::types::ValidationFailure {
+                                        message: message17,
+                                        documentation_url: documentation_url17,
+                                        errors: errors17,
+                                    } = e;
+                                    let vec18 = (message17.into_bytes()).into_boxed_slice();
+                                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                                    let len18 = vec18.len();
+                                    ::core::mem::forget(vec18);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len18;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr18.cast_mut();
+                                    match documentation_url17 {
+                                        Some(e) => {
+                                            *ptr5
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec19 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr19 = vec19.as_ptr().cast::<u8>();
+                                            let len19 = vec19.len();
+                                            ::core::mem::forget(vec19);
+                                            *ptr5
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len19;
+                                            *ptr5
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr19.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr5
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec25 = errors17;
+                                    let len25 = vec25.len();
+                                    let layout25 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec25.len() * (11 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result25 = if layout25.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout25).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout25);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec25.into_iter().enumerate() {
+                                        let base = result25
+                                            .add(i * (11 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let super::super::super::super::wassemble::github::types::ValidationError {
+                                                resource: resource20,
+                                                field: field20,
+                                                code: code20,
+                                                message: message20,
+                                            } = e;
+                                            match resource20 {
+                                                Some(e) => {
+                                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                    let vec21 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                                    let len21 = vec21.len();
+                                                    ::core::mem::forget(vec21);
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len21;
+                                                    *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr21.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match field20 {
+                                                Some(e) => {
+                                                    *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    let vec22 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                                    let len22 = vec22.len();
+                                                    ::core::mem::forget(vec22);
+                                                    *base
+                                                        .add(5 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len22;
+                                                    *base
+                                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr22.cast_mut();
+                                                }
+                                                None => {
+                                                    *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            let vec23 = (code20.into_bytes()).into_boxed_slice();
+                                            let ptr23 = vec23.as_ptr().cast::<u8>();
+                                            let len23 = vec23.len();
+                                            ::core::mem::forget(vec23);
+                                            *base
+                                                .add(7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len23;
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr23.cast_mut();
+                                            match message20 {
+                                                Some(e) => {
+                                                    *base
+                                                        .add(8 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    let vec24 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr24 = vec24.as_ptr().cast::<u8>();
+                                                    let len24 = vec24.len();
+                                                    ::core::mem::forget(vec24);
+                                                    *base
+                                                        .add(10 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len24;
+                                                    *base
+                                                        .add(9 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr24.cast_mut();
+                                                }
+                                                None => {
+                                                    *base
+                                                        .add(8 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                    }
+                                    *ptr5
+                                        .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len25;
+                                    *ptr5
+                                        .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result25;
+                                }
+                                V28::RateLimited(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (7i32) as u8;
+                                    let super::super::super::super::wassemble::github::types::RateLimit {
+                                        message: message26,
+                                        limit: limit26,
+                                        remaining: remaining26,
+                                        reset: reset26,
+                                        retry_after: retry_after26,
+                                    } = e;
+                                    let vec27 = (message26.into_bytes()).into_boxed_slice();
+                                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                                    let len27 = vec27.len();
+                                    ::core::mem::forget(vec27);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len27;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr27.cast_mut();
+                                    match limit26 {
+                                        Some(e) => {
+                                            *ptr5
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr5
+                                                .add(20 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr5
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match remaining26 {
+                                        Some(e) => {
+                                            *ptr5
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr5
+                                                .add(28 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr5
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match reset26 {
+                                        Some(e) => {
+                                            *ptr5
+                                                .add(32 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr5
+                                                .add(40 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr5
+                                                .add(32 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match retry_after26 {
+                                        Some(e) => {
+                                            *ptr5
+                                                .add(48 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr5
+                                                .add(56 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr5
+                                                .add(48 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                        }
+                    };
+                    ptr5
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_create_issue<T: GuestClient>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l4 = *arg0
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                        }
+                        _ => {
+                            let l5 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l5 {
+                                0 => {
+                                    let l6 = *arg0.add(16).cast::<*mut u8>();
+                                    let l7 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                1 => {
+                                    let l8 = *arg0.add(16).cast::<*mut u8>();
+                                    let l9 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                2 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                                3 => {
+                                    let l12 = *arg0.add(16).cast::<*mut u8>();
+                                    let l13 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                }
+                                4 => {
+                                    let l14 = *arg0.add(16).cast::<*mut u8>();
+                                    let l15 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                }
+                                5 => {
+                                    let l16 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l17 = *arg0
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l16, l17, 1);
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l18 {
+                                        0 => {}
+                                        _ => {
+                                            let l19 = *arg0
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l20 = *arg0
+                                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l19, l20, 1);
+                                        }
+                                    }
+                                }
+                                6 => {
+                                    let l21 = *arg0.add(16).cast::<*mut u8>();
+                                    let l22 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l21, l22, 1);
+                                    let l23 = i32::from(
+                                        *arg0
+                                            .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l23 {
+                                        0 => {}
+                                        _ => {
+                                            let l24 = *arg0
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l25 = *arg0
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l24, l25, 1);
+                                        }
+                                    }
+                                    let l26 = *arg0
+                                        .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l27 = *arg0
+                                        .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let base39 = l26;
+                                    let len39 = l27;
+                                    for i in 0..len39 {
+                                        let base = base39
+                                            .add(i * (11 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let l28 = i32::from(*base.add(0).cast::<u8>());
+                                            match l28 {
+                                                0 => {}
+                                                _ => {
+                                                    let l29 = *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l30 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l29, l30, 1);
+                                                }
+                                            }
+                                            let l31 = i32::from(
+                                                *base
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            match l31 {
+                                                0 => {}
+                                                _ => {
+                                                    let l32 = *base
+                                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l33 = *base
+                                                        .add(5 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l32, l33, 1);
+                                                }
+                                            }
+                                            let l34 = *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l35 = *base
+                                                .add(7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l34, l35, 1);
+                                            let l36 = i32::from(
+                                                *base
+                                                    .add(8 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            match l36 {
+                                                0 => {}
+                                                _ => {
+                                                    let l37 = *base
+                                                        .add(9 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l38 = *base
+                                                        .add(10 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l37, l38, 1);
+                                                }
+                                            }
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(
+                                        base39,
+                                        len39 * (11 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                }
+                                _ => {
+                                    let l40 = *arg0.add(16).cast::<*mut u8>();
+                                    let l41 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l40, l41, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_create_repository_cabi<
+                    T: GuestClient,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let len1 = arg4;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                    let result2 = T::create_repository(
+                        unsafe { ClientBorrow::lift(arg0 as u32 as usize) }.get(),
+                        _rt::string_lift(bytes0),
+                        _rt::string_lift(bytes1),
+                    );
+                    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result2 {
+                        Ok(e) => {
+                            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+                            let super::super::super::super::wassemble::github::types::Repository {
+                                name: name4,
+                                owner: owner4,
+                                description: description4,
+                            } = e;
+                            let vec5 = (name4.into_bytes()).into_boxed_slice();
+                            let ptr5 = vec5.as_ptr().cast::<u8>();
+                            let len5 = vec5.len();
+                            ::core::mem::forget(vec5);
+                            *ptr3
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len5;
+                            *ptr3.add(8).cast::<*mut u8>() = ptr5.cast_mut();
+                            let vec6 = (owner4.into_bytes()).into_boxed_slice();
+                            let ptr6 = vec6.as_ptr().cast::<u8>();
+                            let len6 = vec6.len();
+                            ::core::mem::forget(vec6);
+                            *ptr3
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len6;
+                            *ptr3
+                                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr6.cast_mut();
+                            let vec7 = (description4.into_bytes()).into_boxed_slice();
+                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                            let len7 = vec7.len();
+                            ::core::mem::forget(vec7);
+                            *ptr3
+                                .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len7;
+                            *ptr3
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr7.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::wassemble::github::types::GithubError as V27;
+                            match e {
+                                V27::RequestFailed(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len8;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                V27::ResponseBodyError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len9;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                V27::Utf8Error(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (2i32) as u8;
+                                    let vec10 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len10;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                V27::JsonParseError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (3i32) as u8;
+                                    let vec11 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len11;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                V27::ConfigError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (4i32) as u8;
+                                    let vec12 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len12;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr12.cast_mut();
+                                }
+                                V27::HttpError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (5i32) as u8;
+                                    let super::super::super::super::wassemble::github::types::ApiError {
+                                        status: status13,
+                                        message: message13,
+                                        documentation_url: documentation_url13,
+                                    } = e;
+                                    *ptr3.add(16).cast::<u16>() = (_rt::as_i32(status13))
+                                        as u16;
+                                    let vec14 = (message13.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr3
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len14;
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr14.cast_mut();
+                                    match documentation_url13 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec15 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr15 = vec15.as_ptr().cast::<u8>();
+                                            let len15 = vec15.len();
+                                            ::core::mem::forget(vec15);
+                                            *ptr3
+                                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len15;
+                                            *ptr3
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr15.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V27::ValidationFailed(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (6i32) as u8;
+                                    let super::super::super::super::wassemble::github::types::ValidationFailure {
+                                        message: message16,
+                                        documentation_url: documentation_url16,
+                                        errors: errors16,
+                                    } = e;
+                                    let vec17 = (message16.into_bytes()).into_boxed_slice();
+                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                    let len17 = vec17.len();
+                                    ::core::mem::forget(vec17);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len17;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr17.cast_mut();
+                                    match documentation_url16 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec18 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr18 = vec18.as_ptr().cast::<u8>();
+                                            let len18 = vec18.len();
+                                            ::core::mem::forget(vec18);
+                                            *ptr3
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len18;
+                                            *ptr3
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr18.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec24 = errors16;
+                                    let len24 = vec24.len();
+                                    let layout24 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec24.len() * (11 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result24 = if layout24.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout24).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout24);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec24.into_iter().enumerate() {
+                                        let base = result24
+                                            .add(i * (11 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let super::super::super::super::wassemble::github::types::ValidationError {
+                                                resource: resource19,
+                                                field: field19,
+                                                code: code19,
+                                                message: message19,
+                                            } = e;
+                                            match resource19 {
+                                                Some(e) => {
+                                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                    let vec20 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                                    let len20 = vec20.len();
+                                                    ::core::mem::forget(vec20);
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len20;
+                                                    *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr20.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match field19 {
+                                                Some(e) => {
+                                                    *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    let vec21 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                                    let len21 = vec21.len();
+                                                    ::core::mem::forget(vec21);
+                                                    *base
+                                                        .add(5 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len21;
+                                                    *base
+                                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr21.cast_mut();
+                                                }
+                                                None => {
+                                                    *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            let vec22 = (code19.into_bytes()).into_boxed_slice();
+                                            let ptr22 = vec22.as_ptr().cast::<u8>();
+                                            let len22 = vec22.len();
+                                            ::core::mem::forget(vec22);
+                                            *base
+                                                .add(7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len22;
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr22.cast_mut();
+                                            match message19 {
+                                                Some(e) => {
+                                                    *base
+                                                        .add(8 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    let vec23 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr23 = vec23.as_ptr().cast::<u8>();
+                                                    let len23 = vec23.len();
+                                                    ::core::mem::forget(vec23);
+                                                    *base
+                                                        .add(10 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len23;
+                                                    *base
+                                                        .add(9 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr23.cast_mut();
+                                                }
+                                                None => {
+                                                    *base
+                                                        .add(8 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                    }
+                                    *ptr3
+                                        .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len24;
+                                    *ptr3
+                                        .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result24;
+                                }
+                                V27::RateLimited(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (7i32) as u8;
+                                    let super::super::super::super::wassemble::github::types::RateLimit {
+                                        message: message25,
+                                        limit: limit25,
+                                        remaining: remaining25,
+                                        reset: reset25,
+                                        retry_after: retry_after25,
+                                    } = e;
+                                    let vec26 = (message25.into_bytes()).into_boxed_slice();
+                                    let ptr26 = vec26.as_ptr().cast::<u8>();
+                                    let len26 = vec26.len();
+                                    ::core::mem::forget(vec26);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len26;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr26.cast_mut();
+                                    match limit25 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr3
+                                                .add(20 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match remaining25 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr3
+                                                .add(28 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match reset25 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(32 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr3
+                                                .add(40 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(32 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match retry_after25 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(48 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr3
+                                                .add(56 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(48 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                        }
+                    };
+                    ptr3
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_create_repository<
+                    T: GuestClient,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0
+                                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l4 = *arg0
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                            let l5 = *arg0
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l6 = *arg0
+                                .add(8 + 5 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l5, l6, 1);
+                        }
+                        _ => {
+                            let l7 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l7 {
+                                0 => {
+                                    let l8 = *arg0.add(16).cast::<*mut u8>();
+                                    let l9 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                1 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                                2 => {
+                                    let l12 = *arg0.add(16).cast::<*mut u8>();
+                                    let l13 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                }
+                                3 => {
+                                    let l14 = *arg0.add(16).cast::<*mut u8>();
+                                    let l15 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                }
+                                4 => {
+                                    let l16 = *arg0.add(16).cast::<*mut u8>();
+                                    let l17 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l16, l17, 1);
+                                }
+                                5 => {
+                                    let l18 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l19 = *arg0
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l18, l19, 1);
+                                    let l20 = i32::from(
+                                        *arg0
+                                            .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l20 {
+                                        0 => {}
+                                        _ => {
+                                            let l21 = *arg0
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l22 = *arg0
+                                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l21, l22, 1);
+                                        }
+                                    }
+                                }
+                                6 => {
+                                    let l23 = *arg0.add(16).cast::<*mut u8>();
+                                    let l24 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l23, l24, 1);
+                                    let l25 = i32::from(
+                                        *arg0
+                                            .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l25 {
+                                        0 => {}
+                                        _ => {
+                                            let l26 = *arg0
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l27 = *arg0
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l26, l27, 1);
+                                        }
+                                    }
+                                    let l28 = *arg0
+                                        .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l29 = *arg0
+                                        .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let base41 = l28;
+                                    let len41 = l29;
+                                    for i in 0..len41 {
+                                        let base = base41
+                                            .add(i * (11 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let l30 = i32::from(*base.add(0).cast::<u8>());
+                                            match l30 {
+                                                0 => {}
+                                                _ => {
+                                                    let l31 = *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l32 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l31, l32, 1);
+                                                }
+                                            }
+                                            let l33 = i32::from(
+                                                *base
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            match l33 {
+                                                0 => {}
+                                                _ => {
+                                                    let l34 = *base
+                                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l35 = *base
+                                                        .add(5 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l34, l35, 1);
+                                                }
+                                            }
+                                            let l36 = *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l37 = *base
+                                                .add(7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l36, l37, 1);
+                                            let l38 = i32::from(
+                                                *base
+                                                    .add(8 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            match l38 {
+                                                0 => {}
+                                                _ => {
+                                                    let l39 = *base
+                                                        .add(9 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l40 = *base
+                                                        .add(10 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l39, l40, 1);
+                                                }
+                                            }
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(
+                                        base41,
+                                        len41 * (11 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                }
+                                _ => {
+                                    let l42 = *arg0.add(16).cast::<*mut u8>();
+                                    let l43 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l42, l43, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_delete_repository_cabi<
+                    T: GuestClient,
+                >(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let len1 = arg4;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                    let result2 = T::delete_repository(
+                        unsafe { ClientBorrow::lift(arg0 as u32 as usize) }.get(),
+                        _rt::string_lift(bytes0),
+                        _rt::string_lift(bytes1),
+                    );
+                    let ptr3 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result2 {
+                        Ok(_) => {
+                            *ptr3.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                        Err(e) => {
+                            *ptr3.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::wassemble::github::types::GithubError as V23;
+                            match e {
+                                V23::RequestFailed(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec4 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr4 = vec4.as_ptr().cast::<u8>();
+                                    let len4 = vec4.len();
+                                    ::core::mem::forget(vec4);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len4;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr4.cast_mut();
+                                }
+                                V23::ResponseBodyError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len5;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                }
+                                V23::Utf8Error(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (2i32) as u8;
+                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len6;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                                V23::JsonParseError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (3i32) as u8;
+                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len7;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                                V23::ConfigError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (4i32) as u8;
+                                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len8;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                V23::HttpError(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (5i32) as u8;
+                                    let super::super::super::super::wassemble::github::types::ApiError {
+                                        status: status9,
+                                        message: message9,
+                                        documentation_url: documentation_url9,
+                                    } = e;
+                                    *ptr3.add(16).cast::<u16>() = (_rt::as_i32(status9)) as u16;
+                                    let vec10 = (message9.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr3
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len10;
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr10.cast_mut();
+                                    match documentation_url9 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec11 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr11 = vec11.as_ptr().cast::<u8>();
+                                            let len11 = vec11.len();
+                                            ::core::mem::forget(vec11);
+                                            *ptr3
+                                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len11;
+                                            *ptr3
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr11.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V23::ValidationFailed(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (6i32) as u8;
+                                    let super::super::super::super::wassemble::github::types::ValidationFailure {
+                                        message: message12,
+                                        documentation_url: documentation_url12,
+                                        errors: errors12,
+                                    } = e;
+                                    let vec13 = (message12.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len13;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                    match documentation_url12 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec14 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr14 = vec14.as_ptr().cast::<u8>();
+                                            let len14 = vec14.len();
+                                            ::core::mem::forget(vec14);
+                                            *ptr3
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len14;
+                                            *ptr3
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr14.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec20 = errors12;
+                                    let len20 = vec20.len();
+                                    let layout20 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec20.len() * (11 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result20 = if layout20.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout20).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout20);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec20.into_iter().enumerate() {
+                                        let base = result20
+                                            .add(i * (11 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let super::super::super::super::wassemble::github::types::ValidationError {
+                                                resource: resource15,
+                                                field: field15,
+                                                code: code15,
+                                                message: message15,
+                                            } = e;
+                                            match resource15 {
+                                                Some(e) => {
+                                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                    let vec16 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr16 = vec16.as_ptr().cast::<u8>();
+                                                    let len16 = vec16.len();
+                                                    ::core::mem::forget(vec16);
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len16;
+                                                    *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr16.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match field15 {
+                                                Some(e) => {
+                                                    *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    let vec17 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                                    let len17 = vec17.len();
+                                                    ::core::mem::forget(vec17);
+                                                    *base
+                                                        .add(5 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len17;
+                                                    *base
+                                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr17.cast_mut();
+                                                }
+                                                None => {
+                                                    *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            let vec18 = (code15.into_bytes()).into_boxed_slice();
+                                            let ptr18 = vec18.as_ptr().cast::<u8>();
+                                            let len18 = vec18.len();
+                                            ::core::mem::forget(vec18);
+                                            *base
+                                                .add(7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len18;
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr18.cast_mut();
+                                            match message15 {
+                                                Some(e) => {
+                                                    *base
+                                                        .add(8 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    let vec19 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr19 = vec19.as_ptr().cast::<u8>();
+                                                    let len19 = vec19.len();
+                                                    ::core::mem::forget(vec19);
+                                                    *base
+                                                        .add(10 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len19;
+                                                    *base
+                                                        .add(9 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr19.cast_mut();
+                                                }
+                                                None => {
+                                                    *base
+                                                        .add(8 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                    }
+                                    *ptr3
+                                        .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len20;
+                                    *ptr3
+                                        .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result20;
+                                }
+                                V23::RateLimited(e) => {
+                                    *ptr3.add(8).cast::<u8>() = (7i32) as u8;
+                                    let super::super::super::super::wassemble::github::types::RateLimit {
+                                        message: message21,
+                                        limit: limit21,
+                                        remaining: remaining21,
+                                        reset: reset21,
+                                        retry_after: retry_after21,
+                                    } = e;
+                                    let vec22 = (message21.into_bytes()).into_boxed_slice();
+                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                    let len22 = vec22.len();
+                                    ::core::mem::forget(vec22);
+                                    *ptr3
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len22;
+                                    *ptr3.add(16).cast::<*mut u8>() = ptr22.cast_mut();
+                                    match limit21 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr3
+                                                .add(20 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match remaining21 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr3
+                                                .add(28 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match reset21 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(32 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr3
+                                                .add(40 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(32 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match retry_after21 {
+                                        Some(e) => {
+                                            *ptr3
+                                                .add(48 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr3
+                                                .add(56 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr3
+                                                .add(48 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                        }
+                    };
+                    ptr3
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_delete_repository<
+                    T: GuestClient,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l1 {
+                                0 => {
+                                    let l2 = *arg0.add(16).cast::<*mut u8>();
+                                    let l3 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                                1 => {
+                                    let l4 = *arg0.add(16).cast::<*mut u8>();
+                                    let l5 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l4, l5, 1);
+                                }
+                                2 => {
+                                    let l6 = *arg0.add(16).cast::<*mut u8>();
+                                    let l7 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                3 => {
+                                    let l8 = *arg0.add(16).cast::<*mut u8>();
+                                    let l9 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                4 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                                5 => {
+                                    let l12 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l13 = *arg0
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                    let l14 = i32::from(
+                                        *arg0
+                                            .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l14 {
+                                        0 => {}
+                                        _ => {
+                                            let l15 = *arg0
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l16 = *arg0
+                                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l15, l16, 1);
+                                        }
+                                    }
+                                }
+                                6 => {
+                                    let l17 = *arg0.add(16).cast::<*mut u8>();
+                                    let l18 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l17, l18, 1);
+                                    let l19 = i32::from(
+                                        *arg0
+                                            .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l19 {
+                                        0 => {}
+                                        _ => {
+                                            let l20 = *arg0
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l21 = *arg0
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l20, l21, 1);
+                                        }
+                                    }
+                                    let l22 = *arg0
+                                        .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l23 = *arg0
+                                        .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let base35 = l22;
+                                    let len35 = l23;
+                                    for i in 0..len35 {
+                                        let base = base35
+                                            .add(i * (11 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let l24 = i32::from(*base.add(0).cast::<u8>());
+                                            match l24 {
+                                                0 => {}
+                                                _ => {
+                                                    let l25 = *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l26 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l25, l26, 1);
+                                                }
+                                            }
+                                            let l27 = i32::from(
+                                                *base
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            match l27 {
+                                                0 => {}
+                                                _ => {
+                                                    let l28 = *base
+                                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l29 = *base
+                                                        .add(5 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l28, l29, 1);
+                                                }
+                                            }
+                                            let l30 = *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l31 = *base
+                                                .add(7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l30, l31, 1);
+                                            let l32 = i32::from(
+                                                *base
+                                                    .add(8 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            match l32 {
+                                                0 => {}
+                                                _ => {
+                                                    let l33 = *base
+                                                        .add(9 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l34 = *base
+                                                        .add(10 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l33, l34, 1);
+                                                }
+                                            }
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(
+                                        base35,
+                                        len35 * (11 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                }
+                                _ => {
+                                    let l36 = *arg0.add(16).cast::<*mut u8>();
+                                    let l37 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l36, l37, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_get_user_cabi<T: GuestClient>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::get_user(
+                        unsafe { ClientBorrow::lift(arg0 as u32 as usize) }.get(),
+                    );
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result0 {
+                        Ok(e) => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                            let super::super::super::super::wassemble::github::types::User {
+                                login: login2,
+                                id: id2,
+                                avatar_url: avatar_url2,
+                            } = e;
+                            let vec3 = (login2.into_bytes()).into_boxed_slice();
+                            let ptr3 = vec3.as_ptr().cast::<u8>();
+                            let len3 = vec3.len();
+                            ::core::mem::forget(vec3);
+                            *ptr1
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len3;
+                            *ptr1.add(8).cast::<*mut u8>() = ptr3.cast_mut();
+                            *ptr1
+                                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i64>() = _rt::as_i64(id2);
+                            let vec4 = (avatar_url2.into_bytes()).into_boxed_slice();
+                            let ptr4 = vec4.as_ptr().cast::<u8>();
+                            let len4 = vec4.len();
+                            ::core::mem::forget(vec4);
+                            *ptr1
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len4;
+                            *ptr1
+                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr4.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::wassemble::github::types::GithubError as V24;
+                            match e {
+                                V24::RequestFailed(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec5 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr5 = vec5.as_ptr().cast::<u8>();
+                                    let len5 = vec5.len();
+                                    ::core::mem::forget(vec5);
+                                    *ptr1
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len5;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr5.cast_mut();
+                                }
+                                V24::ResponseBodyError(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec6 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr6 = vec6.as_ptr().cast::<u8>();
+                                    let len6 = vec6.len();
+                                    ::core::mem::forget(vec6);
+                                    *ptr1
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len6;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr6.cast_mut();
+                                }
+                                V24::Utf8Error(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (2i32) as u8;
+                                    let vec7 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr7 = vec7.as_ptr().cast::<u8>();
+                                    let len7 = vec7.len();
+                                    ::core::mem::forget(vec7);
+                                    *ptr1
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len7;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr7.cast_mut();
+                                }
+                                V24::JsonParseError(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (3i32) as u8;
+                                    let vec8 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr8 = vec8.as_ptr().cast::<u8>();
+                                    let len8 = vec8.len();
+                                    ::core::mem::forget(vec8);
+                                    *ptr1
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len8;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr8.cast_mut();
+                                }
+                                V24::ConfigError(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (4i32) as u8;
+                                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr1
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len9;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                V24::HttpError(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (5i32) as u8;
+                                    let super::super::super::super::wassemble::github::types::ApiError {
+                                        status: status10,
+                                        message: message10,
+                                        documentation_url: documentation_url10,
+                                    } = e;
+                                    *ptr1.add(16).cast::<u16>() = (_rt::as_i32(status10))
+                                        as u16;
+                                    let vec11 = (message10.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr1
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len11;
+                                    *ptr1
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr11.cast_mut();
+                                    match documentation_url10 {
+                                        Some(e) => {
+                                            *ptr1
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec12 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr12 = vec12.as_ptr().cast::<u8>();
+                                            let len12 = vec12.len();
+                                            ::core::mem::forget(vec12);
+                                            *ptr1
+                                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len12;
+                                            *ptr1
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr12.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V24::ValidationFailed(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (6i32) as u8;
+                                    let super::super::super::super::wassemble::github::types::ValidationFailure {
+                                        message: message13,
+                                        documentation_url: documentation_url13,
+                                        errors: errors13,
+                                    } = e;
+                                    let vec14 = (message13.into_bytes()).into_boxed_slice();
+                                    let ptr14 = vec14.as_ptr().cast::<u8>();
+                                    let len14 = vec14.len();
+                                    ::core::mem::forget(vec14);
+                                    *ptr1
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len14;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr14.cast_mut();
+                                    match documentation_url13 {
+                                        Some(e) => {
+                                            *ptr1
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec15 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr15 = vec15.as_ptr().cast::<u8>();
+                                            let len15 = vec15.len();
+                                            ::core::mem::forget(vec15);
+                                            *ptr1
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len15;
+                                            *ptr1
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr15.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr1
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec21 = errors13;
+                                    let len21 = vec21.len();
+                                    let layout21 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec21.len() * (11 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result21 = if layout21.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout21).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout21);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec21.into_iter().enumerate() {
+                                        let base = result21
+                                            .add(i * (11 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let super::super::super::super::wassemble::github::types::ValidationError {
+                                                resource: resource16,
+                                                field: field16,
+                                                code: code16,
+                                                message: message16,
+                                            } = e;
+                                            match resource16 {
+                                                Some(e) => {
+                                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                    let vec17 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr17 = vec17.as_ptr().cast::<u8>();
+                                                    let len17 = vec17.len();
+                                                    ::core::mem::forget(vec17);
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len17;
+                                                    *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr17.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match field16 {
+                                                Some(e) => {
+                                                    *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    let vec18 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                                                    let len18 = vec18.len();
+                                                    ::core::mem::forget(vec18);
+                                                    *base
+                                                        .add(5 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len18;
+                                                    *base
+                                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr18.cast_mut();
+                                                }
+                                                None => {
+                                                    *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            let vec19 = (code16.into_bytes()).into_boxed_slice();
+                                            let ptr19 = vec19.as_ptr().cast::<u8>();
+                                            let len19 = vec19.len();
+                                            ::core::mem::forget(vec19);
+                                            *base
+                                                .add(7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len19;
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr19.cast_mut();
+                                            match message16 {
+                                                Some(e) => {
+                                                    *base
+                                                        .add(8 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    let vec20 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr20 = vec20.as_ptr().cast::<u8>();
+                                                    let len20 = vec20.len();
+                                                    ::core::mem::forget(vec20);
+                                                    *base
+                                                        .add(10 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len20;
+                                                    *base
+                                                        .add(9 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr20.cast_mut();
+                                                }
+                                                None => {
+                                                    *base
+                                                        .add(8 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                    }
+                                    *ptr1
+                                        .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len21;
+                                    *ptr1
+                                        .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result21;
+                                }
+                                V24::RateLimited(e) => {
+                                    *ptr1.add(8).cast::<u8>() = (7i32) as u8;
+                                    let super::super::super::super::wassemble::github::types::RateLimit {
+                                        message: message22,
+                                        limit: limit22,
+                                        remaining: remaining22,
+                                        reset: reset22,
+                                        retry_after: retry_after22,
+                                    } = e;
+                                    let vec23 = (message22.into_bytes()).into_boxed_slice();
+                                    let ptr23 = vec23.as_ptr().cast::<u8>();
+                                    let len23 = vec23.len();
+                                    ::core::mem::forget(vec23);
+                                    *ptr1
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len23;
+                                    *ptr1.add(16).cast::<*mut u8>() = ptr23.cast_mut();
+                                    match limit22 {
+                                        Some(e) => {
+                                            *ptr1
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr1
+                                                .add(20 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr1
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match remaining22 {
+                                        Some(e) => {
+                                            *ptr1
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr1
+                                                .add(28 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr1
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match reset22 {
+                                        Some(e) => {
+                                            *ptr1
+                                                .add(32 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr1
+                                                .add(40 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1
+                                                .add(32 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match retry_after22 {
+                                        Some(e) => {
+                                            *ptr1
+                                                .add(48 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr1
+                                                .add(56 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr1
+                                                .add(48 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_get_user<T: GuestClient>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0
+                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l4 = *arg0
+                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                        }
+                        _ => {
+                            let l5 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l5 {
+                                0 => {
+                                    let l6 = *arg0.add(16).cast::<*mut u8>();
+                                    let l7 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                1 => {
+                                    let l8 = *arg0.add(16).cast::<*mut u8>();
+                                    let l9 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                2 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                                3 => {
+                                    let l12 = *arg0.add(16).cast::<*mut u8>();
+                                    let l13 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                }
+                                4 => {
+                                    let l14 = *arg0.add(16).cast::<*mut u8>();
+                                    let l15 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                }
+                                5 => {
+                                    let l16 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l17 = *arg0
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l16, l17, 1);
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l18 {
+                                        0 => {}
+                                        _ => {
+                                            let l19 = *arg0
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l20 = *arg0
+                                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l19, l20, 1);
+                                        }
+                                    }
+                                }
+                                6 => {
+                                    let l21 = *arg0.add(16).cast::<*mut u8>();
+                                    let l22 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l21, l22, 1);
+                                    let l23 = i32::from(
+                                        *arg0
+                                            .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l23 {
+                                        0 => {}
+                                        _ => {
+                                            let l24 = *arg0
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l25 = *arg0
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l24, l25, 1);
+                                        }
+                                    }
+                                    let l26 = *arg0
+                                        .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l27 = *arg0
+                                        .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let base39 = l26;
+                                    let len39 = l27;
+                                    for i in 0..len39 {
+                                        let base = base39
+                                            .add(i * (11 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let l28 = i32::from(*base.add(0).cast::<u8>());
+                                            match l28 {
+                                                0 => {}
+                                                _ => {
+                                                    let l29 = *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l30 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l29, l30, 1);
+                                                }
+                                            }
+                                            let l31 = i32::from(
+                                                *base
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            match l31 {
+                                                0 => {}
+                                                _ => {
+                                                    let l32 = *base
+                                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l33 = *base
+                                                        .add(5 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l32, l33, 1);
+                                                }
+                                            }
+                                            let l34 = *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l35 = *base
+                                                .add(7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l34, l35, 1);
+                                            let l36 = i32::from(
+                                                *base
+                                                    .add(8 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            match l36 {
+                                                0 => {}
+                                                _ => {
+                                                    let l37 = *base
+                                                        .add(9 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l38 = *base
+                                                        .add(10 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l37, l38, 1);
+                                                }
+                                            }
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(
+                                        base39,
+                                        len39 * (11 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                }
+                                _ => {
+                                    let l40 = *arg0.add(16).cast::<*mut u8>();
+                                    let l41 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l40, l41, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_update_issue_cabi<T: GuestClient>(
+                    arg0: *mut u8,
+                    arg1: *mut u8,
+                    arg2: usize,
+                    arg3: *mut u8,
+                    arg4: usize,
+                    arg5: i32,
+                    arg6: *mut u8,
+                    arg7: usize,
+                    arg8: *mut u8,
+                    arg9: usize,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let len0 = arg2;
+                    let bytes0 = _rt::Vec::from_raw_parts(arg1.cast(), len0, len0);
+                    let len1 = arg4;
+                    let bytes1 = _rt::Vec::from_raw_parts(arg3.cast(), len1, len1);
+                    let len2 = arg7;
+                    let bytes2 = _rt::Vec::from_raw_parts(arg6.cast(), len2, len2);
+                    let len3 = arg9;
+                    let bytes3 = _rt::Vec::from_raw_parts(arg8.cast(), len3, len3);
+                    let result4 = T::update_issue(
+                        unsafe { ClientBorrow::lift(arg0 as u32 as usize) }.get(),
+                        _rt::string_lift(bytes0),
+                        _rt::string_lift(bytes1),
+                        arg5 as u32,
+                        _rt::string_lift(bytes2),
+                        _rt::string_lift(bytes3),
+                    );
+                    let ptr5 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result4 {
+                        Ok(e) => {
+                            *ptr5.add(0).cast::<u8>() = (0i32) as u8;
+                            let super::super::super::super::wassemble::github::types::Issue {
+                                body: body6,
+                                number: number6,
+                                title: title6,
+                            } = e;
+                            let vec7 = (body6.into_bytes()).into_boxed_slice();
+                            let ptr7 = vec7.as_ptr().cast::<u8>();
+                            let len7 = vec7.len();
+                            ::core::mem::forget(vec7);
+                            *ptr5
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len7;
+                            *ptr5.add(8).cast::<*mut u8>() = ptr7.cast_mut();
+                            *ptr5
+                                .add(8 + 2 * ::core::mem::size_of::<*const u8>())
+                                .cast::<i32>() = _rt::as_i32(number6);
+                            let vec8 = (title6.into_bytes()).into_boxed_slice();
+                            let ptr8 = vec8.as_ptr().cast::<u8>();
+                            let len8 = vec8.len();
+                            ::core::mem::forget(vec8);
+                            *ptr5
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>() = len8;
+                            *ptr5
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>() = ptr8.cast_mut();
+                        }
+                        Err(e) => {
+                            *ptr5.add(0).cast::<u8>() = (1i32) as u8;
+                            use super::super::super::super::wassemble::github::types::GithubError as V28;
+                            match e {
+                                V28::RequestFailed(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (0i32) as u8;
+                                    let vec9 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr9 = vec9.as_ptr().cast::<u8>();
+                                    let len9 = vec9.len();
+                                    ::core::mem::forget(vec9);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len9;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr9.cast_mut();
+                                }
+                                V28::ResponseBodyError(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (1i32) as u8;
+                                    let vec10 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr10 = vec10.as_ptr().cast::<u8>();
+                                    let len10 = vec10.len();
+                                    ::core::mem::forget(vec10);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len10;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr10.cast_mut();
+                                }
+                                V28::Utf8Error(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (2i32) as u8;
+                                    let vec11 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr11 = vec11.as_ptr().cast::<u8>();
+                                    let len11 = vec11.len();
+                                    ::core::mem::forget(vec11);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len11;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr11.cast_mut();
+                                }
+                                V28::JsonParseError(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (3i32) as u8;
+                                    let vec12 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr12 = vec12.as_ptr().cast::<u8>();
+                                    let len12 = vec12.len();
+                                    ::core::mem::forget(vec12);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len12;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr12.cast_mut();
+                                }
+                                V28::ConfigError(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (4i32) as u8;
+                                    let vec13 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr13 = vec13.as_ptr().cast::<u8>();
+                                    let len13 = vec13.len();
+                                    ::core::mem::forget(vec13);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len13;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr13.cast_mut();
+                                }
+                                V28::HttpError(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (5i32) as u8;
+                                    let super::super::super::super::wassemble::github::types::ApiError {
+                                        status: status14,
+                                        message: message14,
+                                        documentation_url: documentation_url14,
+                                    } = e;
+                                    *ptr5.add(16).cast::<u16>() = (_rt::as_i32(status14))
+                                        as u16;
+                                    let vec15 = (message14.into_bytes()).into_boxed_slice();
+                                    let ptr15 = vec15.as_ptr().cast::<u8>();
+                                    let len15 = vec15.len();
+                                    ::core::mem::forget(vec15);
+                                    *ptr5
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len15;
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr15.cast_mut();
+                                    match documentation_url14 {
+                                        Some(e) => {
+                                            *ptr5
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec16 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr16 = vec16.as_ptr().cast::<u8>();
+                                            let len16 = vec16.len();
+                                            ::core::mem::forget(vec16);
+                                            *ptr5
+                                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len16;
+                                            *ptr5
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr16.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr5
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                                V28::ValidationFailed(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (6i32) as u8;
+                                    let super::super::super::super::wassemble::github::types::ValidationFailure {
+                                        message: message17,
+                                        documentation_url: documentation_url17,
+                                        errors: errors17,
+                                    } = e;
+                                    let vec18 = (message17.into_bytes()).into_boxed_slice();
+                                    let ptr18 = vec18.as_ptr().cast::<u8>();
+                                    let len18 = vec18.len();
+                                    ::core::mem::forget(vec18);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len18;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr18.cast_mut();
+                                    match documentation_url17 {
+                                        Some(e) => {
+                                            *ptr5
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            let vec19 = (e.into_bytes()).into_boxed_slice();
+                                            let ptr19 = vec19.as_ptr().cast::<u8>();
+                                            let len19 = vec19.len();
+                                            ::core::mem::forget(vec19);
+                                            *ptr5
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len19;
+                                            *ptr5
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr19.cast_mut();
+                                        }
+                                        None => {
+                                            *ptr5
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    let vec25 = errors17;
+                                    let len25 = vec25.len();
+                                    let layout25 = _rt::alloc::Layout::from_size_align_unchecked(
+                                        vec25.len() * (11 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                    let result25 = if layout25.size() != 0 {
+                                        let ptr = _rt::alloc::alloc(layout25).cast::<u8>();
+                                        if ptr.is_null() {
+                                            _rt::alloc::handle_alloc_error(layout25);
+                                        }
+                                        ptr
+                                    } else {
+                                        ::core::ptr::null_mut()
+                                    };
+                                    for (i, e) in vec25.into_iter().enumerate() {
+                                        let base = result25
+                                            .add(i * (11 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let super::super::super::super::wassemble::github::types::ValidationError {
+                                                resource: resource20,
+                                                field: field20,
+                                                code: code20,
+                                                message: message20,
+                                            } = e;
+                                            match resource20 {
+                                                Some(e) => {
+                                                    *base.add(0).cast::<u8>() = (1i32) as u8;
+                                                    let vec21 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr21 = vec21.as_ptr().cast::<u8>();
+                                                    let len21 = vec21.len();
+                                                    ::core::mem::forget(vec21);
+                                                    *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len21;
+                                                    *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr21.cast_mut();
+                                                }
+                                                None => {
+                                                    *base.add(0).cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            match field20 {
+                                                Some(e) => {
+                                                    *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    let vec22 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr22 = vec22.as_ptr().cast::<u8>();
+                                                    let len22 = vec22.len();
+                                                    ::core::mem::forget(vec22);
+                                                    *base
+                                                        .add(5 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len22;
+                                                    *base
+                                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr22.cast_mut();
+                                                }
+                                                None => {
+                                                    *base
+                                                        .add(3 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                            let vec23 = (code20.into_bytes()).into_boxed_slice();
+                                            let ptr23 = vec23.as_ptr().cast::<u8>();
+                                            let len23 = vec23.len();
+                                            ::core::mem::forget(vec23);
+                                            *base
+                                                .add(7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>() = len23;
+                                            *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>() = ptr23.cast_mut();
+                                            match message20 {
+                                                Some(e) => {
+                                                    *base
+                                                        .add(8 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (1i32) as u8;
+                                                    let vec24 = (e.into_bytes()).into_boxed_slice();
+                                                    let ptr24 = vec24.as_ptr().cast::<u8>();
+                                                    let len24 = vec24.len();
+                                                    ::core::mem::forget(vec24);
+                                                    *base
+                                                        .add(10 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>() = len24;
+                                                    *base
+                                                        .add(9 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>() = ptr24.cast_mut();
+                                                }
+                                                None => {
+                                                    *base
+                                                        .add(8 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<u8>() = (0i32) as u8;
+                                                }
+                                            };
+                                        }
+                                    }
+                                    *ptr5
+                                        .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len25;
+                                    *ptr5
+                                        .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = result25;
+                                }
+                                V28::RateLimited(e) => {
+                                    *ptr5.add(8).cast::<u8>() = (7i32) as u8;
+                                    let super::super::super::super::wassemble::github::types::RateLimit {
+                                        message: message26,
+                                        limit: limit26,
+                                        remaining: remaining26,
+                                        reset: reset26,
+                                        retry_after: retry_after26,
+                                    } = e;
+                                    let vec27 = (message26.into_bytes()).into_boxed_slice();
+                                    let ptr27 = vec27.as_ptr().cast::<u8>();
+                                    let len27 = vec27.len();
+                                    ::core::mem::forget(vec27);
+                                    *ptr5
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len27;
+                                    *ptr5.add(16).cast::<*mut u8>() = ptr27.cast_mut();
+                                    match limit26 {
+                                        Some(e) => {
+                                            *ptr5
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr5
+                                                .add(20 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr5
+                                                .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match remaining26 {
+                                        Some(e) => {
+                                            *ptr5
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr5
+                                                .add(28 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i32>() = _rt::as_i32(e);
+                                        }
+                                        None => {
+                                            *ptr5
+                                                .add(24 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match reset26 {
+                                        Some(e) => {
+                                            *ptr5
+                                                .add(32 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr5
+                                                .add(40 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr5
+                                                .add(32 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                    match retry_after26 {
+                                        Some(e) => {
+                                            *ptr5
+                                                .add(48 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (1i32) as u8;
+                                            *ptr5
+                                                .add(56 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<i64>() = _rt::as_i64(e);
+                                        }
+                                        None => {
+                                            *ptr5
+                                                .add(48 + 2 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<u8>() = (0i32) as u8;
+                                        }
+                                    };
+                                }
+                            }
+                        }
+                    };
+                    ptr5
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_update_issue<T: GuestClient>(
+                    arg0: *mut u8,
+                ) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {
+                            let l1 = *arg0.add(8).cast::<*mut u8>();
+                            let l2 = *arg0
+                                .add(8 + 1 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l1, l2, 1);
+                            let l3 = *arg0
+                                .add(8 + 3 * ::core::mem::size_of::<*const u8>())
+                                .cast::<*mut u8>();
+                            let l4 = *arg0
+                                .add(8 + 4 * ::core::mem::size_of::<*const u8>())
+                                .cast::<usize>();
+                            _rt::cabi_dealloc(l3, l4, 1);
+                        }
+                        _ => {
+                            let l5 = i32::from(*arg0.add(8).cast::<u8>());
+                            match l5 {
+                                0 => {
+                                    let l6 = *arg0.add(16).cast::<*mut u8>();
+                                    let l7 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l6, l7, 1);
+                                }
+                                1 => {
+                                    let l8 = *arg0.add(16).cast::<*mut u8>();
+                                    let l9 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l8, l9, 1);
+                                }
+                                2 => {
+                                    let l10 = *arg0.add(16).cast::<*mut u8>();
+                                    let l11 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l10, l11, 1);
+                                }
+                                3 => {
+                                    let l12 = *arg0.add(16).cast::<*mut u8>();
+                                    let l13 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l12, l13, 1);
+                                }
+                                4 => {
+                                    let l14 = *arg0.add(16).cast::<*mut u8>();
+                                    let l15 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l14, l15, 1);
+                                }
+                                5 => {
+                                    let l16 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l17 = *arg0
+                                        .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l16, l17, 1);
+                                    let l18 = i32::from(
+                                        *arg0
+                                            .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l18 {
+                                        0 => {}
+                                        _ => {
+                                            let l19 = *arg0
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l20 = *arg0
+                                                .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l19, l20, 1);
+                                        }
+                                    }
+                                }
+                                6 => {
+                                    let l21 = *arg0.add(16).cast::<*mut u8>();
+                                    let l22 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l21, l22, 1);
+                                    let l23 = i32::from(
+                                        *arg0
+                                            .add(16 + 2 * ::core::mem::size_of::<*const u8>())
+                                            .cast::<u8>(),
+                                    );
+                                    match l23 {
+                                        0 => {}
+                                        _ => {
+                                            let l24 = *arg0
+                                                .add(16 + 3 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l25 = *arg0
+                                                .add(16 + 4 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l24, l25, 1);
+                                        }
+                                    }
+                                    let l26 = *arg0
+                                        .add(16 + 5 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l27 = *arg0
+                                        .add(16 + 6 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    let base39 = l26;
+                                    let len39 = l27;
+                                    for i in 0..len39 {
+                                        let base = base39
+                                            .add(i * (11 * ::core::mem::size_of::<*const u8>()));
+                                        {
+                                            let l28 = i32::from(*base.add(0).cast::<u8>());
+                                            match l28 {
+                                                0 => {}
+                                                _ => {
+                                                    let l29 = *base
+                                                        .add(::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l30 = *base
+                                                        .add(2 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l29, l30, 1);
+                                                }
+                                            }
+                                            let l31 = i32::from(
+                                                *base
+                                                    .add(3 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            match l31 {
+                                                0 => {}
+                                                _ => {
+                                                    let l32 = *base
+                                                        .add(4 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l33 = *base
+                                                        .add(5 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l32, l33, 1);
+                                                }
+                                            }
+                                            let l34 = *base
+                                                .add(6 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<*mut u8>();
+                                            let l35 = *base
+                                                .add(7 * ::core::mem::size_of::<*const u8>())
+                                                .cast::<usize>();
+                                            _rt::cabi_dealloc(l34, l35, 1);
+                                            let l36 = i32::from(
+                                                *base
+                                                    .add(8 * ::core::mem::size_of::<*const u8>())
+                                                    .cast::<u8>(),
+                                            );
+                                            match l36 {
+                                                0 => {}
+                                                _ => {
+                                                    let l37 = *base
+                                                        .add(9 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<*mut u8>();
+                                                    let l38 = *base
+                                                        .add(10 * ::core::mem::size_of::<*const u8>())
+                                                        .cast::<usize>();
+                                                    _rt::cabi_dealloc(l37, l38, 1);
+                                                }
+                                            }
+                                        }
+                                    }
+                                    _rt::cabi_dealloc(
+                                        base39,
+                                        len39 * (11 * ::core::mem::size_of::<*const u8>()),
+                                        ::core::mem::size_of::<*const u8>(),
+                                    );
+                                }
+                                _ => {
+                                    let l40 = *arg0.add(16).cast::<*mut u8>();
+                                    let l41 = *arg0
+                                        .add(16 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l40, l41, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn _export_method_client_last_rate_limit_cabi<T: GuestClient>(
+                    arg0: *mut u8,
+                ) -> *mut u8 {
+                    #[cfg(target_arch = "wasm32")] _rt::run_ctors_once();
+                    let result0 = T::last_rate_limit(
+                        unsafe { ClientBorrow::lift(arg0 as u32 as usize) }.get(),
+                    );
+                    let ptr1 = (&raw mut _RET_AREA.0).cast::<u8>();
+                    match result0 {
+                        Some(e) => {
+                            *ptr1.add(0).cast::<u8>() = (1i32) as u8;
+                            let super::super::super::super::wassemble::github::types::RateLimitStatus {
+                                limit: limit2,
+                                remaining: remaining2,
+                                used: used2,
+                                reset: reset2,
+                                resource: resource2,
+                            } = e;
+                            *ptr1.add(8).cast::<i32>() = _rt::as_i32(limit2);
+                            *ptr1.add(12).cast::<i32>() = _rt::as_i32(remaining2);
+                            match used2 {
+                                Some(e) => {
+                                    *ptr1.add(16).cast::<u8>() = (1i32) as u8;
+                                    *ptr1.add(20).cast::<i32>() = _rt::as_i32(e);
+                                }
+                                None => {
+                                    *ptr1.add(16).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                            *ptr1.add(24).cast::<i64>() = _rt::as_i64(reset2);
+                            match resource2 {
+                                Some(e) => {
+                                    *ptr1.add(32).cast::<u8>() = (1i32) as u8;
+                                    let vec3 = (e.into_bytes()).into_boxed_slice();
+                                    let ptr3 = vec3.as_ptr().cast::<u8>();
+                                    let len3 = vec3.len();
+                                    ::core::mem::forget(vec3);
+                                    *ptr1
+                                        .add(32 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>() = len3;
+                                    *ptr1
+                                        .add(32 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>() = ptr3.cast_mut();
+                                }
+                                None => {
+                                    *ptr1.add(32).cast::<u8>() = (0i32) as u8;
+                                }
+                            };
+                        }
+                        None => {
+                            *ptr1.add(0).cast::<u8>() = (0i32) as u8;
+                        }
+                    };
+                    ptr1
+                }
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                pub unsafe fn __post_return_method_client_last_rate_limit<
+                    T: GuestClient,
+                >(arg0: *mut u8) {
+                    let l0 = i32::from(*arg0.add(0).cast::<u8>());
+                    match l0 {
+                        0 => {}
+                        _ => {
+                            let l1 = i32::from(*arg0.add(32).cast::<u8>());
+                            match l1 {
+                                0 => {}
+                                _ => {
+                                    let l2 = *arg0
+                                        .add(32 + 1 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<*mut u8>();
+                                    let l3 = *arg0
+                                        .add(32 + 2 * ::core::mem::size_of::<*const u8>())
+                                        .cast::<usize>();
+                                    _rt::cabi_dealloc(l2, l3, 1);
+                                }
+                            }
+                        }
+                    }
+                }
+                pub trait Guest {
+                    type Client: GuestClient;
+                }
+                pub trait GuestClient: 'static {
+                    #[doc(hidden)]
+                    unsafe fn _resource_new(val: *mut u8) -> u32
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = val;
+                            unreachable!();
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]wassemble:github/api@0.2.0"
+                            )]
+                            unsafe extern "C" {
+                                #[link_name = "[resource-new]client"]
+                                fn new(_: *mut u8) -> u32;
+                            }
+                            unsafe { new(val) }
+                        }
+                    }
+                    #[doc(hidden)]
+                    fn _resource_rep(handle: u32) -> *mut u8
+                    where
+                        Self: Sized,
+                    {
+                        #[cfg(not(target_arch = "wasm32"))]
+                        {
+                            let _ = handle;
+                            unreachable!();
+                        }
+                        #[cfg(target_arch = "wasm32")]
+                        {
+                            #[link(
+                                wasm_import_module = "[export]wassemble:github/api@0.2.0"
+                            )]
+                            unsafe extern "C" {
+                                #[link_name = "[resource-rep]client"]
+                                fn rep(_: u32) -> *mut u8;
+                            }
+                            unsafe { rep(handle) }
+                        }
+                    }
+                    fn new(config: Config) -> Self;
+                    fn create_issue(
+                        &self,
+                        owner: _rt::String,
+                        repo: _rt::String,
+                        title: _rt::String,
+                        body: _rt::String,
+                    ) -> Result<Issue, GithubError>;
+                    fn create_repository(
+                        &self,
+                        name: _rt::String,
+                        description: _rt::String,
+                    ) -> Result<Repository, GithubError>;
+                    fn delete_repository(
+                        &self,
+                        owner: _rt::String,
+                        repo: _rt::String,
+                    ) -> Result<(), GithubError>;
+                    fn get_user(&self) -> Result<User, GithubError>;
+                    fn update_issue(
+                        &self,
+                        owner: _rt::String,
+                        repo: _rt::String,
+                        number: u32,
+                        title: _rt::String,
+                        body: _rt::String,
+                    ) -> Result<Issue, GithubError>;
+                    /// Rate limit counters of the most recent response, if it carried them
+                    fn last_rate_limit(&self) -> Option<RateLimitStatus>;
+                }
+                #[doc(hidden)]
+                macro_rules! __export_wassemble_github_api_0_2_0_cabi {
+                    ($ty:ident with_types_in $($path_to_types:tt)*) => {
+                        const _ : () = { #[unsafe (export_name =
+                        "wassemble:github/api@0.2.0#[constructor]client")] unsafe extern
+                        "C" fn export_constructor_client(arg0 : * mut u8, arg1 : usize,
+                        arg2 : i32, arg3 : * mut u8, arg4 : usize, arg5 : i32, arg6 : *
+                        mut u8, arg7 : usize, arg8 : i32, arg9 : i32, arg10 : i32, arg11
+                        : i32, arg12 : i32, arg13 : i32, arg14 : * mut u8, arg15 :
+                        usize,) -> i32 { unsafe { $($path_to_types)*::
+                        _export_constructor_client_cabi::<<$ty as $($path_to_types)*::
+                        Guest >::Client > (arg0, arg1, arg2, arg3, arg4, arg5, arg6,
+                        arg7, arg8, arg9, arg10, arg11, arg12, arg13, arg14, arg15) } }
+                        #[unsafe (export_name =
+                        "wassemble:github/api@0.2.0#[method]client.create-issue")] unsafe
+                        extern "C" fn export_method_client_create_issue(arg0 : * mut u8,
+                        arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4 : usize,
+                        arg5 : * mut u8, arg6 : usize, arg7 : * mut u8, arg8 : usize,) ->
+                        * mut u8 { unsafe { $($path_to_types)*::
+                        _export_method_client_create_issue_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7, arg8) } } #[unsafe (export_name =
+                        "cabi_post_wassemble:github/api@0.2.0#[method]client.create-issue")]
+                        unsafe extern "C" fn _post_return_method_client_create_issue(arg0
+                        : * mut u8,) { unsafe { $($path_to_types)*::
+                        __post_return_method_client_create_issue::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "wassemble:github/api@0.2.0#[method]client.create-repository")]
+                        unsafe extern "C" fn export_method_client_create_repository(arg0
+                        : * mut u8, arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4
+                        : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_method_client_create_repository_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0, arg1, arg2, arg3,
+                        arg4) } } #[unsafe (export_name =
+                        "cabi_post_wassemble:github/api@0.2.0#[method]client.create-repository")]
+                        unsafe extern "C" fn
+                        _post_return_method_client_create_repository(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_method_client_create_repository::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "wassemble:github/api@0.2.0#[method]client.delete-repository")]
+                        unsafe extern "C" fn export_method_client_delete_repository(arg0
+                        : * mut u8, arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4
+                        : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_method_client_delete_repository_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0, arg1, arg2, arg3,
+                        arg4) } } #[unsafe (export_name =
+                        "cabi_post_wassemble:github/api@0.2.0#[method]client.delete-repository")]
+                        unsafe extern "C" fn
+                        _post_return_method_client_delete_repository(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_method_client_delete_repository::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "wassemble:github/api@0.2.0#[method]client.get-user")] unsafe
+                        extern "C" fn export_method_client_get_user(arg0 : * mut u8,) ->
+                        * mut u8 { unsafe { $($path_to_types)*::
+                        _export_method_client_get_user_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "cabi_post_wassemble:github/api@0.2.0#[method]client.get-user")]
+                        unsafe extern "C" fn _post_return_method_client_get_user(arg0 : *
+                        mut u8,) { unsafe { $($path_to_types)*::
+                        __post_return_method_client_get_user::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "wassemble:github/api@0.2.0#[method]client.update-issue")] unsafe
+                        extern "C" fn export_method_client_update_issue(arg0 : * mut u8,
+                        arg1 : * mut u8, arg2 : usize, arg3 : * mut u8, arg4 : usize,
+                        arg5 : i32, arg6 : * mut u8, arg7 : usize, arg8 : * mut u8, arg9
+                        : usize,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_method_client_update_issue_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0, arg1, arg2, arg3,
+                        arg4, arg5, arg6, arg7, arg8, arg9) } } #[unsafe (export_name =
+                        "cabi_post_wassemble:github/api@0.2.0#[method]client.update-issue")]
+                        unsafe extern "C" fn _post_return_method_client_update_issue(arg0
+                        : * mut u8,) { unsafe { $($path_to_types)*::
+                        __post_return_method_client_update_issue::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "wassemble:github/api@0.2.0#[method]client.last-rate-limit")]
+                        unsafe extern "C" fn export_method_client_last_rate_limit(arg0 :
+                        * mut u8,) -> * mut u8 { unsafe { $($path_to_types)*::
+                        _export_method_client_last_rate_limit_cabi::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } #[unsafe
+                        (export_name =
+                        "cabi_post_wassemble:github/api@0.2.0#[method]client.last-rate-limit")]
+                        unsafe extern "C" fn
+                        _post_return_method_client_last_rate_limit(arg0 : * mut u8,) {
+                        unsafe { $($path_to_types)*::
+                        __post_return_method_client_last_rate_limit::<<$ty as
+                        $($path_to_types)*:: Guest >::Client > (arg0) } } const _ : () =
+                        { #[doc(hidden)] #[unsafe (export_name =
+                        "wassemble:github/api@0.2.0#[dtor]client")]
+                        #[allow(non_snake_case)] unsafe extern "C" fn dtor(rep : * mut
+                        u8) { unsafe { $($path_to_types)*:: Client::dtor::< <$ty as
+                        $($path_to_types)*:: Guest >::Client > (rep) } } }; };
+                    };
+                }
+                #[doc(hidden)]
+                pub(crate) use __export_wassemble_github_api_0_2_0_cabi;
+                #[repr(align(8))]
+                struct _RetArea(
+                    [::core::mem::MaybeUninit<
+                        u8,
+                    >; 64 + 2 * ::core::mem::size_of::<*const u8>()],
+                );
+                static mut _RET_AREA: _RetArea = _RetArea(
+                    [::core::mem::MaybeUninit::uninit(); 64
+                        + 2 * ::core::mem::size_of::<*const u8>()],
+                );
+            }
         }
     }
 }
@@ -410,10 +3974,6 @@ pub mod wassemble {
 mod _rt {
     #![allow(dead_code, clippy::all)]
     pub use alloc_crate::string::String;
-    #[cfg(target_arch = "wasm32")]
-    pub fn run_ctors_once() {
-        wit_bindgen_rt::run_ctors_once();
-    }
     pub use alloc_crate::vec::Vec;
     pub unsafe fn string_lift(bytes: Vec<u8>) -> String {
         if cfg!(debug_assertions) {
@@ -422,6 +3982,99 @@ mod _rt {
             String::from_utf8_unchecked(bytes)
         }
     }
+    pub unsafe fn invalid_enum_discriminant<T>() -> T {
+        if cfg!(debug_assertions) {
+            panic!("invalid enum discriminant")
+        } else {
+            unsafe { core::hint::unreachable_unchecked() }
+        }
+    }
+    pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
+        if size == 0 {
+            return;
+        }
+        let layout = alloc::Layout::from_size_align_unchecked(size, align);
+        alloc::dealloc(ptr, layout);
+    }
+    use core::fmt;
+    use core::marker;
+    use core::sync::atomic::{AtomicU32, Ordering::Relaxed};
+    /// A type which represents a component model resource, either imported or
+    /// exported into this component.
+    ///
+    /// This is a low-level wrapper which handles the lifetime of the resource
+    /// (namely this has a destructor). The `T` provided defines the component model
+    /// intrinsics that this wrapper uses.
+    ///
+    /// One of the chief purposes of this type is to provide `Deref` implementations
+    /// to access the underlying data when it is owned.
+    ///
+    /// This type is primarily used in generated code for exported and imported
+    /// resources.
+    #[repr(transparent)]
+    pub struct Resource<T: WasmResource> {
+        handle: AtomicU32,
+        _marker: marker::PhantomData<T>,
+    }
+    /// A trait which all wasm resources implement, namely providing the ability to
+    /// drop a resource.
+    ///
+    /// This generally is implemented by generated code, not user-facing code.
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe trait WasmResource {
+        /// Invokes the `[resource-drop]...` intrinsic.
+        unsafe fn drop(handle: u32);
+    }
+    impl<T: WasmResource> Resource<T> {
+        #[doc(hidden)]
+        pub unsafe fn from_handle(handle: u32) -> Self {
+            debug_assert!(handle != u32::MAX);
+            Self {
+                handle: AtomicU32::new(handle),
+                _marker: marker::PhantomData,
+            }
+        }
+        /// Takes ownership of the handle owned by `resource`.
+        ///
+        /// Note that this ideally would be `into_handle` taking `Resource<T>` by
+        /// ownership. The code generator does not enable that in all situations,
+        /// unfortunately, so this is provided instead.
+        ///
+        /// Also note that `take_handle` is in theory only ever called on values
+        /// owned by a generated function. For example a generated function might
+        /// take `Resource<T>` as an argument but then call `take_handle` on a
+        /// reference to that argument. In that sense the dynamic nature of
+        /// `take_handle` should only be exposed internally to generated code, not
+        /// to user code.
+        #[doc(hidden)]
+        pub fn take_handle(resource: &Resource<T>) -> u32 {
+            resource.handle.swap(u32::MAX, Relaxed)
+        }
+        #[doc(hidden)]
+        pub fn handle(resource: &Resource<T>) -> u32 {
+            resource.handle.load(Relaxed)
+        }
+    }
+    impl<T: WasmResource> fmt::Debug for Resource<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Resource").field("handle", &self.handle).finish()
+        }
+    }
+    impl<T: WasmResource> Drop for Resource<T> {
+        fn drop(&mut self) {
+            unsafe {
+                match self.handle.load(Relaxed) {
+                    u32::MAX => {}
+                    other => T::drop(other),
+                }
+            }
+        }
+    }
+    pub use alloc_crate::boxed::Box;
+    #[cfg(target_arch = "wasm32")]
+    pub fn run_ctors_once() {
+        wit_bindgen_rt::run_ctors_once();
+    }
     pub fn as_i32<T: AsI32>(t: T) -> i32 {
         t.as_i32()
     }
@@ -481,13 +4134,7 @@ mod _rt {
             self as i32
         }
     }
-    pub unsafe fn cabi_dealloc(ptr: *mut u8, size: usize, align: usize) {
-        if size == 0 {
-            return;
-        }
-        let layout = alloc::Layout::from_size_align_unchecked(size, align);
-        alloc::dealloc(ptr, layout);
-    }
+    pub use alloc_crate::alloc;
     pub fn as_i64<T: AsI64>(t: T) -> i64 {
         t.as_i64()
     }
@@ -512,7 +4159,6 @@ mod _rt {
         }
     }
     extern crate alloc as alloc_crate;
-    pub use alloc_crate::alloc;
 }
 /// Generates `#[unsafe(no_mangle)]` functions to export the specified type as
 /// the root implementation of all generated traits.
@@ -532,38 +4178,64 @@ mod _rt {
 /// ```
 #[allow(unused_macros)]
 #[doc(hidden)]
-macro_rules! __export_github_impl {
+macro_rules! __export_github_with_config_impl {
     ($ty:ident) => {
         self::export!($ty with_types_in self);
     };
     ($ty:ident with_types_in $($path_to_types_root:tt)*) => {
-        $($path_to_types_root)*:: __export_world_github_cabi!($ty with_types_in
-        $($path_to_types_root)*);
+        $($path_to_types_root)*::
+        exports::wassemble::github::api::__export_wassemble_github_api_0_2_0_cabi!($ty
+        with_types_in $($path_to_types_root)*:: exports::wassemble::github::api);
     };
 }
 #[doc(inline)]
-pub(crate) use __export_github_impl as export;
+pub(crate) use __export_github_with_config_impl as export;
 #[cfg(target_arch = "wasm32")]
 #[unsafe(
-    link_section = "component-type:wit-bindgen:0.41.0:wassemble:github@0.1.0:github:encoded world"
+    link_section = "component-type:wit-bindgen:0.41.0:wassemble:github@0.2.0:github-with-config:encoded world"
 )]
 #[doc(hidden)]
 #[allow(clippy::octal_escapes)]
-pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 631] = *b"\
-\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xfa\x03\x01A\x02\x01\
-A\x12\x01B\x06\x01r\x03\x04bodys\x06numbery\x05titles\x04\0\x05issue\x03\0\0\x01\
-r\x03\x04names\x05owners\x0bdescriptions\x04\0\x0arepository\x03\0\x02\x01r\x03\x05\
-logins\x02idw\x0aavatar-urls\x04\0\x04user\x03\0\x04\x03\0\x1cwassemble:github/t\
-ypes@0.1.0\x05\0\x02\x03\0\0\x05issue\x03\0\x05issue\x03\0\x01\x02\x03\0\0\x0are\
-pository\x03\0\x0arepository\x03\0\x03\x02\x03\0\0\x04user\x03\0\x04user\x03\0\x05\
-\x01@\x05\x05tokens\x05owners\x04repos\x05titles\x04bodys\0\x02\x04\0\x0ccreate-\
-issue\x01\x07\x01@\x03\x05tokens\x04names\x0bdescriptions\0\x04\x04\0\x11create-\
-repository\x01\x08\x01@\x03\x05tokens\x05owners\x04repos\0\x7f\x04\0\x11delete-r\
-epository\x01\x09\x01@\x01\x05tokens\0\x06\x04\0\x08get-user\x01\x0a\x01@\x06\x05\
-tokens\x05owners\x04repos\x06numbery\x05titles\x04bodys\0\x02\x04\0\x0cupdate-is\
-sue\x01\x0b\x04\0\x1dwassemble:github/github@0.1.0\x04\0\x0b\x0c\x01\0\x06github\
-\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070.227.1\x10\
-wit-bindgen-rust\x060.41.0";
+pub static __WIT_BINDGEN_COMPONENT_TYPE: [u8; 1787] = *b"\
+\0asm\x0d\0\x01\0\0\x19\x16wit-component-encoding\x04\0\x07\xf2\x0c\x01A\x02\x01\
+A\x0c\x01B\x0b\x01q\x02\x08upstream\x01s\0\x02io\x01s\0\x04\0\x05error\x03\0\0\x01\
+ks\x01j\x01\x02\x01\x01\x01@\x01\x03keys\0\x03\x04\0\x03get\x01\x04\x01o\x02ss\x01\
+p\x05\x01j\x01\x06\x01\x01\x01@\0\0\x07\x04\0\x07get-all\x01\x08\x03\0\x1dwasi:c\
+onfig/store@0.2.0-draft\x05\0\x01B\x1c\x01p{\x01r\x04\x0cmax-attemptsy\x10initia\
+l-delay-msy\x0cmax-delay-msy\x0eretry-statuses\0\x04\0\x0cretry-config\x03\0\x01\
+\x01ks\x01ky\x01k\x02\x01r\x05\x05tokens\x08base-url\x03\x0auser-agent\x03\x12co\
+nnect-timeout-ms\x04\x05retry\x05\x04\0\x06config\x03\0\x06\x01r\x03\x04bodys\x06\
+numbery\x05titles\x04\0\x05issue\x03\0\x08\x01r\x03\x04names\x05owners\x0bdescri\
+ptions\x04\0\x0arepository\x03\0\x0a\x01r\x03\x05logins\x02idw\x0aavatar-urls\x04\
+\0\x04user\x03\0\x0c\x01r\x03\x06status{\x07messages\x11documentation-url\x03\x04\
+\0\x09api-error\x03\0\x0e\x01r\x04\x08resource\x03\x05field\x03\x04codes\x07mess\
+age\x03\x04\0\x10validation-error\x03\0\x10\x01p\x11\x01r\x03\x07messages\x11doc\
+umentation-url\x03\x06errors\x12\x04\0\x12validation-failure\x03\0\x13\x01kw\x01\
+r\x05\x07messages\x05limit\x04\x09remaining\x04\x05reset\x15\x0bretry-after\x15\x04\
+\0\x0arate-limit\x03\0\x16\x01r\x05\x05limity\x09remainingy\x04used\x04\x05reset\
+w\x08resource\x03\x04\0\x11rate-limit-status\x03\0\x18\x01q\x08\x0erequest-faile\
+d\x01s\0\x13response-body-error\x01s\0\x0autf8-error\x01s\0\x10json-parse-error\x01\
+s\0\x0cconfig-error\x01s\0\x0ahttp-error\x01\x0f\0\x11validation-failed\x01\x14\0\
+\x0crate-limited\x01\x17\0\x04\0\x0cgithub-error\x03\0\x1a\x03\0\x1cwassemble:gi\
+thub/types@0.2.0\x05\x01\x02\x03\0\x01\x06config\x02\x03\0\x01\x05issue\x02\x03\0\
+\x01\x0arepository\x02\x03\0\x01\x04user\x02\x03\0\x01\x11rate-limit-status\x02\x03\
+\0\x01\x0cgithub-error\x01B\"\x02\x03\x02\x01\x02\x04\0\x06config\x03\0\0\x02\x03\
+\x02\x01\x03\x04\0\x05issue\x03\0\x02\x02\x03\x02\x01\x04\x04\0\x0arepository\x03\
+\0\x04\x02\x03\x02\x01\x05\x04\0\x04user\x03\0\x06\x02\x03\x02\x01\x06\x04\0\x11\
+rate-limit-status\x03\0\x08\x02\x03\x02\x01\x07\x04\0\x0cgithub-error\x03\0\x0a\x04\
+\0\x06client\x03\x01\x01i\x0c\x01@\x01\x06config\x01\0\x0d\x04\0\x13[constructor\
+]client\x01\x0e\x01h\x0c\x01j\x01\x03\x01\x0b\x01@\x05\x04self\x0f\x05owners\x04\
+repos\x05titles\x04bodys\0\x10\x04\0\x1b[method]client.create-issue\x01\x11\x01j\
+\x01\x05\x01\x0b\x01@\x03\x04self\x0f\x04names\x0bdescriptions\0\x12\x04\0\x20[m\
+ethod]client.create-repository\x01\x13\x01j\0\x01\x0b\x01@\x03\x04self\x0f\x05ow\
+ners\x04repos\0\x14\x04\0\x20[method]client.delete-repository\x01\x15\x01j\x01\x07\
+\x01\x0b\x01@\x01\x04self\x0f\0\x16\x04\0\x17[method]client.get-user\x01\x17\x01\
+@\x06\x04self\x0f\x05owners\x04repos\x06numbery\x05titles\x04bodys\0\x10\x04\0\x1b\
+[method]client.update-issue\x01\x18\x01k\x09\x01@\x01\x04self\x0f\0\x19\x04\0\x1e\
+[method]client.last-rate-limit\x01\x1a\x04\0\x1awassemble:github/api@0.2.0\x05\x08\
+\x04\0)wassemble:github/github-with-config@0.2.0\x04\0\x0b\x18\x01\0\x12github-w\
+ith-config\x03\0\0\0G\x09producers\x01\x0cprocessed-by\x02\x0dwit-component\x070\
+.227.1\x10wit-bindgen-rust\x060.41.0";
 #[inline(never)]
 #[doc(hidden)]
 pub fn __link_custom_section_describing_imports() {
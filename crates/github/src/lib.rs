@@ -1,18 +1,29 @@
-use bindings::Guest;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use http_core::{Backoff, Client, Error, HeaderMap, Response};
 use serde::{Deserialize, Serialize};
-use waki::Client;
 
-use crate::bindings::{Issue, Repository, User};
+#[cfg(feature = "wasi-config")]
+use crate::bindings::wasi::config::store;
+use crate::bindings::{
+    exports::wassemble::github::api::{Guest, GuestClient},
+    wassemble::github::types::{
+        ApiError, Config, GithubError, Issue, RateLimit, RateLimitStatus, Repository, User,
+        ValidationError, ValidationFailure,
+    },
+};
 
 #[allow(warnings)]
 mod bindings;
 
 const GITHUB_API_BASE: &str = "https://api.github.com";
 
-#[derive(Deserialize, Serialize)]
-struct GitHubResponse<T> {
-    #[serde(flatten)]
-    data: T,
+#[derive(Deserialize)]
+struct GitHubErrorBody {
+    documentation_url: Option<String>,
+    #[serde(default)]
+    errors: Vec<GitHubValidationError>,
+    message: String,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -37,154 +48,276 @@ struct GitHubUser {
     login: String,
 }
 
+/// GitHub reports most validation errors as objects, but some endpoints use plain strings.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum GitHubValidationError {
+    Detailed {
+        #[serde(default)]
+        code: String,
+        field: Option<String>,
+        message: Option<String>,
+        resource: Option<String>,
+    },
+    Message(String),
+}
+
+impl From<GitHubValidationError> for ValidationError {
+    fn from(error: GitHubValidationError) -> Self {
+        match error {
+            GitHubValidationError::Detailed {
+                code,
+                field,
+                message,
+                resource,
+            } => ValidationError {
+                resource,
+                field,
+                code,
+                message,
+            },
+            GitHubValidationError::Message(message) => ValidationError {
+                resource: None,
+                field: None,
+                code: "custom".to_string(),
+                message: Some(message),
+            },
+        }
+    }
+}
+
+impl From<Error> for GithubError {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::Build(message) | Error::Connect(message) | Error::Request(message) => {
+                GithubError::RequestFailed(message)
+            }
+            Error::ResponseBody(message) => GithubError::ResponseBodyError(message),
+            Error::Utf8(message) => GithubError::Utf8Error(message),
+            Error::Json(message) => GithubError::JsonParseError(message),
+            Error::Status(response) => error_from_response(&response),
+        }
+    }
+}
+
+fn error_from_response(response: &Response) -> GithubError {
+    let status = response.status();
+    let body = String::from_utf8_lossy(response.body());
+    let (message, documentation_url, errors) = match serde_json::from_str::<GitHubErrorBody>(&body)
+    {
+        Ok(error) => (error.message, error.documentation_url, error.errors),
+        Err(_) => (body.to_string(), None, Vec::new()),
+    };
+
+    let remaining = response.header_value::<u32>("x-ratelimit-remaining");
+    let retry_after = response.header_value::<u64>("retry-after");
+    let is_rate_limited =
+        status == 429 || (status == 403 && (remaining == Some(0) || retry_after.is_some()));
+
+    if is_rate_limited {
+        GithubError::RateLimited(RateLimit {
+            message,
+            limit: response.header_value("x-ratelimit-limit"),
+            remaining,
+            reset: response.header_value("x-ratelimit-reset"),
+            retry_after,
+        })
+    } else if status == 422 {
+        GithubError::ValidationFailed(ValidationFailure {
+            message,
+            documentation_url,
+            errors: errors.into_iter().map(ValidationError::from).collect(),
+        })
+    } else {
+        GithubError::HttpError(ApiError {
+            status,
+            message,
+            documentation_url,
+        })
+    }
+}
+
+/// GitHub signals an exhausted primary rate limit with `x-ratelimit-remaining: 0` and the
+/// time it resets at, rather than with `retry-after`.
+fn rate_limit_delay(response: &Response) -> Option<Duration> {
+    if let Some(delay) = http_core::retry_after(response) {
+        return Some(delay);
+    }
+    if !matches!(response.status(), 403 | 429)
+        || response.header_value::<u32>("x-ratelimit-remaining") != Some(0)
+    {
+        return None;
+    }
+    let reset = response.header_value::<u64>("x-ratelimit-reset")?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+    Some(Duration::from_secs(reset.saturating_sub(now)))
+}
+
+fn rate_limit_status(headers: &HeaderMap) -> Option<RateLimitStatus> {
+    let header = |name| headers.get(name)?.to_str().ok();
+    Some(RateLimitStatus {
+        limit: header("x-ratelimit-limit")?.parse().ok()?,
+        remaining: header("x-ratelimit-remaining")?.parse().ok()?,
+        used: header("x-ratelimit-used").and_then(|used| used.parse().ok()),
+        reset: header("x-ratelimit-reset")?.parse().ok()?,
+        resource: header("x-ratelimit-resource").map(str::to_string),
+    })
+}
+
+/// Returns `value`, or the `key` entry of `wasi:config/store` when `value` is empty.
+#[cfg(feature = "wasi-config")]
+fn config_fallback(value: String, key: &str) -> Result<Option<String>, GithubError> {
+    if !value.is_empty() {
+        return Ok(Some(value));
+    }
+    store::get(key).map_err(|error| match error {
+        store::Error::Upstream(message) | store::Error::Io(message) => GithubError::ConfigError(
+            format!("Failed to read {key} from wasi:config/store: {message}"),
+        ),
+    })
+}
+
+/// Returns `value`, or `None` when it is empty.
+#[cfg(not(feature = "wasi-config"))]
+fn config_fallback(value: String, _key: &str) -> Result<Option<String>, GithubError> {
+    Ok(Some(value).filter(|value| !value.is_empty()))
+}
+
 struct Component;
 
 impl Guest for Component {
+    type Client = GitHubClient;
+}
+
+/// Backs the `client` resource, reusing one configured HTTP client for every call.
+///
+/// A failure to read credentials from `wasi:config/store` can't be reported by the
+/// constructor, so it is kept and returned from every method instead.
+struct GitHubClient {
+    http: Result<Client, GithubError>,
+}
+
+impl GitHubClient {
+    fn build_http(config: Config) -> Result<Client, GithubError> {
+        let mut http = Client::new(config.base_url.as_deref().unwrap_or(GITHUB_API_BASE))
+            .header("Accept", "application/vnd.github.v3+json");
+        // Unauthenticated requests are allowed, with a lower rate limit
+        if let Some(token) = config_fallback(config.token, "github_token")? {
+            http = http.bearer_auth(&token);
+        }
+        if let Some(user_agent) = config.user_agent {
+            http = http.header("User-Agent", user_agent);
+        }
+        if let Some(timeout) = config.connect_timeout_ms {
+            http = http.connect_timeout(Duration::from_millis(timeout.into()));
+        }
+        let mut backoff = Backoff::default().rate_limit_delay(rate_limit_delay);
+        if let Some(retry) = config.retry {
+            backoff = backoff
+                .max_attempts(retry.max_attempts)
+                .initial_delay(Duration::from_millis(retry.initial_delay_ms.into()))
+                .max_delay(Duration::from_millis(retry.max_delay_ms.into()))
+                .statuses(retry.retry_statuses);
+        }
+        Ok(http.retry_policy(backoff))
+    }
+
+    fn http(&self) -> Result<&Client, GithubError> {
+        self.http.as_ref().map_err(Clone::clone)
+    }
+}
+
+impl GuestClient for GitHubClient {
+    fn new(config: Config) -> Self {
+        Self {
+            http: Self::build_http(config),
+        }
+    }
+
     fn create_issue(
-        token: String,
+        &self,
         owner: String,
         repo: String,
         title: String,
         body: String,
-    ) -> Issue {
-        let response = Client::new()
-            .post(&format!("{GITHUB_API_BASE}/repos/{owner}/{repo}/issues"))
-            .header("Authorization", format!("Bearer {token}"))
-            .header("Accept", "application/vnd.github.v3+json")
-            .header("Content-Type", "application/json")
+    ) -> Result<Issue, GithubError> {
+        let issue: GitHubIssue = self
+            .http()?
+            .post(&format!("/repos/{owner}/{repo}/issues"))
             .json(&serde_json::json!({
                 "title": title,
                 "body": body
             }))
-            .send()
-            .map_err(|e| format!("Failed to send request: {e}"))
-            .unwrap();
-
-        let body = response
-            .body()
-            .map_err(|e| format!("Failed to get response body: {e}"))
-            .unwrap();
-
-        let body_str = String::from_utf8(body)
-            .map_err(|e| format!("Failed to parse response as UTF-8: {e}"))
-            .unwrap();
-
-        let issue: GitHubIssue = serde_json::from_str(&body_str).unwrap();
-        Issue {
+            .send_json()?;
+        Ok(Issue {
             body: issue.body,
             number: issue.number,
             title: issue.title,
-        }
+        })
     }
 
-    fn create_repository(token: String, name: String, description: String) -> Repository {
-        let response = Client::new()
-            .post(&format!("{GITHUB_API_BASE}/user/repos"))
-            .header("Authorization", format!("Bearer {token}"))
-            .header("Accept", "application/vnd.github.v3+json")
-            .header("Content-Type", "application/json")
+    fn create_repository(
+        &self,
+        name: String,
+        description: String,
+    ) -> Result<Repository, GithubError> {
+        let repo: GitHubRepository = self
+            .http()?
+            .post("/user/repos")
             .json(&serde_json::json!({
                 "name": name,
                 "description": description,
                 "private": false
             }))
-            .send()
-            .map_err(|e| format!("Failed to send request: {e}"))
-            .unwrap();
-
-        let body = response
-            .body()
-            .map_err(|e| format!("Failed to get response body: {e}"))
-            .unwrap();
-
-        let body_str = String::from_utf8(body)
-            .map_err(|e| format!("Failed to parse response as UTF-8: {e}"))
-            .unwrap();
-
-        let repo: GitHubRepository = serde_json::from_str(&body_str).unwrap();
-        Repository {
+            .send_json()?;
+        Ok(Repository {
             description: repo.description,
             name: repo.name,
             owner: repo.owner.login,
-        }
+        })
     }
 
-    fn delete_repository(token: String, owner: String, repo: String) -> bool {
-        let response = Client::new()
-            .delete(&format!("{GITHUB_API_BASE}/repos/{owner}/{repo}"))
-            .header("Authorization", format!("Bearer {token}"))
-            .header("Accept", "application/vnd.github.v3+json")
-            .send()
-            .map_err(|e| format!("Failed to send request: {e}"))
-            .unwrap();
-
-        response.status_code() == 204
+    fn delete_repository(&self, owner: String, repo: String) -> Result<(), GithubError> {
+        self.http()?
+            .delete(&format!("/repos/{owner}/{repo}"))
+            .send()?;
+        Ok(())
     }
 
-    fn get_user(token: String) -> User {
-        let response = Client::new()
-            .get(&format!("{GITHUB_API_BASE}/user"))
-            .header("Authorization", format!("Bearer {token}"))
-            .header("Accept", "application/vnd.github.v3+json")
-            .send()
-            .map_err(|e| format!("Failed to send request: {e}"))
-            .unwrap();
-
-        let body = response
-            .body()
-            .map_err(|e| format!("Failed to get response body: {e}"))
-            .unwrap();
-
-        let body_str = String::from_utf8(body)
-            .map_err(|e| format!("Failed to parse response as UTF-8: {e}"))
-            .unwrap();
-
-        let user: GitHubUser = serde_json::from_str(&body_str).unwrap();
-        User {
+    fn get_user(&self) -> Result<User, GithubError> {
+        let user: GitHubUser = self.http()?.get("/user").send_json()?;
+        Ok(User {
             avatar_url: user.avatar_url,
             id: user.id,
             login: user.login,
-        }
+        })
     }
 
     fn update_issue(
-        token: String,
+        &self,
         owner: String,
         repo: String,
         number: u32,
         title: String,
         body: String,
-    ) -> Issue {
-        let response = Client::new()
-            .patch(&format!(
-                "{GITHUB_API_BASE}/repos/{owner}/{repo}/issues/{number}"
-            ))
-            .header("Authorization", format!("Bearer {token}"))
-            .header("Accept", "application/vnd.github.v3+json")
-            .header("Content-Type", "application/json")
+    ) -> Result<Issue, GithubError> {
+        let issue: GitHubIssue = self
+            .http()?
+            .patch(&format!("/repos/{owner}/{repo}/issues/{number}"))
             .json(&serde_json::json!({
                 "title": title,
                 "body": body
             }))
-            .send()
-            .map_err(|e| format!("Failed to send request: {e}"))
-            .unwrap();
-
-        let body = response
-            .body()
-            .map_err(|e| format!("Failed to get response body: {e}"))
-            .unwrap();
-
-        let body_str = String::from_utf8(body)
-            .map_err(|e| format!("Failed to parse response as UTF-8: {e}"))
-            .unwrap();
-
-        let issue: GitHubIssue = serde_json::from_str(&body_str).unwrap();
-        Issue {
+            .send_json()?;
+        Ok(Issue {
             body: issue.body,
             number: issue.number,
             title: issue.title,
-        }
+        })
+    }
+
+    fn last_rate_limit(&self) -> Option<RateLimitStatus> {
+        rate_limit_status(&self.http().ok()?.last_response_headers()?)
     }
 }
 
@@ -204,4 +337,121 @@ mod tests {
         assert_eq!(issue.number, 1);
         assert_eq!(issue.title, "title");
     }
+
+    #[test]
+    fn test_error_from_response_validation_failed() {
+        let body = r#"{
+            "message": "Validation Failed",
+            "errors": [{"resource": "Issue", "field": "title", "code": "missing_field"}],
+            "documentation_url": "https://docs.github.com/rest/issues/issues#create-an-issue"
+        }"#;
+
+        match error_from_response(&Response::new(422, HeaderMap::new(), body.into())) {
+            GithubError::ValidationFailed(failure) => {
+                assert_eq!(failure.message, "Validation Failed");
+                assert_eq!(failure.errors.len(), 1);
+                assert_eq!(failure.errors[0].resource.as_deref(), Some("Issue"));
+                assert_eq!(failure.errors[0].field.as_deref(), Some("title"));
+                assert_eq!(failure.errors[0].code, "missing_field");
+            }
+            error => panic!("Unexpected error: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn test_error_from_response_rate_limited() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-limit", "5000".parse().unwrap());
+        headers.insert("x-ratelimit-remaining", "0".parse().unwrap());
+        headers.insert("x-ratelimit-reset", "1700000000".parse().unwrap());
+        let body = r#"{"message": "API rate limit exceeded"}"#;
+
+        match error_from_response(&Response::new(403, headers, body.into())) {
+            GithubError::RateLimited(rate_limit) => {
+                assert_eq!(rate_limit.limit, Some(5000));
+                assert_eq!(rate_limit.remaining, Some(0));
+                assert_eq!(rate_limit.reset, Some(1700000000));
+                assert_eq!(rate_limit.retry_after, None);
+            }
+            error => panic!("Unexpected error: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_delay() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", "0".parse().unwrap());
+        headers.insert("x-ratelimit-reset", "1700000000".parse().unwrap());
+        let exhausted = Response::new(403, headers, Vec::new());
+        assert_eq!(rate_limit_delay(&exhausted), Some(Duration::ZERO));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", "60".parse().unwrap());
+        let secondary = Response::new(403, headers, Vec::new());
+        assert_eq!(rate_limit_delay(&secondary), Some(Duration::from_secs(60)));
+
+        let forbidden = Response::new(403, HeaderMap::new(), Vec::new());
+        assert_eq!(rate_limit_delay(&forbidden), None);
+    }
+
+    #[test]
+    fn test_rate_limit_status() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-limit", "5000".parse().unwrap());
+        headers.insert("x-ratelimit-remaining", "4999".parse().unwrap());
+        headers.insert("x-ratelimit-used", "1".parse().unwrap());
+        headers.insert("x-ratelimit-reset", "1700000000".parse().unwrap());
+        headers.insert("x-ratelimit-resource", "core".parse().unwrap());
+
+        let status = rate_limit_status(&headers).unwrap();
+        assert_eq!(status.limit, 5000);
+        assert_eq!(status.remaining, 4999);
+        assert_eq!(status.used, Some(1));
+        assert_eq!(status.reset, 1700000000);
+        assert_eq!(status.resource.as_deref(), Some("core"));
+
+        assert!(rate_limit_status(&HeaderMap::new()).is_none());
+    }
+
+    #[test]
+    fn test_error_from_response_http_error() {
+        let body =
+            r#"{"message": "Not Found", "documentation_url": "https://docs.github.com/rest"}"#;
+
+        match error_from_response(&Response::new(404, HeaderMap::new(), body.into())) {
+            GithubError::HttpError(error) => {
+                assert_eq!(error.status, 404);
+                assert_eq!(error.message, "Not Found");
+                assert_eq!(
+                    error.documentation_url.as_deref(),
+                    Some("https://docs.github.com/rest")
+                );
+            }
+            error => panic!("Unexpected error: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn test_client_base_url() {
+        let config = Config {
+            token: "token".to_string(),
+            base_url: None,
+            user_agent: None,
+            connect_timeout_ms: None,
+            retry: None,
+        };
+        assert_eq!(
+            GitHubClient::new(config.clone()).http().unwrap().base_url(),
+            GITHUB_API_BASE
+        );
+
+        let config = Config {
+            base_url: Some("https://github.example.com/api/v3".to_string()),
+            ..config
+        };
+        assert_eq!(
+            GitHubClient::new(config).http().unwrap().base_url(),
+            "https://github.example.com/api/v3"
+        );
+    }
 }
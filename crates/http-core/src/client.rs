@@ -0,0 +1,380 @@
+use std::{cell::RefCell, thread, time::Duration};
+
+use serde::{Serialize, de::DeserializeOwned};
+use waki::{
+    ErrorCode, Method,
+    header::{HeaderMap, HeaderName, HeaderValue},
+};
+
+use crate::{Error, EventStream, Form, NoRetry, Response, RetryPolicy, StatusClass};
+
+/// User agent sent with every request unless overridden.
+pub const DEFAULT_USER_AGENT: &str = concat!("wassemble-components/", env!("CARGO_PKG_VERSION"));
+
+/// An HTTP client bound to a single API base URL.
+///
+/// Default headers are sent with every request; headers set on a [`RequestBuilder`]
+/// take precedence over them.
+pub struct Client {
+    base_url: String,
+    connect_timeout: Option<Duration>,
+    headers: Vec<(String, String)>,
+    last_headers: RefCell<Option<HeaderMap>>,
+    retry_policy: Box<dyn RetryPolicy>,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            connect_timeout: None,
+            headers: vec![
+                ("User-Agent".to_string(), DEFAULT_USER_AGENT.to_string()),
+                ("Accept".to_string(), "application/json".to_string()),
+            ],
+            last_headers: RefCell::new(None),
+            retry_policy: Box::new(NoRetry),
+        }
+    }
+
+    /// Sets a default header, replacing any previous value.
+    pub fn header(mut self, name: &str, value: impl Into<String>) -> Self {
+        set_header(&mut self.headers, name, value.into());
+        self
+    }
+
+    /// Sets the `Authorization: Bearer` header.
+    pub fn bearer_auth(self, token: &str) -> Self {
+        self.header("Authorization", format!("Bearer {token}"))
+    }
+
+    /// Sets the timeout for establishing a connection.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: impl RetryPolicy + 'static) -> Self {
+        self.retry_policy = Box::new(retry_policy);
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Headers of the most recent response, successful or not.
+    ///
+    /// Components read rate-limit counters from these.
+    pub fn last_response_headers(&self) -> Option<HeaderMap> {
+        self.last_headers.borrow().clone()
+    }
+
+    pub fn get(&self, path: &str) -> RequestBuilder<'_> {
+        self.request(Method::Get, path)
+    }
+
+    pub fn post(&self, path: &str) -> RequestBuilder<'_> {
+        self.request(Method::Post, path)
+    }
+
+    pub fn put(&self, path: &str) -> RequestBuilder<'_> {
+        self.request(Method::Put, path)
+    }
+
+    pub fn patch(&self, path: &str) -> RequestBuilder<'_> {
+        self.request(Method::Patch, path)
+    }
+
+    pub fn delete(&self, path: &str) -> RequestBuilder<'_> {
+        self.request(Method::Delete, path)
+    }
+
+    /// Starts a request to `path`, relative to the base URL.
+    pub fn request(&self, method: Method, path: &str) -> RequestBuilder<'_> {
+        RequestBuilder {
+            body: None,
+            client: self,
+            error: None,
+            headers: Vec::new(),
+            method,
+            query: Vec::new(),
+            url: join_url(&self.base_url, path),
+        }
+    }
+}
+
+/// A request under construction.
+///
+/// The request is kept in its unsent form so that it can be sent again when the
+/// client's [`RetryPolicy`] asks for it.
+pub struct RequestBuilder<'a> {
+    body: Option<Vec<u8>>,
+    client: &'a Client,
+    // errors raised while building are deferred until the request is sent
+    error: Option<Error>,
+    headers: Vec<(String, String)>,
+    method: Method,
+    query: Vec<(String, String)>,
+    url: String,
+}
+
+impl RequestBuilder<'_> {
+    pub fn header(mut self, name: &str, value: impl Into<String>) -> Self {
+        set_header(&mut self.headers, name, value.into());
+        self
+    }
+
+    pub fn query(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.query.push((name.to_string(), value.into()));
+        self
+    }
+
+    /// Sets a raw body along with its content type.
+    pub fn body(self, body: Vec<u8>, content_type: &str) -> Self {
+        let mut builder = self.header("Content-Type", content_type);
+        builder.body = Some(body);
+        builder
+    }
+
+    /// Sets a JSON body.
+    pub fn json<T: Serialize + ?Sized>(mut self, value: &T) -> Self {
+        match serde_json::to_vec(value) {
+            Ok(body) => self.body(body, "application/json"),
+            Err(e) => {
+                self.error = Some(Error::Json(format!("Failed to serialize request: {e}")));
+                self
+            }
+        }
+    }
+
+    /// Sets a `multipart/form-data` body.
+    pub fn multipart(self, form: &Form) -> Self {
+        let (body, content_type) = form.encode();
+        self.body(body, &content_type)
+    }
+
+    /// Sends the request, retrying as the client's [`RetryPolicy`] dictates.
+    ///
+    /// Any status outside the 2xx range is returned as [`Error::Status`].
+    pub fn send(mut self) -> Result<Response, Error> {
+        self.validate()?;
+
+        let mut attempt = 1;
+        loop {
+            let outcome = self.open().and_then(|response| {
+                let status = response.status_code();
+                let headers = response.headers().clone();
+                let body = response.body().map_err(|e| {
+                    Error::ResponseBody(format!("Failed to get response body: {e}"))
+                })?;
+                Ok(Response::new(status, headers, body))
+            });
+            match self.retry_delay(attempt, &outcome) {
+                Some(delay) => {
+                    thread::sleep(delay);
+                    attempt += 1;
+                }
+                None => return outcome,
+            }
+        }
+    }
+
+    /// Sends the request and reads the response as server-sent events.
+    ///
+    /// Retries stop once a successful response has arrived: an error while the events
+    /// are being read is returned by [`EventStream::next_event`] instead.
+    pub fn send_event_stream(mut self) -> Result<EventStream, Error> {
+        self = self.header("Accept", "text/event-stream");
+        self.validate()?;
+
+        let mut attempt = 1;
+        loop {
+            let outcome = match self.open() {
+                Ok(response) => {
+                    let stream = EventStream::new(response);
+                    *self.client.last_headers.borrow_mut() = Some(stream.headers().clone());
+                    return Ok(stream);
+                }
+                Err(error) => Err(error),
+            };
+            match self.retry_delay(attempt, &outcome) {
+                Some(delay) => {
+                    thread::sleep(delay);
+                    attempt += 1;
+                }
+                None => return Err(outcome.unwrap_err()),
+            }
+        }
+    }
+
+    /// Sends the request and decodes the JSON response body.
+    pub fn send_json<T: DeserializeOwned>(self) -> Result<T, Error> {
+        self.send()?.json()
+    }
+
+    /// Reports errors in the request itself before the first attempt, since the retry
+    /// policy never gets to see them.
+    fn validate(&mut self) -> Result<(), Error> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+        self.header_map()?;
+        let mut request = waki::Client::new().request(self.method.clone(), &self.url);
+        if !self.query.is_empty() {
+            request = request.query(&self.query);
+        }
+        request
+            .build()
+            .map_err(|e| Error::Build(format!("Invalid URL {:?}: {e}", self.url)))?;
+        Ok(())
+    }
+
+    /// Records the headers of an attempt's response and asks the retry policy whether
+    /// to try again.
+    fn retry_delay(&self, attempt: u32, outcome: &Result<Response, Error>) -> Option<Duration> {
+        let response = match outcome {
+            Ok(response) => Some(response),
+            Err(Error::Status(response)) => Some(&**response),
+            Err(_) => None,
+        };
+        if let Some(response) = response {
+            *self.client.last_headers.borrow_mut() = Some(response.headers().clone());
+        }
+        self.client
+            .retry_policy
+            .retry_delay(attempt, &self.method, outcome)
+    }
+
+    /// Sends the request once, leaving the body of a successful response unread.
+    fn open(&self) -> Result<waki::Response, Error> {
+        let headers = self.header_map()?;
+        let mut request = waki::Client::new()
+            .request(self.method.clone(), &self.url)
+            .headers(headers.iter().map(|(k, v)| (k.clone(), v.clone())));
+        if !self.query.is_empty() {
+            request = request.query(&self.query);
+        }
+        if let Some(body) = &self.body {
+            request = request.body(body.clone());
+        }
+        if let Some(timeout) = self.client.connect_timeout {
+            request = request.connect_timeout(timeout);
+        }
+
+        let response = request
+            .send()
+            .map_err(|e| match e.downcast_ref::<ErrorCode>() {
+                Some(code) if never_sent(code) => Error::Connect(format!("Failed to connect: {e}")),
+                _ => Error::Request(format!("Failed to send request: {e}")),
+            })?;
+        if StatusClass::of(response.status_code()) == StatusClass::Success {
+            return Ok(response);
+        }
+
+        let status = response.status_code();
+        let headers = response.headers().clone();
+        let body = response
+            .body()
+            .map_err(|e| Error::ResponseBody(format!("Failed to get response body: {e}")))?;
+        Err(Error::Status(Box::new(Response::new(
+            status, headers, body,
+        ))))
+    }
+
+    fn header_map(&self) -> Result<HeaderMap, Error> {
+        let mut map = HeaderMap::new();
+        for (name, value) in self.client.headers.iter().chain(&self.headers) {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| Error::Build(format!("Invalid header name {name:?}: {e}")))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|e| Error::Build(format!("Invalid value for header {name}: {e}")))?;
+            map.insert(name, value);
+        }
+        Ok(map)
+    }
+}
+
+/// Whether a failure to send shows that the server never received the request, so
+/// that even a POST can be sent again.
+fn never_sent(code: &ErrorCode) -> bool {
+    matches!(
+        code,
+        ErrorCode::DnsTimeout
+            | ErrorCode::DnsError(_)
+            | ErrorCode::DestinationNotFound
+            | ErrorCode::DestinationUnavailable
+            | ErrorCode::DestinationIpProhibited
+            | ErrorCode::DestinationIpUnroutable
+            | ErrorCode::ConnectionRefused
+            | ErrorCode::ConnectionTimeout
+            | ErrorCode::ConnectionLimitReached
+            | ErrorCode::TlsProtocolError
+            | ErrorCode::TlsCertificateError
+            | ErrorCode::TlsAlertReceived(_)
+    )
+}
+
+fn set_header(headers: &mut Vec<(String, String)>, name: &str, value: String) {
+    headers.retain(|(existing, _)| !existing.eq_ignore_ascii_case(name));
+    headers.push((name.to_string(), value));
+}
+
+fn join_url(base_url: &str, path: &str) -> String {
+    match path {
+        "" => base_url.to_string(),
+        _ => format!(
+            "{}/{}",
+            base_url.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_join_url() {
+        assert_eq!(
+            join_url("https://api.github.com", "/user"),
+            "https://api.github.com/user"
+        );
+        assert_eq!(
+            join_url("http://localhost:8080/v1/", "models"),
+            "http://localhost:8080/v1/models"
+        );
+        assert_eq!(join_url("http://localhost", ""), "http://localhost");
+    }
+
+    #[test]
+    fn test_request_headers_override_defaults() {
+        let client = Client::new("https://example.com")
+            .bearer_auth("secret")
+            .header("accept", "application/vnd.github.v3+json");
+        let request = client.get("/user").header("Authorization", "Bot other");
+
+        let headers = request.header_map().unwrap();
+        assert_eq!(headers["user-agent"], DEFAULT_USER_AGENT);
+        assert_eq!(headers["accept"], "application/vnd.github.v3+json");
+        assert_eq!(headers["authorization"], "Bot other");
+    }
+
+    #[test]
+    fn test_invalid_header_is_reported() {
+        let client = Client::new("https://example.com").header("X-Token", "line\nbreak");
+        assert!(matches!(client.get("/").header_map(), Err(Error::Build(_))));
+    }
+
+    #[test]
+    fn test_invalid_url_is_reported() {
+        let client = Client::new("https://exa mple.com");
+        let mut request = client.get("/user");
+        assert!(matches!(request.validate(), Err(Error::Build(_))));
+
+        let client = Client::new("https://example.com");
+        let mut request = client.get("/user");
+        assert!(request.validate().is_ok());
+    }
+}
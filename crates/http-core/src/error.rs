@@ -0,0 +1,47 @@
+use std::fmt;
+
+use crate::Response;
+
+/// Errors that can occur while performing a request.
+///
+/// The cases mirror the stages of a request, so components can map them onto the
+/// matching cases of their WIT error variant.
+#[derive(Debug)]
+pub enum Error {
+    /// The request is invalid, such as a header value with a line break or a malformed
+    /// URL; sending it again cannot help
+    Build(String),
+    /// Failed to connect, so the request never reached the server
+    Connect(String),
+    /// Failed to send the HTTP request or to receive the response
+    Request(String),
+    /// Failed to get response body
+    ResponseBody(String),
+    /// Failed to parse response as UTF-8
+    Utf8(String),
+    /// Failed to serialize the request or parse the JSON response
+    Json(String),
+    /// The server answered with a non-success status
+    Status(Box<Response>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Build(message)
+            | Error::Connect(message)
+            | Error::Request(message)
+            | Error::ResponseBody(message)
+            | Error::Utf8(message)
+            | Error::Json(message) => f.write_str(message),
+            Error::Status(response) => write!(
+                f,
+                "Request failed with status {}: {}",
+                response.status(),
+                String::from_utf8_lossy(response.body())
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
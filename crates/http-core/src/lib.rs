@@ -0,0 +1,25 @@
+//! Shared HTTP plumbing for the service components.
+//!
+//! Every component talks to a JSON API over `wasi:http`: it sends a request with a
+//! handful of default headers and a JSON body or a multipart form, checks the status
+//! code and decodes the body, or reads it as server-sent events. This crate does that
+//! once, and leaves mapping [`Error`] onto the component's WIT error variant to the
+//! component itself.
+
+mod client;
+mod error;
+mod multipart;
+mod response;
+mod retry;
+mod sse;
+
+pub use waki::{Method, header::HeaderMap};
+
+pub use self::{
+    client::{Client, DEFAULT_USER_AGENT, RequestBuilder},
+    error::Error,
+    multipart::Form,
+    response::{Response, StatusClass},
+    retry::{Backoff, NoRetry, RetryPolicy, retry_after},
+    sse::{Event, EventStream},
+};
@@ -0,0 +1,139 @@
+/// A `multipart/form-data` body, for endpoints that take file uploads.
+///
+/// Fields are encoded in the order they were added, which some APIs rely on for
+/// repeated fields such as `image[]`.
+#[derive(Clone, Debug, Default)]
+pub struct Form {
+    parts: Vec<Part>,
+}
+
+#[derive(Clone, Debug)]
+struct Part {
+    content_type: Option<String>,
+    data: Vec<u8>,
+    filename: Option<String>,
+    name: String,
+}
+
+impl Form {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a plain text field.
+    pub fn text(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.parts.push(Part {
+            content_type: None,
+            data: value.into().into_bytes(),
+            filename: None,
+            name: name.to_string(),
+        });
+        self
+    }
+
+    /// Adds a file field with the file's name and content type.
+    pub fn file(mut self, name: &str, filename: &str, content_type: &str, data: Vec<u8>) -> Self {
+        self.parts.push(Part {
+            content_type: Some(content_type.to_string()),
+            data,
+            filename: Some(filename.to_string()),
+            name: name.to_string(),
+        });
+        self
+    }
+
+    /// Encodes the form, returning the body and its `Content-Type` header value.
+    pub fn encode(&self) -> (Vec<u8>, String) {
+        let boundary = self.boundary();
+        let mut body = Vec::new();
+        for part in &self.parts {
+            body.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
+            let mut disposition = format!("form-data; name=\"{}\"", escape(&part.name));
+            if let Some(filename) = &part.filename {
+                disposition.push_str(&format!("; filename=\"{}\"", escape(filename)));
+            }
+            body.extend_from_slice(format!("Content-Disposition: {disposition}\r\n").as_bytes());
+            if let Some(content_type) = &part.content_type {
+                body.extend_from_slice(format!("Content-Type: {content_type}\r\n").as_bytes());
+            }
+            body.extend_from_slice(b"\r\n");
+            body.extend_from_slice(&part.data);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+        (body, format!("multipart/form-data; boundary={boundary}"))
+    }
+
+    /// Picks a boundary that appears in none of the parts.
+    ///
+    /// The boundary is derived from the content rather than random, so a form always
+    /// encodes the same way.
+    fn boundary(&self) -> String {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for part in &self.parts {
+            for &byte in part.name.as_bytes().iter().chain(&part.data) {
+                hash = (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+        loop {
+            let boundary = format!("wassemble-{hash:016x}");
+            if !self
+                .parts
+                .iter()
+                .any(|part| contains(&part.data, boundary.as_bytes()))
+            {
+                return boundary;
+            }
+            hash = hash.wrapping_add(1);
+        }
+    }
+}
+
+/// Escapes a field or file name for a quoted header parameter, as browsers do.
+fn escape(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_form() {
+        let form = Form::new().text("model", "gpt-image-1").file(
+            "image[]",
+            "cat \"1\".png",
+            "image/png",
+            vec![0x89, b'P', b'N', b'G'],
+        );
+        let (body, content_type) = form.encode();
+        let boundary = content_type
+            .strip_prefix("multipart/form-data; boundary=")
+            .unwrap();
+
+        let mut expected = format!(
+            "--{boundary}\r\n\
+             Content-Disposition: form-data; name=\"model\"\r\n\
+             \r\n\
+             gpt-image-1\r\n\
+             --{boundary}\r\n\
+             Content-Disposition: form-data; name=\"image[]\"; filename=\"cat %221%22.png\"\r\n\
+             Content-Type: image/png\r\n\
+             \r\n"
+        )
+        .into_bytes();
+        expected.extend_from_slice(&[0x89, b'P', b'N', b'G']);
+        expected.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+        assert_eq!(body, expected);
+        assert_eq!(form.encode().1, content_type);
+    }
+}
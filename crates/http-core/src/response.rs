@@ -0,0 +1,133 @@
+use std::str::FromStr;
+
+use serde::de::DeserializeOwned;
+use waki::header::HeaderMap;
+
+use crate::Error;
+
+/// Coarse classification of an HTTP status code.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StatusClass {
+    /// 1xx
+    Informational,
+    /// 2xx
+    Success,
+    /// 3xx
+    Redirection,
+    /// 4xx
+    ClientError,
+    /// 5xx, and anything outside the registered ranges
+    ServerError,
+}
+
+impl StatusClass {
+    pub fn of(status: u16) -> Self {
+        match status {
+            100..=199 => StatusClass::Informational,
+            200..=299 => StatusClass::Success,
+            300..=399 => StatusClass::Redirection,
+            400..=499 => StatusClass::ClientError,
+            _ => StatusClass::ServerError,
+        }
+    }
+}
+
+/// A fully buffered HTTP response.
+#[derive(Debug)]
+pub struct Response {
+    body: Vec<u8>,
+    headers: HeaderMap,
+    status: u16,
+}
+
+impl Response {
+    pub fn new(status: u16, headers: HeaderMap, body: Vec<u8>) -> Self {
+        Self {
+            body,
+            headers,
+            status,
+        }
+    }
+
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    pub fn status_class(&self) -> StatusClass {
+        StatusClass::of(self.status)
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Returns the header as a string, if present and valid.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name)?.to_str().ok()
+    }
+
+    /// Returns the header parsed as `T`, if present and valid.
+    pub fn header_value<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.header(name)?.trim().parse().ok()
+    }
+
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    pub fn into_body(self) -> Vec<u8> {
+        self.body
+    }
+
+    pub fn text(&self) -> Result<&str, Error> {
+        std::str::from_utf8(&self.body)
+            .map_err(|e| Error::Utf8(format!("Failed to parse response as UTF-8: {e}")))
+    }
+
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        serde_json::from_str(self.text()?)
+            .map_err(|e| Error::Json(format!("Failed to parse JSON response: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_class() {
+        assert_eq!(StatusClass::of(101), StatusClass::Informational);
+        assert_eq!(StatusClass::of(204), StatusClass::Success);
+        assert_eq!(StatusClass::of(304), StatusClass::Redirection);
+        assert_eq!(StatusClass::of(429), StatusClass::ClientError);
+        assert_eq!(StatusClass::of(503), StatusClass::ServerError);
+    }
+
+    #[test]
+    fn test_response_accessors() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", "42".parse().unwrap());
+        let response = Response::new(200, headers, br#"{"id": 7}"#.to_vec());
+
+        assert_eq!(response.status_class(), StatusClass::Success);
+        assert_eq!(response.header("X-RateLimit-Remaining"), Some("42"));
+        assert_eq!(
+            response.header_value::<u32>("x-ratelimit-remaining"),
+            Some(42)
+        );
+        assert_eq!(response.header_value::<u32>("retry-after"), None);
+
+        let value: serde_json::Value = response.json().unwrap();
+        assert_eq!(value["id"], 7);
+    }
+
+    #[test]
+    fn test_response_invalid_utf8() {
+        let response = Response::new(200, HeaderMap::new(), vec![0xff, 0xfe]);
+        assert!(matches!(response.text(), Err(Error::Utf8(_))));
+        assert!(matches!(
+            response.json::<serde_json::Value>(),
+            Err(Error::Utf8(_))
+        ));
+    }
+}
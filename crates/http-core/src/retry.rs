@@ -0,0 +1,349 @@
+use std::time::Duration;
+
+use waki::Method;
+
+use crate::{Error, Response};
+
+/// Decides whether a request should be sent again.
+///
+/// The client calls the policy after every attempt, including successful ones, so a
+/// policy can also react to headers such as rate-limit counters.
+pub trait RetryPolicy {
+    /// Returns how long to wait before the next attempt, or `None` to return `outcome`.
+    ///
+    /// `attempt` starts at 1 for the initial request, and `method` is the request's.
+    fn retry_delay(
+        &self,
+        attempt: u32,
+        method: &Method,
+        outcome: &Result<Response, Error>,
+    ) -> Option<Duration>;
+}
+
+/// Never retries.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoRetry;
+
+impl RetryPolicy for NoRetry {
+    fn retry_delay(
+        &self,
+        _attempt: u32,
+        _method: &Method,
+        _outcome: &Result<Response, Error>,
+    ) -> Option<Duration> {
+        None
+    }
+}
+
+/// Retries failed sends and retryable statuses with exponential backoff.
+///
+/// POST and PATCH are not idempotent, so by default they are only retried when the
+/// server cannot have applied them: after a failed connection, or on a 429. Requests
+/// that could not be built ([`Error::Build`]) are never retried.
+///
+/// When a 429 or 403 says how long to wait, through `Retry-After` or the hook set with
+/// [`Backoff::rate_limit_delay`], that delay is used instead of the backoff. A server
+/// asking to wait longer than the maximum delay is not retried, so the caller gets
+/// the error and can schedule the work itself.
+#[derive(Clone, Debug)]
+pub struct Backoff {
+    initial_delay: Duration,
+    max_attempts: u32,
+    max_delay: Duration,
+    rate_limit_delay: fn(&Response) -> Option<Duration>,
+    retry_non_idempotent: bool,
+    statuses: Vec<u16>,
+}
+
+impl Default for Backoff {
+    /// Three attempts, starting at one second, for 429 and the gateway errors.
+    ///
+    /// A plain 500 is not retried by default, since the request may have been applied.
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            max_attempts: 3,
+            max_delay: Duration::from_secs(60),
+            rate_limit_delay: retry_after,
+            retry_non_idempotent: false,
+            statuses: vec![429, 502, 503, 504],
+        }
+    }
+}
+
+impl Backoff {
+    /// Total number of attempts, including the first one.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Delay before the first retry; it doubles for every further retry.
+    pub fn initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// Upper bound for a single delay.
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Statuses that are retried even without a rate-limit hint.
+    pub fn statuses(mut self, statuses: impl Into<Vec<u16>>) -> Self {
+        self.statuses = statuses.into();
+        self
+    }
+
+    /// Sets how to read the delay a rate-limited response asks for.
+    ///
+    /// Defaults to [`retry_after`]. Only 429 and 403 responses are passed to the hook;
+    /// one it returns a delay for is retried even if its status is not listed.
+    pub fn rate_limit_delay(mut self, hook: fn(&Response) -> Option<Duration>) -> Self {
+        self.rate_limit_delay = hook;
+        self
+    }
+
+    /// Also retries POST and PATCH after failed sends and on every listed status.
+    ///
+    /// Only enable this for APIs that deduplicate requests, such as through an
+    /// idempotency key.
+    pub fn retry_non_idempotent(mut self, retry: bool) -> Self {
+        self.retry_non_idempotent = retry;
+        self
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+}
+
+impl RetryPolicy for Backoff {
+    fn retry_delay(
+        &self,
+        attempt: u32,
+        method: &Method,
+        outcome: &Result<Response, Error>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        let idempotent =
+            self.retry_non_idempotent || !matches!(method, Method::Post | Method::Patch);
+        let response = match outcome {
+            Err(Error::Connect(_)) => return Some(self.backoff(attempt)),
+            Err(Error::Request(_)) => return idempotent.then(|| self.backoff(attempt)),
+            Err(Error::Status(response)) => response,
+            _ => return None,
+        };
+        let status = response.status();
+        let hinted = match status {
+            403 | 429 => (self.rate_limit_delay)(response),
+            _ => None,
+        };
+        match hinted {
+            Some(delay) => (delay <= self.max_delay).then_some(delay),
+            None if self.statuses.contains(&status) && (idempotent || status == 429) => {
+                Some(self.backoff(attempt))
+            }
+            None => None,
+        }
+    }
+}
+
+/// Reads the `Retry-After` header, in seconds, or the `retry-after-ms` header some APIs
+/// send alongside it.
+///
+/// The HTTP-date form of `Retry-After` is not supported.
+pub fn retry_after(response: &Response) -> Option<Duration> {
+    if let Some(millis) = response.header_value::<f64>("retry-after-ms") {
+        return Duration::try_from_secs_f64(millis / 1000.0).ok();
+    }
+    Duration::try_from_secs_f64(response.header_value("retry-after")?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use waki::header::HeaderMap;
+
+    use super::*;
+
+    fn status(status: u16, headers: &[(&'static str, &str)]) -> Result<Response, Error> {
+        let mut map = HeaderMap::new();
+        for (name, value) in headers {
+            map.insert(*name, value.parse().unwrap());
+        }
+        Err(Error::Status(Box::new(Response::new(
+            status,
+            map,
+            Vec::new(),
+        ))))
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_max_delay() {
+        let policy = Backoff::default()
+            .max_attempts(10)
+            .initial_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(500));
+        let outcome = status(503, &[]);
+
+        assert_eq!(
+            policy.retry_delay(1, &Method::Get, &outcome),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            policy.retry_delay(2, &Method::Get, &outcome),
+            Some(Duration::from_millis(200))
+        );
+        assert_eq!(
+            policy.retry_delay(4, &Method::Get, &outcome),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(policy.retry_delay(10, &Method::Get, &outcome), None);
+    }
+
+    #[test]
+    fn test_backoff_only_retries_configured_statuses() {
+        let policy = Backoff::default();
+        assert!(
+            policy
+                .retry_delay(1, &Method::Get, &status(429, &[]))
+                .is_some()
+        );
+        assert!(
+            policy
+                .retry_delay(1, &Method::Get, &status(500, &[]))
+                .is_none()
+        );
+        assert!(
+            policy
+                .retry_delay(1, &Method::Get, &status(404, &[]))
+                .is_none()
+        );
+        assert!(
+            policy
+                .retry_delay(
+                    1,
+                    &Method::Get,
+                    &Err(Error::Request("connection refused".into()))
+                )
+                .is_some()
+        );
+        assert!(
+            policy
+                .retry_delay(1, &Method::Get, &Err(Error::Json("bad body".into())))
+                .is_none()
+        );
+
+        let policy = policy.statuses([500]);
+        assert!(
+            policy
+                .retry_delay(1, &Method::Get, &status(500, &[]))
+                .is_some()
+        );
+        assert!(
+            policy
+                .retry_delay(1, &Method::Get, &status(503, &[]))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_backoff_honors_retry_after() {
+        let policy = Backoff::default().max_delay(Duration::from_secs(10));
+
+        assert_eq!(
+            policy.retry_delay(1, &Method::Get, &status(429, &[("retry-after", "7")])),
+            Some(Duration::from_secs(7))
+        );
+        assert_eq!(
+            policy.retry_delay(1, &Method::Get, &status(429, &[("retry-after-ms", "250")])),
+            Some(Duration::from_millis(250))
+        );
+        // waiting longer than allowed is left to the caller
+        assert_eq!(
+            policy.retry_delay(1, &Method::Get, &status(429, &[("retry-after", "3600")])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_backoff_rate_limit_hook() {
+        let policy = Backoff::default().rate_limit_delay(|response| {
+            (response.header("x-ratelimit-remaining") == Some("0")).then_some(Duration::ZERO)
+        });
+
+        assert_eq!(
+            policy.retry_delay(
+                1,
+                &Method::Get,
+                &status(403, &[("x-ratelimit-remaining", "0")])
+            ),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(policy.retry_delay(1, &Method::Get, &status(403, &[])), None);
+    }
+
+    #[test]
+    fn test_backoff_hook_only_sees_rate_limits() {
+        let policy = Backoff::default().statuses([]);
+
+        assert_eq!(
+            policy.retry_delay(1, &Method::Get, &status(403, &[("retry-after", "2")])),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            policy.retry_delay(1, &Method::Get, &status(503, &[("retry-after", "2")])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_backoff_never_retries_build_errors() {
+        let policy = Backoff::default().retry_non_idempotent(true);
+        assert!(
+            policy
+                .retry_delay(1, &Method::Get, &Err(Error::Build("bad header".into())))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_backoff_retries_post_only_when_not_applied() {
+        let policy = Backoff::default();
+        let refused = Err(Error::Connect("connection refused".into()));
+        let reset = Err(Error::Request("connection reset".into()));
+
+        assert!(policy.retry_delay(1, &Method::Post, &refused).is_some());
+        assert!(
+            policy
+                .retry_delay(1, &Method::Post, &status(429, &[]))
+                .is_some()
+        );
+        assert!(policy.retry_delay(1, &Method::Post, &reset).is_none());
+        assert!(
+            policy
+                .retry_delay(1, &Method::Patch, &status(503, &[]))
+                .is_none()
+        );
+        assert!(
+            policy
+                .retry_delay(1, &Method::Put, &status(503, &[]))
+                .is_some()
+        );
+        assert!(policy.retry_delay(1, &Method::Delete, &reset).is_some());
+
+        let policy = policy.retry_non_idempotent(true);
+        assert!(policy.retry_delay(1, &Method::Post, &reset).is_some());
+        assert!(
+            policy
+                .retry_delay(1, &Method::Post, &status(503, &[]))
+                .is_some()
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use waki::header::HeaderMap;
+
+use crate::Error;
+
+/// Size of the reads from the response body; an event is returned as soon as its
+/// terminating blank line has arrived, whatever the read size.
+const READ_SIZE: u64 = 16 * 1024;
+
+/// A single server-sent event.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Event {
+    /// Lines of the `data` field, joined with `\n`
+    pub data: String,
+    /// Value of the `event` field, if the server named the event
+    pub event: Option<String>,
+    /// Value of the `id` field
+    pub id: Option<String>,
+}
+
+/// Reads server-sent events from a `text/event-stream` response as they arrive.
+pub struct EventStream {
+    closed: bool,
+    headers: HeaderMap,
+    parser: EventParser,
+    response: waki::Response,
+}
+
+impl EventStream {
+    pub(crate) fn new(response: waki::Response) -> Self {
+        Self {
+            closed: false,
+            headers: response.headers().clone(),
+            parser: EventParser::default(),
+            response,
+        }
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Waits for the next event, returning `None` once the server closed the stream.
+    pub fn next_event(&mut self) -> Result<Option<Event>, Error> {
+        loop {
+            if let Some(event) = self.parser.next_event()? {
+                return Ok(Some(event));
+            }
+            if self.closed {
+                return self.parser.finish();
+            }
+            match self.response.chunk(READ_SIZE) {
+                Ok(Some(chunk)) => self.parser.push(&chunk),
+                Ok(None) => self.closed = true,
+                Err(e) => {
+                    return Err(Error::ResponseBody(format!(
+                        "Failed to read event stream: {e}"
+                    )));
+                }
+            }
+        }
+    }
+}
+
+/// Incremental parser for the `text/event-stream` format.
+///
+/// Lines may end with `\n` or `\r\n`; comments and unknown fields are skipped.
+#[derive(Default)]
+struct EventParser {
+    buffer: Vec<u8>,
+    data: Option<String>,
+    event: Option<String>,
+    id: Option<String>,
+}
+
+impl EventParser {
+    fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Returns the next complete event in the buffer.
+    fn next_event(&mut self) -> Result<Option<Event>, Error> {
+        while let Some(end) = self.buffer.iter().position(|&b| b == b'\n') {
+            let mut line: Vec<u8> = self.buffer.drain(..=end).collect();
+            line.pop();
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+            let line = String::from_utf8(line)
+                .map_err(|e| Error::Utf8(format!("Invalid UTF-8 in event stream: {e}")))?;
+            if let Some(event) = self.process_line(&line) {
+                return Ok(Some(event));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Dispatches an event left unterminated when the stream closed.
+    fn finish(&mut self) -> Result<Option<Event>, Error> {
+        if !self.buffer.is_empty() {
+            self.push(b"\n");
+        }
+        if let Some(event) = self.next_event()? {
+            return Ok(Some(event));
+        }
+        Ok(self.process_line(""))
+    }
+
+    fn process_line(&mut self, line: &str) -> Option<Event> {
+        if line.is_empty() {
+            let event = self.event.take();
+            return self.data.take().map(|data| Event {
+                data,
+                event,
+                id: self.id.clone(),
+            });
+        }
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match field {
+            "data" => match &mut self.data {
+                Some(data) => {
+                    data.push('\n');
+                    data.push_str(value);
+                }
+                None => self.data = Some(value.to_string()),
+            },
+            "event" => self.event = Some(value.to_string()),
+            "id" => self.id = Some(value.to_string()),
+            // comments start with a colon and leave an empty field name
+            _ => {}
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_all(input: &[&[u8]]) -> Vec<Event> {
+        let mut parser = EventParser::default();
+        let mut events = Vec::new();
+        for chunk in input {
+            parser.push(chunk);
+            while let Some(event) = parser.next_event().unwrap() {
+                events.push(event);
+            }
+        }
+        events.extend(parser.finish().unwrap());
+        events
+    }
+
+    fn data(data: &str) -> Event {
+        Event {
+            data: data.to_string(),
+            ..Event::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_events_split_across_chunks() {
+        let events = parse_all(&[
+            b"data: {\"a\":",
+            b"1}\n\ndata: {\"b\":2}\r\n",
+            b"\r\n: keep-alive\n\ndata: [DONE]\n\n",
+        ]);
+        assert_eq!(
+            events,
+            vec![data("{\"a\":1}"), data("{\"b\":2}"), data("[DONE]")]
+        );
+    }
+
+    #[test]
+    fn test_parse_event_fields() {
+        let events = parse_all(&[
+            b"event: response.created\nid: 1\ndata: first\ndata:second\n\nevent: ping\n\n",
+        ]);
+        assert_eq!(
+            events,
+            vec![Event {
+                data: "first\nsecond".to_string(),
+                event: Some("response.created".to_string()),
+                id: Some("1".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_unterminated_event() {
+        assert_eq!(parse_all(&[b"data: last"]), vec![data("last")]);
+        assert_eq!(parse_all(&[b"data: last\n"]), vec![data("last")]);
+        assert!(parse_all(&[b""]).is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    env, fs,
+    path::PathBuf,
+    sync::{LazyLock, Mutex, PoisonError},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use http_body_util::{BodyExt, Full};
+use wasmtime::{
+    Engine, Store,
+    component::{Component, HasSelf, Linker, ResourceTable},
+};
+use wasmtime_wasi::{WasiCtx, WasiCtxView, WasiView};
+use wasmtime_wasi_http::{
+    HttpResult, WasiHttpCtx, WasiHttpView,
+    body::HyperOutgoingBody,
+    types::{HostFutureIncomingResponse, IncomingResponse, OutgoingRequestConfig},
+};
+
+use crate::{MockServer, RecordedRequest, bindings::github::wasi::config::store};
+
+/// Magic number and layer of a component binary; core modules use layer 0.
+const COMPONENT_HEADER: &[u8] = b"\0asm\x0d\0\x01\0";
+
+static ENGINE: LazyLock<Engine> = LazyLock::new(Engine::default);
+
+// Compiling a component takes a while, so each one is compiled once per test binary
+static COMPONENTS: LazyLock<Mutex<HashMap<String, Component>>> = LazyLock::new(Default::default);
+
+/// A built component, ready to be instantiated against a [`MockServer`].
+pub struct Harness {
+    component: Component,
+    linker: Linker<Host>,
+}
+
+impl Harness {
+    /// Loads `target/wasm32-wasip1/{debug,release}/<name>.wasm`.
+    ///
+    /// Panics with the command to build the component when it has not been built, so
+    /// that a missing build fails the tests instead of skipping them.
+    pub fn load(name: &str) -> Self {
+        let mut components = COMPONENTS.lock().unwrap_or_else(PoisonError::into_inner);
+        let component = match components.entry(name.to_string()) {
+            Entry::Occupied(entry) => entry.get().clone(),
+            Entry::Vacant(entry) => entry.insert(compile(name)).clone(),
+        };
+        drop(components);
+
+        let mut linker = Linker::new(&ENGINE);
+        wasmtime_wasi::p2::add_to_linker_sync(&mut linker).unwrap();
+        wasmtime_wasi_http::add_only_http_to_linker_sync(&mut linker).unwrap();
+        store::add_to_linker::<_, HasSelf<_>>(&mut linker, |host| host).unwrap();
+
+        Self { component, linker }
+    }
+
+    pub fn component(&self) -> &Component {
+        &self.component
+    }
+
+    pub fn linker(&self) -> &Linker<Host> {
+        &self.linker
+    }
+
+    /// Creates a store whose outgoing requests are answered by `mock` and whose
+    /// `wasi:config/store` serves `config`.
+    pub fn store(&self, mock: &MockServer, config: &[(&str, &str)]) -> Store<Host> {
+        let host = Host {
+            config: config
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            http: WasiHttpCtx::new(),
+            mock: mock.clone(),
+            table: ResourceTable::new(),
+            wasi: WasiCtx::builder().inherit_stderr().build(),
+        };
+        Store::new(&ENGINE, host)
+    }
+}
+
+fn compile(name: &str) -> Component {
+    let target_dir = env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../target"));
+    let file = format!("{}.wasm", name.replace('-', "_"));
+    let Some(path) = ["debug", "release"]
+        .iter()
+        .map(|profile| target_dir.join("wasm32-wasip1").join(profile).join(&file))
+        .find(|path| path.exists())
+    else {
+        panic!(
+            "{file} not found under {}: build it with `cargo component build -p {name} --features wasi-config`",
+            target_dir.join("wasm32-wasip1").display()
+        );
+    };
+
+    let bytes =
+        fs::read(&path).unwrap_or_else(|e| panic!("Failed to read {}: {e}", path.display()));
+    // A plain `cargo build --target wasm32-wasip1` leaves a core module behind
+    assert!(
+        bytes.starts_with(COMPONENT_HEADER),
+        "{} is not a component: build it with `cargo component build -p {name} --features wasi-config`",
+        path.display()
+    );
+    Component::new(&ENGINE, &bytes)
+        .unwrap_or_else(|e| panic!("Failed to load {}: {e:?}", path.display()))
+}
+
+/// Store data for a component under test.
+pub struct Host {
+    config: HashMap<String, String>,
+    http: WasiHttpCtx,
+    mock: MockServer,
+    table: ResourceTable,
+    wasi: WasiCtx,
+}
+
+impl WasiView for Host {
+    fn ctx(&mut self) -> WasiCtxView<'_> {
+        WasiCtxView {
+            ctx: &mut self.wasi,
+            table: &mut self.table,
+        }
+    }
+}
+
+impl WasiHttpView for Host {
+    fn ctx(&mut self) -> &mut WasiHttpCtx {
+        &mut self.http
+    }
+
+    fn table(&mut self) -> &mut ResourceTable {
+        &mut self.table
+    }
+
+    fn send_request(
+        &mut self,
+        request: hyper::Request<HyperOutgoingBody>,
+        _config: OutgoingRequestConfig,
+    ) -> HttpResult<HostFutureIncomingResponse> {
+        let mock = self.mock.clone();
+        // The guest writes the body after handing the request over, so it has to be
+        // collected asynchronously
+        let handle = wasmtime_wasi::runtime::spawn(async move {
+            let (parts, body) = request.into_parts();
+            let body = match body.collect().await {
+                Ok(body) => body.to_bytes(),
+                Err(code) => return Ok(Err(code)),
+            };
+            let response = mock.respond(
+                parts.uri.path(),
+                RecordedRequest {
+                    body: body.to_vec(),
+                    headers: parts.headers,
+                    method: parts.method.to_string(),
+                    uri: parts.uri.to_string(),
+                },
+            )?;
+
+            let mut builder = hyper::Response::builder().status(response.status);
+            for (name, value) in &response.headers {
+                builder = builder.header(name, value);
+            }
+            let resp = builder.body(
+                Full::new(Bytes::from(response.body))
+                    .map_err(|never| match never {})
+                    .boxed_unsync(),
+            )?;
+            Ok(Ok(IncomingResponse {
+                resp,
+                worker: None,
+                between_bytes_timeout: Duration::from_secs(5),
+            }))
+        });
+        Ok(HostFutureIncomingResponse::pending(handle))
+    }
+}
+
+impl store::Host for Host {
+    fn get(&mut self, key: String) -> Result<Option<String>, store::Error> {
+        Ok(self.config.get(&key).cloned())
+    }
+
+    fn get_all(&mut self) -> Result<Vec<(String, String)>, store::Error> {
+        Ok(self
+            .config
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect())
+    }
+}
@@ -0,0 +1,48 @@
+//! Offline integration tests for the service components.
+//!
+//! The tests load the components built under `target/wasm32-wasip1` into wasmtime and
+//! answer their `wasi:http` requests from recorded fixtures instead of the network, so
+//! every export can be exercised without credentials. Build the components first:
+//!
+//! ```text
+//! cargo component build -p discord -p github -p openai --features wasi-config
+//! ```
+//!
+//! Tests for a component that has not been built fail with this command.
+
+mod harness;
+mod mock;
+
+pub mod bindings {
+    pub mod github {
+        wasmtime::component::bindgen!({
+            path: ["../../wit/deps/wasi-config-0.2.0-draft", "../github/wit"],
+            world: "wassemble:github/github-with-config",
+        });
+    }
+
+    pub mod discord {
+        wasmtime::component::bindgen!({
+            path: ["../../wit/deps/wasi-config-0.2.0-draft", "../discord/wit"],
+            world: "wassemble:discord/discord-with-config",
+            with: {
+                "wasi:config/store": super::github::wasi::config::store,
+            },
+        });
+    }
+
+    pub mod openai {
+        wasmtime::component::bindgen!({
+            path: ["../../wit/deps/wasi-config-0.2.0-draft", "../openai/wit"],
+            world: "wassemble:openai/openai-with-config",
+            with: {
+                "wasi:config/store": super::github::wasi::config::store,
+            },
+        });
+    }
+}
+
+pub use self::{
+    harness::{Harness, Host},
+    mock::{MockResponse, MockServer, RecordedRequest},
+};
@@ -0,0 +1,145 @@
+use std::{
+    fs,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use hyper::HeaderMap;
+
+/// A canned response, usually loaded from a file under `fixtures/`.
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    pub body: Vec<u8>,
+    pub headers: Vec<(String, String)>,
+    pub status: u16,
+}
+
+impl MockResponse {
+    pub fn new(status: u16) -> Self {
+        Self {
+            body: Vec::new(),
+            headers: Vec::new(),
+            status,
+        }
+    }
+
+    /// A JSON response whose body is read from `fixtures/<name>`.
+    pub fn fixture(status: u16, name: &str) -> Self {
+        Self::new(status)
+            .header("Content-Type", "application/json")
+            .body(read_fixture(name))
+    }
+
+    /// A successful `text/event-stream` response whose events are read from
+    /// `fixtures/<name>`.
+    pub fn event_stream(name: &str) -> Self {
+        Self::new(200)
+            .header("Content-Type", "text/event-stream")
+            .body(read_fixture(name))
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+}
+
+fn read_fixture(name: &str) -> Vec<u8> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .join(name);
+    fs::read(&path).unwrap_or_else(|e| panic!("Failed to read fixture {}: {e}", path.display()))
+}
+
+/// A request sent by a component, as seen by the mock server.
+#[derive(Clone, Debug)]
+pub struct RecordedRequest {
+    pub body: Vec<u8>,
+    pub headers: HeaderMap,
+    pub method: String,
+    /// Full request URI, including the query
+    pub uri: String,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name)?.to_str().ok()
+    }
+
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_slice(&self.body).expect("request body is not JSON")
+    }
+}
+
+/// Answers requests from a list of expected routes.
+///
+/// Every route answers exactly one request, and routes for the same method and path
+/// answer in the order they were added. A request that matches no remaining route
+/// traps the component, which fails the test with the request in the message.
+#[derive(Clone, Default)]
+pub struct MockServer {
+    inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Default)]
+struct Inner {
+    requests: Vec<RecordedRequest>,
+    routes: Vec<Route>,
+}
+
+struct Route {
+    method: String,
+    path: String,
+    response: MockResponse,
+}
+
+impl MockServer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expects one `method` request to `path`, matched without the query string.
+    pub fn on(&self, method: &str, path: &str, response: MockResponse) -> &Self {
+        self.inner.lock().unwrap().routes.push(Route {
+            method: method.to_string(),
+            path: path.to_string(),
+            response,
+        });
+        self
+    }
+
+    /// Every request received so far, in order.
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.inner.lock().unwrap().requests.clone()
+    }
+
+    /// The only request received so far.
+    pub fn single_request(&self) -> RecordedRequest {
+        let requests = self.requests();
+        assert_eq!(requests.len(), 1, "expected exactly one request");
+        requests.into_iter().next().unwrap()
+    }
+
+    pub(crate) fn respond(
+        &self,
+        path: &str,
+        request: RecordedRequest,
+    ) -> anyhow::Result<MockResponse> {
+        let mut inner = self.inner.lock().unwrap();
+        let position = inner
+            .routes
+            .iter()
+            .position(|route| route.method == request.method && route.path == path);
+        let summary = format!("{} {}", request.method, request.uri);
+        inner.requests.push(request);
+        match position {
+            Some(position) => Ok(inner.routes.remove(position).response),
+            None => anyhow::bail!("No mock route for {summary}"),
+        }
+    }
+}
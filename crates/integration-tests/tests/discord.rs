@@ -0,0 +1,553 @@
+use integration_tests::{
+    Harness, Host, MockResponse, MockServer,
+    bindings::discord::{
+        DiscordWithConfig,
+        wassemble::discord::types::{Config, DiscordError, Message, RetryConfig, Webhook},
+    },
+};
+use wasmtime::{Store, component::ResourceAny};
+
+const CHANNEL_ID: &str = "290926798999357250";
+const MESSAGE_ID: &str = "334385199974967042";
+
+struct Bot {
+    client: ResourceAny,
+    discord: DiscordWithConfig,
+    store: Store<Host>,
+}
+
+fn connect(mock: &MockServer, token: &str, runtime_config: &[(&str, &str)]) -> Bot {
+    let harness = Harness::load("discord");
+    let mut store = harness.store(mock, runtime_config);
+    let discord =
+        DiscordWithConfig::instantiate(&mut store, harness.component(), harness.linker()).unwrap();
+    let config = Config {
+        token: token.to_string(),
+        base_url: None,
+        user_agent: None,
+        connect_timeout_ms: None,
+        retry: Some(RetryConfig {
+            max_attempts: 3,
+            initial_delay_ms: 0,
+            max_delay_ms: 1_000,
+            retry_statuses: vec![429, 502, 503, 504],
+        }),
+    };
+    let client = discord
+        .wassemble_discord_api()
+        .client()
+        .call_constructor(&mut store, &config)
+        .unwrap();
+    Bot {
+        client,
+        discord,
+        store,
+    }
+}
+
+fn webhook() -> Webhook {
+    Webhook {
+        id: "223704706495545344".to_string(),
+        token: "3d89bb7572e0fb30d8128367b3b1b44fecd1726de135cbe28a41f8b2f777c372ba2939e72279b94526ff5d1bd4358d65cf11".to_string(),
+        url: "https://discord.com/api/webhooks/223704706495545344/3d89bb7572e0fb30d8128367b3b1b44fecd1726de135cbe28a41f8b2f777c372ba2939e72279b94526ff5d1bd4358d65cf11".to_string(),
+    }
+}
+
+#[test]
+fn test_create_webhook() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/api/v10/channels/199737254929760256/webhooks",
+        MockResponse::fixture(200, "discord/webhook.json"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let webhook = bot
+        .discord
+        .wassemble_discord_api()
+        .client()
+        .call_create_webhook(
+            &mut bot.store,
+            bot.client,
+            "199737254929760256",
+            "test webhook",
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(webhook.id, "223704706495545344");
+    assert!(webhook.url.ends_with(&webhook.token));
+
+    let request = mock.single_request();
+    assert_eq!(
+        request.uri,
+        "https://discord.com/api/v10/channels/199737254929760256/webhooks"
+    );
+    assert_eq!(request.header("authorization"), Some("Bot bot-token"));
+    assert!(
+        request
+            .header("user-agent")
+            .is_some_and(|agent| agent.starts_with("DiscordBot ("))
+    );
+    assert_eq!(request.json()["name"], "test webhook");
+}
+
+#[test]
+fn test_create_webhook_missing_permissions() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/api/v10/channels/199737254929760256/webhooks",
+        MockResponse::fixture(403, "discord/missing_permissions.json"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let result = bot
+        .discord
+        .wassemble_discord_api()
+        .client()
+        .call_create_webhook(
+            &mut bot.store,
+            bot.client,
+            "199737254929760256",
+            "test webhook",
+        )
+        .unwrap();
+    let Err(DiscordError::ApiError(error)) = result else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.status, 403);
+    assert_eq!(error.code, 50013);
+    assert_eq!(error.message, "Missing Permissions");
+}
+
+#[test]
+fn test_delete_webhook() {
+    let webhook = webhook();
+    let mock = MockServer::new();
+    mock.on(
+        "DELETE",
+        &format!("/api/v10/webhooks/{}/{}", webhook.id, webhook.token),
+        MockResponse::new(204),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    bot.discord
+        .wassemble_discord_api()
+        .client()
+        .call_delete_webhook(&mut bot.store, bot.client, &webhook.id, &webhook.token)
+        .unwrap()
+        .unwrap();
+    assert_eq!(mock.single_request().method, "DELETE");
+}
+
+#[test]
+fn test_delete_webhook_unknown() {
+    let webhook = webhook();
+    let mock = MockServer::new();
+    mock.on(
+        "DELETE",
+        &format!("/api/v10/webhooks/{}/{}", webhook.id, webhook.token),
+        MockResponse::fixture(404, "discord/unknown_webhook.json"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let result = bot
+        .discord
+        .wassemble_discord_api()
+        .client()
+        .call_delete_webhook(&mut bot.store, bot.client, &webhook.id, &webhook.token)
+        .unwrap();
+    let Err(DiscordError::ApiError(error)) = result else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.status, 404);
+    assert_eq!(error.code, 10015);
+}
+
+#[test]
+fn test_delete_message() {
+    let mock = MockServer::new();
+    mock.on(
+        "DELETE",
+        &format!("/api/v10/channels/{CHANNEL_ID}/messages/{MESSAGE_ID}"),
+        MockResponse::new(204),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    bot.discord
+        .wassemble_discord_api()
+        .client()
+        .call_delete_message(&mut bot.store, bot.client, CHANNEL_ID, MESSAGE_ID)
+        .unwrap()
+        .unwrap();
+}
+
+#[test]
+fn test_delete_message_unknown() {
+    let mock = MockServer::new();
+    mock.on(
+        "DELETE",
+        &format!("/api/v10/channels/{CHANNEL_ID}/messages/{MESSAGE_ID}"),
+        MockResponse::fixture(404, "discord/unknown_message.json"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let result = bot
+        .discord
+        .wassemble_discord_api()
+        .client()
+        .call_delete_message(&mut bot.store, bot.client, CHANNEL_ID, MESSAGE_ID)
+        .unwrap();
+    let Err(DiscordError::ApiError(error)) = result else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.code, 10008);
+    assert_eq!(error.message, "Unknown Message");
+}
+
+#[test]
+fn test_edit_message() {
+    let mock = MockServer::new();
+    mock.on(
+        "PATCH",
+        &format!("/api/v10/channels/{CHANNEL_ID}/messages/{MESSAGE_ID}"),
+        MockResponse::fixture(200, "discord/edited_message.json"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let message = bot
+        .discord
+        .wassemble_discord_api()
+        .client()
+        .call_edit_message(
+            &mut bot.store,
+            bot.client,
+            CHANNEL_ID,
+            MESSAGE_ID,
+            "Supa Hot, edited",
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(message.content, "Supa Hot, edited");
+    assert_eq!(message.author.username, "Mason");
+    assert!(message.edited_timestamp.is_some());
+    assert_eq!(mock.single_request().json()["content"], "Supa Hot, edited");
+}
+
+#[test]
+fn test_edit_message_invalid_form_body() {
+    let mock = MockServer::new();
+    mock.on(
+        "PATCH",
+        &format!("/api/v10/channels/{CHANNEL_ID}/messages/{MESSAGE_ID}"),
+        MockResponse::fixture(400, "discord/invalid_form_body.json"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let result = bot
+        .discord
+        .wassemble_discord_api()
+        .client()
+        .call_edit_message(&mut bot.store, bot.client, CHANNEL_ID, MESSAGE_ID, "")
+        .unwrap();
+    let Err(DiscordError::ApiError(error)) = result else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.code, 50035);
+    assert!(
+        error
+            .errors
+            .is_some_and(|errors| errors.contains("BASE_TYPE_MAX_LENGTH"))
+    );
+}
+
+#[test]
+fn test_get_channel() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/api/v10/channels/41771983423143937",
+        MockResponse::fixture(200, "discord/channel.json"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let channel = bot
+        .discord
+        .wassemble_discord_api()
+        .client()
+        .call_get_channel(&mut bot.store, bot.client, "41771983423143937")
+        .unwrap()
+        .unwrap();
+    assert_eq!(channel.name, "general");
+    assert_eq!(channel.ty, 0);
+    assert_eq!(channel.guild_id.as_deref(), Some("41771983423143937"));
+}
+
+#[test]
+fn test_get_channel_unknown() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/api/v10/channels/1",
+        MockResponse::fixture(404, "discord/unknown_channel.json"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let result = bot
+        .discord
+        .wassemble_discord_api()
+        .client()
+        .call_get_channel(&mut bot.store, bot.client, "1")
+        .unwrap();
+    let Err(DiscordError::ApiError(error)) = result else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.code, 10003);
+}
+
+#[test]
+fn test_get_user() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/api/v10/users/80351110224678912",
+        MockResponse::fixture(200, "discord/user.json"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let user = bot
+        .discord
+        .wassemble_discord_api()
+        .client()
+        .call_get_user(&mut bot.store, bot.client, "80351110224678912")
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.username, "Nelly");
+    assert_eq!(user.discriminator, "1337");
+}
+
+#[test]
+fn test_get_user_unknown() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/api/v10/users/1",
+        MockResponse::fixture(404, "discord/unknown_user.json"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let result = bot
+        .discord
+        .wassemble_discord_api()
+        .client()
+        .call_get_user(&mut bot.store, bot.client, "1")
+        .unwrap();
+    let Err(DiscordError::ApiError(error)) = result else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.code, 10013);
+}
+
+#[test]
+fn test_send_message() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        &format!("/api/v10/channels/{CHANNEL_ID}/messages"),
+        MockResponse::fixture(200, "discord/message.json"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let message = Message {
+        content: "Supa Hot".to_string(),
+        channel_id: CHANNEL_ID.to_string(),
+        guild_id: None,
+    };
+    let id = bot
+        .discord
+        .wassemble_discord_api()
+        .client()
+        .call_send_message(&mut bot.store, bot.client, &message)
+        .unwrap()
+        .unwrap();
+    assert_eq!(id, MESSAGE_ID);
+    assert_eq!(mock.single_request().json()["content"], "Supa Hot");
+}
+
+#[test]
+fn test_send_message_non_json_error() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        &format!("/api/v10/channels/{CHANNEL_ID}/messages"),
+        MockResponse::new(502).body("<html>502 Bad Gateway</html>"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let message = Message {
+        content: "Supa Hot".to_string(),
+        channel_id: CHANNEL_ID.to_string(),
+        guild_id: None,
+    };
+    let result = bot
+        .discord
+        .wassemble_discord_api()
+        .client()
+        .call_send_message(&mut bot.store, bot.client, &message)
+        .unwrap();
+    let Err(DiscordError::ApiError(error)) = result else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.status, 502);
+    // the message may have been posted, so a POST is not retried on a gateway error
+    assert_eq!(mock.requests().len(), 1);
+    assert_eq!(error.code, 0);
+    assert_eq!(error.message, "<html>502 Bad Gateway</html>");
+}
+
+#[test]
+fn test_send_webhook_message() {
+    let webhook = webhook();
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        &format!("/api/v10/webhooks/{}/{}", webhook.id, webhook.token),
+        MockResponse::fixture(200, "discord/message.json"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let id = bot
+        .discord
+        .wassemble_discord_api()
+        .client()
+        .call_send_webhook_message(&mut bot.store, bot.client, &webhook, "Supa Hot")
+        .unwrap()
+        .unwrap();
+    assert_eq!(id, MESSAGE_ID);
+
+    // DiscordWithConfig only answers with the message, instead of a 204, when asked to wait
+    let request = mock.single_request();
+    assert_eq!(
+        request.uri,
+        format!(
+            "https://discord.com/api/v10/webhooks/{}/{}?wait=true",
+            webhook.id, webhook.token
+        )
+    );
+    assert_eq!(request.json()["content"], "Supa Hot");
+}
+
+#[test]
+fn test_send_webhook_message_unknown_webhook() {
+    let webhook = webhook();
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        &format!("/api/v10/webhooks/{}/{}", webhook.id, webhook.token),
+        MockResponse::fixture(404, "discord/unknown_webhook.json"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let result = bot
+        .discord
+        .wassemble_discord_api()
+        .client()
+        .call_send_webhook_message(&mut bot.store, bot.client, &webhook, "Supa Hot")
+        .unwrap();
+    let Err(DiscordError::ApiError(error)) = result else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.code, 10015);
+}
+
+#[test]
+fn test_token_from_runtime_config() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/api/v10/users/80351110224678912",
+        MockResponse::fixture(200, "discord/user.json"),
+    );
+    let mut bot = connect(&mock, "", &[("discord_bot_token", "from-config")]);
+
+    bot.discord
+        .wassemble_discord_api()
+        .client()
+        .call_get_user(&mut bot.store, bot.client, "80351110224678912")
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        mock.single_request().header("authorization"),
+        Some("Bot from-config")
+    );
+}
+
+#[test]
+fn test_retries_rate_limited_request() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/api/v10/channels/41771983423143937",
+        MockResponse::fixture(429, "discord/rate_limited.json")
+            .header("retry-after", "1")
+            .header("x-ratelimit-scope", "user"),
+    );
+    mock.on(
+        "GET",
+        "/api/v10/channels/41771983423143937",
+        MockResponse::fixture(200, "discord/channel.json")
+            .header("x-ratelimit-bucket", "80c17d2f203122d936070c88c8d10f33")
+            .header("x-ratelimit-limit", "5")
+            .header("x-ratelimit-remaining", "4")
+            .header("x-ratelimit-reset-after", "0.5"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let api = bot.discord.wassemble_discord_api();
+    api.client()
+        .call_get_channel(&mut bot.store, bot.client, "41771983423143937")
+        .unwrap()
+        .unwrap();
+    assert_eq!(mock.requests().len(), 2);
+
+    let status = api
+        .client()
+        .call_last_rate_limit(&mut bot.store, bot.client)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        status.bucket.as_deref(),
+        Some("80c17d2f203122d936070c88c8d10f33")
+    );
+    assert_eq!(status.remaining, 4);
+    assert_eq!(status.reset_after_ms, 500);
+}
+
+#[test]
+fn test_rate_limited_beyond_max_delay() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/api/v10/channels/41771983423143937",
+        MockResponse::fixture(429, "discord/rate_limited_global.json")
+            .header("retry-after", "65")
+            .header("x-ratelimit-global", "true")
+            .header("x-ratelimit-scope", "global"),
+    );
+    let mut bot = connect(&mock, "bot-token", &[]);
+
+    let result = bot
+        .discord
+        .wassemble_discord_api()
+        .client()
+        .call_get_channel(&mut bot.store, bot.client, "41771983423143937")
+        .unwrap();
+    let Err(DiscordError::RateLimited(rate_limit)) = result else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert!(rate_limit.global);
+    assert_eq!(rate_limit.retry_after_ms, 64570);
+    assert_eq!(rate_limit.scope.as_deref(), Some("global"));
+    assert_eq!(mock.requests().len(), 1);
+}
@@ -0,0 +1,416 @@
+use integration_tests::{
+    Harness, Host, MockResponse, MockServer,
+    bindings::github::{
+        GithubWithConfig,
+        wassemble::github::types::{Config, GithubError, RetryConfig},
+    },
+};
+use wasmtime::{Store, component::ResourceAny};
+
+struct GitHub {
+    client: ResourceAny,
+    github: GithubWithConfig,
+    store: Store<Host>,
+}
+
+fn connect(mock: &MockServer, token: &str, runtime_config: &[(&str, &str)]) -> GitHub {
+    let harness = Harness::load("github");
+    let mut store = harness.store(mock, runtime_config);
+    let github =
+        GithubWithConfig::instantiate(&mut store, harness.component(), harness.linker()).unwrap();
+    let config = Config {
+        token: token.to_string(),
+        base_url: None,
+        user_agent: None,
+        connect_timeout_ms: None,
+        retry: Some(RetryConfig {
+            max_attempts: 3,
+            initial_delay_ms: 0,
+            max_delay_ms: 1_000,
+            retry_statuses: vec![429, 502, 503, 504],
+        }),
+    };
+    let client = github
+        .wassemble_github_api()
+        .client()
+        .call_constructor(&mut store, &config)
+        .unwrap();
+    GitHub {
+        client,
+        github,
+        store,
+    }
+}
+
+#[test]
+fn test_create_issue() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/repos/octocat/Hello-World/issues",
+        MockResponse::fixture(201, "github/issue.json"),
+    );
+    let mut gh = connect(&mock, "ghp_test", &[]);
+
+    let issue = gh
+        .github
+        .wassemble_github_api()
+        .client()
+        .call_create_issue(
+            &mut gh.store,
+            gh.client,
+            "octocat",
+            "Hello-World",
+            "Found a bug",
+            "I'm having a problem with this.",
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(issue.number, 1347);
+    assert_eq!(issue.title, "Found a bug");
+
+    let request = mock.single_request();
+    assert_eq!(
+        request.uri,
+        "https://api.github.com/repos/octocat/Hello-World/issues"
+    );
+    assert_eq!(request.header("authorization"), Some("Bearer ghp_test"));
+    assert_eq!(
+        request.header("accept"),
+        Some("application/vnd.github.v3+json")
+    );
+    assert_eq!(request.json()["title"], "Found a bug");
+    assert_eq!(request.json()["body"], "I'm having a problem with this.");
+}
+
+#[test]
+fn test_create_repository() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/user/repos",
+        MockResponse::fixture(201, "github/repository.json"),
+    );
+    let mut gh = connect(&mock, "ghp_test", &[]);
+
+    let repository = gh
+        .github
+        .wassemble_github_api()
+        .client()
+        .call_create_repository(
+            &mut gh.store,
+            gh.client,
+            "Hello-World",
+            "This your first repo!",
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(repository.name, "Hello-World");
+    assert_eq!(repository.owner, "octocat");
+
+    let request = mock.single_request();
+    assert_eq!(request.json()["name"], "Hello-World");
+    assert_eq!(request.json()["private"], false);
+}
+
+#[test]
+fn test_create_repository_validation_failed() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/user/repos",
+        MockResponse::fixture(422, "github/validation_failed.json"),
+    );
+    let mut gh = connect(&mock, "ghp_test", &[]);
+
+    let result = gh
+        .github
+        .wassemble_github_api()
+        .client()
+        .call_create_repository(&mut gh.store, gh.client, "Hello-World", "")
+        .unwrap();
+    let Err(GithubError::ValidationFailed(failure)) = result else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(failure.message, "Repository creation failed.");
+    assert_eq!(failure.errors.len(), 1);
+    assert_eq!(failure.errors[0].field.as_deref(), Some("name"));
+    assert_eq!(failure.errors[0].code, "custom");
+}
+
+#[test]
+fn test_delete_repository() {
+    let mock = MockServer::new();
+    mock.on(
+        "DELETE",
+        "/repos/octocat/Hello-World",
+        MockResponse::new(204),
+    );
+    let mut gh = connect(&mock, "ghp_test", &[]);
+
+    gh.github
+        .wassemble_github_api()
+        .client()
+        .call_delete_repository(&mut gh.store, gh.client, "octocat", "Hello-World")
+        .unwrap()
+        .unwrap();
+    assert_eq!(mock.single_request().method, "DELETE");
+}
+
+#[test]
+fn test_delete_repository_rate_limited() {
+    let mock = MockServer::new();
+    mock.on(
+        "DELETE",
+        "/repos/octocat/Hello-World",
+        MockResponse::fixture(403, "github/rate_limited.json")
+            .header("x-ratelimit-limit", "5000")
+            .header("x-ratelimit-remaining", "0")
+            .header("x-ratelimit-reset", "4102444800"),
+    );
+    let mut gh = connect(&mock, "ghp_test", &[]);
+
+    let result = gh
+        .github
+        .wassemble_github_api()
+        .client()
+        .call_delete_repository(&mut gh.store, gh.client, "octocat", "Hello-World")
+        .unwrap();
+    let Err(GithubError::RateLimited(rate_limit)) = result else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(rate_limit.limit, Some(5000));
+    assert_eq!(rate_limit.remaining, Some(0));
+    assert_eq!(rate_limit.reset, Some(4102444800));
+    // resetting later than the maximum delay is left to the caller
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[test]
+fn test_get_user() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/user",
+        MockResponse::fixture(200, "github/user.json"),
+    );
+    let mut gh = connect(&mock, "ghp_test", &[]);
+
+    let user = gh
+        .github
+        .wassemble_github_api()
+        .client()
+        .call_get_user(&mut gh.store, gh.client)
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.login, "octocat");
+    assert_eq!(user.id, 1);
+}
+
+#[test]
+fn test_get_user_bad_credentials() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/user",
+        MockResponse::fixture(401, "github/bad_credentials.json"),
+    );
+    let mut gh = connect(&mock, "ghp_invalid", &[]);
+
+    let result = gh
+        .github
+        .wassemble_github_api()
+        .client()
+        .call_get_user(&mut gh.store, gh.client)
+        .unwrap();
+    let Err(GithubError::HttpError(error)) = result else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.status, 401);
+    assert_eq!(error.message, "Bad credentials");
+}
+
+#[test]
+fn test_update_issue() {
+    let mock = MockServer::new();
+    mock.on(
+        "PATCH",
+        "/repos/octocat/Hello-World/issues/1347",
+        MockResponse::fixture(200, "github/issue.json"),
+    );
+    let mut gh = connect(&mock, "ghp_test", &[]);
+
+    let issue = gh
+        .github
+        .wassemble_github_api()
+        .client()
+        .call_update_issue(
+            &mut gh.store,
+            gh.client,
+            "octocat",
+            "Hello-World",
+            1347,
+            "Found a bug",
+            "I'm having a problem with this.",
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(issue.number, 1347);
+}
+
+#[test]
+fn test_update_issue_not_found() {
+    let mock = MockServer::new();
+    mock.on(
+        "PATCH",
+        "/repos/octocat/Hello-World/issues/1",
+        MockResponse::fixture(404, "github/not_found.json"),
+    );
+    let mut gh = connect(&mock, "ghp_test", &[]);
+
+    let result = gh
+        .github
+        .wassemble_github_api()
+        .client()
+        .call_update_issue(
+            &mut gh.store,
+            gh.client,
+            "octocat",
+            "Hello-World",
+            1,
+            "",
+            "",
+        )
+        .unwrap();
+    let Err(GithubError::HttpError(error)) = result else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.status, 404);
+    assert_eq!(error.message, "Not Found");
+    assert!(error.documentation_url.is_some());
+}
+
+#[test]
+fn test_token_from_runtime_config() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/user",
+        MockResponse::fixture(200, "github/user.json"),
+    );
+    let mut gh = connect(&mock, "", &[("github_token", "ghp_from_config")]);
+
+    gh.github
+        .wassemble_github_api()
+        .client()
+        .call_get_user(&mut gh.store, gh.client)
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        mock.single_request().header("authorization"),
+        Some("Bearer ghp_from_config")
+    );
+}
+
+#[test]
+fn test_unauthenticated_without_token() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/user",
+        MockResponse::fixture(200, "github/user.json"),
+    );
+    let mut gh = connect(&mock, "", &[]);
+
+    gh.github
+        .wassemble_github_api()
+        .client()
+        .call_get_user(&mut gh.store, gh.client)
+        .unwrap()
+        .unwrap();
+    assert_eq!(mock.single_request().header("authorization"), None);
+}
+
+#[test]
+fn test_retries_server_error() {
+    let mock = MockServer::new();
+    mock.on("GET", "/user", MockResponse::new(503));
+    mock.on(
+        "GET",
+        "/user",
+        MockResponse::fixture(200, "github/user.json")
+            .header("x-ratelimit-limit", "5000")
+            .header("x-ratelimit-remaining", "4998")
+            .header("x-ratelimit-used", "2")
+            .header("x-ratelimit-reset", "1700000000")
+            .header("x-ratelimit-resource", "core"),
+    );
+    let mut gh = connect(&mock, "ghp_test", &[]);
+
+    let api = gh.github.wassemble_github_api();
+    let user = api
+        .client()
+        .call_get_user(&mut gh.store, gh.client)
+        .unwrap()
+        .unwrap();
+    assert_eq!(user.login, "octocat");
+    assert_eq!(mock.requests().len(), 2);
+
+    let status = api
+        .client()
+        .call_last_rate_limit(&mut gh.store, gh.client)
+        .unwrap()
+        .unwrap();
+    assert_eq!(status.remaining, 4998);
+    assert_eq!(status.used, Some(2));
+    assert_eq!(status.resource.as_deref(), Some("core"));
+}
+
+#[test]
+fn test_retries_exhausted_primary_rate_limit() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/user",
+        MockResponse::fixture(403, "github/rate_limited.json")
+            .header("x-ratelimit-remaining", "0")
+            // already reset, so the retry is immediate
+            .header("x-ratelimit-reset", "1700000000"),
+    );
+    mock.on(
+        "GET",
+        "/user",
+        MockResponse::fixture(200, "github/user.json"),
+    );
+    let mut gh = connect(&mock, "ghp_test", &[]);
+
+    gh.github
+        .wassemble_github_api()
+        .client()
+        .call_get_user(&mut gh.store, gh.client)
+        .unwrap()
+        .unwrap();
+    assert_eq!(mock.requests().len(), 2);
+}
+
+#[test]
+fn test_no_rate_limit_status_without_headers() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/user",
+        MockResponse::fixture(200, "github/user.json"),
+    );
+    let mut gh = connect(&mock, "ghp_test", &[]);
+
+    let api = gh.github.wassemble_github_api();
+    api.client()
+        .call_get_user(&mut gh.store, gh.client)
+        .unwrap()
+        .unwrap();
+    let status = api
+        .client()
+        .call_last_rate_limit(&mut gh.store, gh.client)
+        .unwrap();
+    assert!(status.is_none());
+}
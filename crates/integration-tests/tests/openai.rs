@@ -0,0 +1,1867 @@
+use integration_tests::{
+    Harness, Host, MockResponse, MockServer,
+    bindings::openai::{
+        OpenaiWithConfig,
+        wassemble::openai::types::{
+            AzureConfig, BatchBody, BatchEndpoint, BatchInputLine, BatchRequest, BatchResult,
+            ChatChunk, ChatCompletion, ChatMessage, Config, ContentPart, Embedding, EmbeddingBatch,
+            ErrorKind, FileUpload, ImageBackground, ImageData, ImageDetail, ImageEdit,
+            ImageGeneration, ImageOutputFormat, ImageUrl, InputItem, InputMessage,
+            JsonSchemaFormat, MessageContent, Moderation, ModerationInput, ModerationPart,
+            OpenaiError, OutputItem, ReasoningEffort, ResponseFormat, ResponseRequest,
+            ResponseTool, RetryConfig, Speech, SpeechFormat, TimestampGranularity, Tool,
+            ToolChoice, Transcription, TranscriptionFormat, Translation,
+        },
+    },
+};
+use wasmtime::{Store, component::ResourceAny};
+
+struct OpenAI {
+    client: ResourceAny,
+    openai: OpenaiWithConfig,
+    store: Store<Host>,
+}
+
+fn config(api_key: &str) -> Config {
+    Config {
+        api_key: api_key.to_string(),
+        base_url: None,
+        user_agent: None,
+        organization: None,
+        project: None,
+        connect_timeout_ms: None,
+        retry: Some(RetryConfig {
+            max_attempts: 3,
+            initial_delay_ms: 0,
+            max_delay_ms: 1_000,
+            retry_statuses: vec![429, 502, 503, 504],
+        }),
+        azure: None,
+    }
+}
+
+fn connect(mock: &MockServer, api_key: &str, runtime_config: &[(&str, &str)]) -> OpenAI {
+    connect_with(mock, &config(api_key), runtime_config)
+}
+
+fn connect_with(mock: &MockServer, config: &Config, runtime_config: &[(&str, &str)]) -> OpenAI {
+    let harness = Harness::load("openai");
+    let mut store = harness.store(mock, runtime_config);
+    let openai =
+        OpenaiWithConfig::instantiate(&mut store, harness.component(), harness.linker()).unwrap();
+    let client = openai
+        .wassemble_openai_api()
+        .client()
+        .call_constructor(&mut store, config)
+        .unwrap();
+    OpenAI {
+        client,
+        openai,
+        store,
+    }
+}
+
+impl OpenAI {
+    /// Reads a streamed chat completion to its end, which must not fail.
+    fn collect_stream(&mut self, stream: ResourceAny) -> Vec<ChatChunk> {
+        let mut chunks = Vec::new();
+        let chat_stream = self.openai.wassemble_openai_api().chat_stream();
+        while let Some(chunk) = chat_stream
+            .call_next_chunk(&mut self.store, stream)
+            .unwrap()
+            .unwrap()
+        {
+            chunks.push(chunk);
+        }
+        chunks
+    }
+}
+
+fn completion() -> ChatCompletion {
+    ChatCompletion {
+        id: "test".to_string(),
+        model: "gpt-4.1".to_string(),
+        messages: vec![ChatMessage {
+            role: "user".to_string(),
+            content: MessageContent::Text("Hello!".to_string()),
+            tool_calls: Vec::new(),
+            tool_call_id: None,
+        }],
+        temperature: Some(0.2),
+        max_tokens: None,
+        max_completion_tokens: None,
+        top_p: None,
+        stop: Vec::new(),
+        seed: None,
+        presence_penalty: None,
+        frequency_penalty: None,
+        logit_bias: Vec::new(),
+        reasoning_effort: None,
+        user: None,
+        tools: Vec::new(),
+        tool_choice: None,
+        parallel_tool_calls: None,
+        response_format: None,
+        n: None,
+        logprobs: None,
+        top_logprobs: None,
+        store: None,
+        metadata: Vec::new(),
+        client_request_id: None,
+    }
+}
+
+fn embedding() -> Embedding {
+    Embedding {
+        model: "text-embedding-3-small".to_string(),
+        input: "The food was delicious and the waiter...".to_string(),
+        client_request_id: None,
+    }
+}
+
+#[test]
+fn test_create_chat_completion() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(200, "openai/chat_completion.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let response = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_chat_completion(&mut ai.store, ai.client, &completion())
+        .unwrap()
+        .unwrap();
+    assert_eq!(response.id, "chatcmpl-B9MBs8CjcvOU2jLn4n570S5qMJKcT");
+    assert_eq!(response.content, "Hello! How can I assist you today?");
+    assert_eq!(response.finish_reason, "stop");
+    assert_eq!(response.created, 1741569952);
+    assert_eq!(response.choices.len(), 1);
+    let usage = response.usage.unwrap();
+    assert_eq!(usage.prompt_tokens, 19);
+    assert_eq!(usage.completion_tokens, 10);
+    assert_eq!(usage.cached_tokens, Some(0));
+
+    let request = mock.single_request();
+    assert_eq!(request.uri, "https://api.openai.com/v1/chat/completions");
+    assert_eq!(request.header("authorization"), Some("Bearer sk-test"));
+    let body = request.json();
+    assert_eq!(body["model"], "gpt-4.1");
+    assert_eq!(body["messages"][0]["content"], "Hello!");
+    assert_eq!(body["temperature"], 0.2);
+    // unset parameters are left to the API's defaults
+    assert!(body.get("max_tokens").is_none());
+    assert!(body.get("seed").is_none());
+}
+
+#[test]
+fn test_create_chat_completion_with_image() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(200, "openai/chat_completion.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let mut request = completion();
+    request.messages[0].content = MessageContent::Parts(vec![
+        ContentPart::Text("Which error does this screenshot show?".to_string()),
+        ContentPart::ImageUrl(ImageUrl {
+            url: "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==".to_string(),
+            detail: Some(ImageDetail::Low),
+        }),
+    ]);
+    ai.openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_chat_completion(&mut ai.store, ai.client, &request)
+        .unwrap()
+        .unwrap();
+
+    let body = mock.single_request().json();
+    let content = &body["messages"][0]["content"];
+    assert_eq!(content[0]["type"], "text");
+    assert_eq!(content[0]["text"], "Which error does this screenshot show?");
+    assert_eq!(content[1]["type"], "image_url");
+    assert!(
+        content[1]["image_url"]["url"]
+            .as_str()
+            .unwrap()
+            .starts_with("data:image/png;base64,")
+    );
+    assert_eq!(content[1]["image_url"]["detail"], "low");
+}
+
+#[test]
+fn test_create_chat_completion_choices_and_logprobs() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(200, "openai/chat_completion_choices.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let mut request = completion();
+    request.n = Some(2);
+    request.logprobs = Some(true);
+    request.top_logprobs = Some(2);
+    let response = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_chat_completion(&mut ai.store, ai.client, &request)
+        .unwrap()
+        .unwrap();
+    assert_eq!(response.content, "Hi");
+    assert_eq!(
+        response.system_fingerprint.as_deref(),
+        Some("fp_8fd43718b3")
+    );
+    assert_eq!(response.choices.len(), 2);
+    assert_eq!(response.choices[1].index, 1);
+    assert_eq!(response.choices[1].content, "Hello");
+
+    let logprobs = response.choices[0].logprobs.as_ref().unwrap();
+    assert_eq!(logprobs[0].token, "Hi");
+    assert_eq!(logprobs[0].bytes.as_deref(), Some(&b"Hi"[..]));
+    assert_eq!(logprobs[0].top_logprobs[1].token, "Hello");
+    assert!(logprobs[0].top_logprobs[1].logprob < logprobs[0].logprob);
+
+    let usage = response.usage.unwrap();
+    assert_eq!(usage.total_tokens, 1163);
+    assert_eq!(usage.cached_tokens, Some(1024));
+    assert_eq!(usage.reasoning_tokens, Some(32));
+
+    let body = mock.single_request().json();
+    assert_eq!(body["n"], 2);
+    assert_eq!(body["logprobs"], true);
+    assert_eq!(body["top_logprobs"], 2);
+}
+
+#[test]
+fn test_create_chat_completion_no_choices() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(200, "openai/chat_completion_no_choices.json")
+            .header("x-request-id", "req_7c41d09e"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let result = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_chat_completion(&mut ai.store, ai.client, &completion())
+        .unwrap();
+    // errors raised for a successful response carry its request ID too
+    let Err(OpenaiError {
+        kind: ErrorKind::NoChoices,
+        request_id,
+    }) = result
+    else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(request_id.as_deref(), Some("req_7c41d09e"));
+}
+
+#[test]
+fn test_create_chat_completion_invalid_api_key() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(401, "openai/invalid_api_key.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let result = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_chat_completion(&mut ai.store, ai.client, &completion())
+        .unwrap();
+    let Err(OpenaiError {
+        kind: ErrorKind::Authentication(error),
+        ..
+    }) = result
+    else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.status, 401);
+    assert_eq!(error.code.as_deref(), Some("invalid_api_key"));
+    assert_eq!(error.type_.as_deref(), Some("invalid_request_error"));
+    assert!(error.message.starts_with("Incorrect API key provided"));
+}
+
+#[test]
+fn test_create_chat_completion_tool_calls() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(200, "openai/chat_completion_tool_calls.json"),
+    );
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(200, "openai/chat_completion.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let mut request = completion();
+    request.tools = vec![Tool {
+        name: "get_weather".to_string(),
+        description: Some("Get the current weather in a location".to_string()),
+        parameters: Some(
+            r#"{"type":"object","properties":{"location":{"type":"string"}},"required":["location"]}"#
+                .to_string(),
+        ),
+        strict: None,
+    }];
+    request.tool_choice = Some(ToolChoice::Auto);
+
+    let api = ai.openai.wassemble_openai_api();
+    let response = api
+        .client()
+        .call_create_chat_completion(&mut ai.store, ai.client, &request)
+        .unwrap()
+        .unwrap();
+    assert_eq!(response.content, "");
+    assert_eq!(response.finish_reason, "tool_calls");
+    let call = &response.tool_calls[0];
+    assert_eq!(call.id, "call_DdmO9pD3xa9XTPNJ32zg2hcA");
+    assert_eq!(call.name, "get_weather");
+    assert_eq!(call.arguments, r#"{"location":"Paris, France"}"#);
+
+    // answer the call and let the model continue
+    request.messages.push(ChatMessage {
+        role: "assistant".to_string(),
+        content: MessageContent::Text(String::new()),
+        tool_calls: response.tool_calls.clone(),
+        tool_call_id: None,
+    });
+    request.messages.push(ChatMessage {
+        role: "tool".to_string(),
+        content: MessageContent::Text(r#"{"temperature":18}"#.to_string()),
+        tool_calls: Vec::new(),
+        tool_call_id: Some(call.id.clone()),
+    });
+    api.client()
+        .call_create_chat_completion(&mut ai.store, ai.client, &request)
+        .unwrap()
+        .unwrap();
+
+    let requests = mock.requests();
+    let first = requests[0].json();
+    assert_eq!(first["tools"][0]["type"], "function");
+    assert_eq!(first["tools"][0]["function"]["name"], "get_weather");
+    assert_eq!(
+        first["tools"][0]["function"]["parameters"]["required"][0],
+        "location"
+    );
+    assert_eq!(first["tool_choice"], "auto");
+    let second = requests[1].json();
+    assert_eq!(second["messages"][1]["content"], serde_json::Value::Null);
+    assert_eq!(
+        second["messages"][1]["tool_calls"][0]["id"],
+        "call_DdmO9pD3xa9XTPNJ32zg2hcA"
+    );
+    assert_eq!(second["messages"][2]["role"], "tool");
+    assert_eq!(
+        second["messages"][2]["tool_call_id"],
+        "call_DdmO9pD3xa9XTPNJ32zg2hcA"
+    );
+}
+
+fn structured_completion() -> ChatCompletion {
+    let mut completion = completion();
+    completion.response_format = Some(ResponseFormat::JsonSchema(JsonSchemaFormat {
+        name: "calendar_event".to_string(),
+        description: None,
+        schema: r#"{
+            "type": "object",
+            "properties": {
+                "name": {"type": "string"},
+                "date": {"type": "string"},
+                "participants": {"type": "array", "items": {"type": "string"}}
+            },
+            "required": ["name", "date", "participants"],
+            "additionalProperties": false
+        }"#
+        .to_string(),
+        strict: Some(true),
+    }));
+    completion
+}
+
+#[test]
+fn test_create_structured_completion() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(200, "openai/chat_completion_structured.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let response = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_structured_completion(&mut ai.store, ai.client, &structured_completion())
+        .unwrap()
+        .unwrap();
+    let content: serde_json::Value = serde_json::from_str(&response.content).unwrap();
+    assert_eq!(content["participants"][1], "Bob");
+
+    let body = mock.single_request().json();
+    let format = &body["response_format"];
+    assert_eq!(format["type"], "json_schema");
+    assert_eq!(format["json_schema"]["name"], "calendar_event");
+    assert_eq!(format["json_schema"]["strict"], true);
+    assert_eq!(
+        format["json_schema"]["schema"]["required"][2],
+        "participants"
+    );
+}
+
+#[test]
+fn test_create_structured_completion_schema_violation() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(200, "openai/chat_completion_structured_invalid.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let result = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_structured_completion(&mut ai.store, ai.client, &structured_completion())
+        .unwrap();
+    let Err(OpenaiError {
+        kind: ErrorKind::SchemaViolation(message),
+        ..
+    }) = result
+    else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert!(message.contains("missing required property \"participants\""));
+}
+
+#[test]
+fn test_create_structured_completion_refusal() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(200, "openai/chat_completion_refusal.json"),
+    );
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(200, "openai/chat_completion_refusal.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let api = ai.openai.wassemble_openai_api();
+    let result = api
+        .client()
+        .call_create_structured_completion(&mut ai.store, ai.client, &structured_completion())
+        .unwrap();
+    let Err(OpenaiError {
+        kind: ErrorKind::Refusal(refusal),
+        ..
+    }) = result
+    else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(refusal, "I'm sorry, I can't help with that request.");
+
+    // a plain completion reports the refusal alongside the empty content
+    let response = api
+        .client()
+        .call_create_chat_completion(&mut ai.store, ai.client, &structured_completion())
+        .unwrap()
+        .unwrap();
+    assert_eq!(response.content, "");
+    assert_eq!(
+        response.refusal.as_deref(),
+        Some("I'm sorry, I can't help with that request.")
+    );
+}
+
+#[test]
+fn test_create_structured_completion_requires_json_format() {
+    let mock = MockServer::new();
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let result = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_structured_completion(&mut ai.store, ai.client, &completion())
+        .unwrap();
+    assert!(matches!(
+        result,
+        Err(OpenaiError {
+            kind: ErrorKind::SchemaViolation(_),
+            ..
+        })
+    ));
+    assert!(mock.requests().is_empty());
+}
+
+#[test]
+fn test_stream_chat_completion() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::event_stream("openai/chat_stream.txt"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let stream = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_stream_chat_completion(&mut ai.store, ai.client, &completion())
+        .unwrap()
+        .unwrap();
+    let chunks = ai.collect_stream(stream);
+    assert_eq!(chunks.len(), 5);
+    assert_eq!(chunks[0].role.as_deref(), Some("assistant"));
+    let content: String = chunks.iter().map(|chunk| chunk.content.as_str()).collect();
+    assert_eq!(content, "Hello! How can I assist you today?");
+    assert_eq!(chunks[3].finish_reason.as_deref(), Some("stop"));
+    let usage = chunks[4].usage.as_ref().unwrap();
+    assert_eq!(usage.prompt_tokens, 19);
+    assert_eq!(usage.completion_tokens, 10);
+
+    let request = mock.single_request();
+    assert_eq!(request.header("accept"), Some("text/event-stream"));
+    let body = request.json();
+    assert_eq!(body["stream"], true);
+    assert_eq!(body["stream_options"]["include_usage"], true);
+    assert_eq!(body["messages"][0]["content"], "Hello!");
+}
+
+#[test]
+fn test_stream_chat_completion_tool_call() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::event_stream("openai/chat_stream_tool_call.txt"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let stream = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_stream_chat_completion(&mut ai.store, ai.client, &completion())
+        .unwrap()
+        .unwrap();
+    let chunks = ai.collect_stream(stream);
+    let calls: Vec<_> = chunks.iter().flat_map(|chunk| &chunk.tool_calls).collect();
+    assert_eq!(
+        calls[0].id.as_deref(),
+        Some("call_DdmO9pD3xa9XTPNJ32zg2hcA")
+    );
+    assert_eq!(calls[0].name.as_deref(), Some("get_weather"));
+    let arguments: String = calls.iter().map(|call| call.arguments.as_str()).collect();
+    assert_eq!(arguments, r#"{"location":"Paris, France"}"#);
+    assert_eq!(
+        chunks.last().unwrap().finish_reason.as_deref(),
+        Some("tool_calls")
+    );
+}
+
+#[test]
+fn test_stream_chat_completion_error_event() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::event_stream("openai/chat_stream_error.txt"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let api = ai.openai.wassemble_openai_api();
+    let stream = api
+        .client()
+        .call_stream_chat_completion(&mut ai.store, ai.client, &completion())
+        .unwrap()
+        .unwrap();
+    let first = api
+        .chat_stream()
+        .call_next_chunk(&mut ai.store, stream)
+        .unwrap()
+        .unwrap();
+    assert_eq!(first.unwrap().content, "Hel");
+    let result = api
+        .chat_stream()
+        .call_next_chunk(&mut ai.store, stream)
+        .unwrap();
+    let Err(OpenaiError {
+        kind: ErrorKind::ServerError(error),
+        ..
+    }) = result
+    else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.status, 200);
+    assert!(error.message.starts_with("The server had an error"));
+    // the stream is finished after an error
+    let next = api
+        .chat_stream()
+        .call_next_chunk(&mut ai.store, stream)
+        .unwrap();
+    assert!(matches!(next, Ok(None)));
+}
+
+#[test]
+fn test_stream_chat_completion_invalid_api_key() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(401, "openai/invalid_api_key.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let result = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_stream_chat_completion(&mut ai.store, ai.client, &completion())
+        .unwrap();
+    let Err(OpenaiError {
+        kind: ErrorKind::Authentication(error),
+        ..
+    }) = result
+    else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.code.as_deref(), Some("invalid_api_key"));
+}
+
+#[test]
+fn test_create_embedding() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/embeddings",
+        MockResponse::fixture(200, "openai/embedding.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let response = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_embedding(&mut ai.store, ai.client, &embedding())
+        .unwrap()
+        .unwrap();
+    assert_eq!(response.model, "text-embedding-3-small");
+    assert_eq!(response.embedding.len(), 3);
+
+    let body = mock.single_request().json();
+    assert_eq!(body["model"], "text-embedding-3-small");
+    assert_eq!(body["input"], "The food was delicious and the waiter...");
+}
+
+#[test]
+fn test_create_embeddings_in_batches() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/embeddings",
+        MockResponse::fixture(200, "openai/embedding_base64.json"),
+    );
+    mock.on(
+        "POST",
+        "/v1/embeddings",
+        MockResponse::fixture(200, "openai/embedding_base64_last.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let batch = EmbeddingBatch {
+        model: "text-embedding-3-small".to_string(),
+        inputs: vec![
+            "first".to_string(),
+            "second".to_string(),
+            "third".to_string(),
+        ],
+        dimensions: Some(3),
+        max_inputs_per_request: Some(2),
+        max_tokens_per_request: None,
+    };
+    let response = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_embeddings(&mut ai.store, ai.client, &batch)
+        .unwrap()
+        .unwrap();
+    assert_eq!(response.model, "text-embedding-3-small");
+    let indexes: Vec<u32> = response
+        .embeddings
+        .iter()
+        .map(|vector| vector.index)
+        .collect();
+    assert_eq!(indexes, [0, 1, 2]);
+    assert_eq!(response.embeddings[0].embedding, [0.125, 0.75, -1.5]);
+    assert_eq!(response.embeddings[1].embedding, [0.25, -0.5, 1.0]);
+    assert_eq!(response.embeddings[2].embedding, [2.0, -0.0625, 0.5]);
+    assert_eq!(response.usage.prompt_tokens, 17);
+    assert_eq!(response.usage.total_tokens, 17);
+
+    let requests = mock.requests();
+    let first = requests[0].json();
+    assert_eq!(first["input"], serde_json::json!(["first", "second"]));
+    assert_eq!(first["dimensions"], 3);
+    assert_eq!(first["encoding_format"], "base64");
+    assert_eq!(requests[1].json()["input"], serde_json::json!(["third"]));
+}
+
+#[test]
+fn test_create_embedding_no_data() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/embeddings",
+        MockResponse::fixture(200, "openai/embedding_no_data.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let result = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_embedding(&mut ai.store, ai.client, &embedding())
+        .unwrap();
+    assert!(matches!(
+        result,
+        Err(OpenaiError {
+            kind: ErrorKind::NoEmbeddingData,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_create_embedding_model_not_found() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/embeddings",
+        MockResponse::fixture(404, "openai/model_not_found.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let result = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_embedding(&mut ai.store, ai.client, &embedding())
+        .unwrap();
+    let Err(OpenaiError {
+        kind: ErrorKind::NotFound(error),
+        ..
+    }) = result
+    else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.code.as_deref(), Some("model_not_found"));
+}
+
+#[test]
+fn test_create_chat_completion_context_length_exceeded() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(400, "openai/context_length_exceeded.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let result = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_chat_completion(&mut ai.store, ai.client, &completion())
+        .unwrap();
+    let Err(OpenaiError {
+        kind: ErrorKind::ContextLengthExceeded(error),
+        ..
+    }) = result
+    else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.status, 400);
+    assert_eq!(error.param.as_deref(), Some("messages"));
+}
+
+#[test]
+fn test_create_chat_completion_invalid_request() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(400, "openai/invalid_request.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let result = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_chat_completion(&mut ai.store, ai.client, &completion())
+        .unwrap();
+    let Err(OpenaiError {
+        kind: ErrorKind::InvalidRequest(error),
+        ..
+    }) = result
+    else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.param.as_deref(), Some("temperature"));
+    assert_eq!(error.code.as_deref(), Some("decimal_above_max_value"));
+}
+
+#[test]
+fn test_create_chat_completion_insufficient_quota() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(429, "openai/insufficient_quota.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let result = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_chat_completion(&mut ai.store, ai.client, &completion())
+        .unwrap();
+    let Err(OpenaiError {
+        kind: ErrorKind::InsufficientQuota(error),
+        ..
+    }) = result
+    else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.status, 429);
+    assert!(error.message.starts_with("You exceeded your current quota"));
+    // waiting does not restore a quota, so it is not retried
+    assert_eq!(mock.requests().len(), 1);
+}
+
+#[test]
+fn test_create_chat_completion_server_error() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(500, "openai/server_error.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let result = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_chat_completion(&mut ai.store, ai.client, &completion())
+        .unwrap();
+    let Err(OpenaiError {
+        kind: ErrorKind::ServerError(error),
+        ..
+    }) = result
+    else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert_eq!(error.status, 500);
+    assert_eq!(error.type_.as_deref(), Some("server_error"));
+}
+
+#[test]
+fn test_api_key_from_runtime_config() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/embeddings",
+        MockResponse::fixture(200, "openai/embedding.json"),
+    );
+    let mut ai = connect(&mock, "", &[("openai_api_key", "sk-from-config")]);
+
+    ai.openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_embedding(&mut ai.store, ai.client, &embedding())
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        mock.single_request().header("authorization"),
+        Some("Bearer sk-from-config")
+    );
+}
+
+#[test]
+fn test_retries_rate_limited_request() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/embeddings",
+        MockResponse::fixture(429, "openai/rate_limited.json").header("retry-after-ms", "10"),
+    );
+    mock.on(
+        "POST",
+        "/v1/embeddings",
+        MockResponse::fixture(200, "openai/embedding.json")
+            .header("x-ratelimit-limit-requests", "3000")
+            .header("x-ratelimit-remaining-requests", "2999")
+            .header("x-ratelimit-reset-requests", "20ms")
+            .header("x-ratelimit-limit-tokens", "1000000")
+            .header("x-ratelimit-remaining-tokens", "999992")
+            .header("x-ratelimit-reset-tokens", "0s"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let api = ai.openai.wassemble_openai_api();
+    api.client()
+        .call_create_embedding(&mut ai.store, ai.client, &embedding())
+        .unwrap()
+        .unwrap();
+    assert_eq!(mock.requests().len(), 2);
+
+    let status = api
+        .client()
+        .call_last_rate_limit(&mut ai.store, ai.client)
+        .unwrap()
+        .unwrap();
+    assert_eq!(status.remaining_requests, Some(2999));
+    assert_eq!(status.reset_requests_ms, Some(20));
+    assert_eq!(status.remaining_tokens, Some(999992));
+}
+
+#[test]
+fn test_rate_limited_beyond_max_delay() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(429, "openai/rate_limited.json").header("retry-after", "20"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let result = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_chat_completion(&mut ai.store, ai.client, &completion())
+        .unwrap();
+    let Err(OpenaiError {
+        kind: ErrorKind::RateLimited(rate_limit),
+        ..
+    }) = result
+    else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert!(rate_limit.message.starts_with("Rate limit reached"));
+    assert_eq!(rate_limit.retry_after_ms, Some(20_000));
+    assert_eq!(mock.requests().len(), 1);
+}
+
+fn response_request() -> ResponseRequest {
+    ResponseRequest {
+        model: "o4-mini".to_string(),
+        input: vec![InputItem::Message(InputMessage {
+            role: "user".to_string(),
+            content: MessageContent::Text("What is the weather like in Paris?".to_string()),
+        })],
+        instructions: Some("Answer briefly.".to_string()),
+        previous_response_id: Some(
+            "resp_67ccd2bed1ec8190b14f964abc0542670bb6a6b452d3795b".to_string(),
+        ),
+        tools: vec![ResponseTool::Function(Tool {
+            name: "get_weather".to_string(),
+            description: Some("Current weather of a location".to_string()),
+            parameters: Some(
+                r#"{"type":"object","properties":{"location":{"type":"string"}}}"#.to_string(),
+            ),
+            strict: None,
+        })],
+        tool_choice: None,
+        parallel_tool_calls: None,
+        temperature: None,
+        top_p: None,
+        max_output_tokens: None,
+        reasoning_effort: Some(ReasoningEffort::Low),
+        store: None,
+        metadata: Vec::new(),
+        user: None,
+    }
+}
+
+#[test]
+fn test_create_response() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/responses",
+        MockResponse::fixture(200, "openai/response.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let response = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_response(&mut ai.store, ai.client, &response_request())
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        response.id,
+        "resp_67ccd3a9da748190baa7f1570fe91ac604becb25c45c1d41"
+    );
+    assert_eq!(response.status, "completed");
+    assert_eq!(response.output.len(), 3);
+    assert!(
+        matches!(&response.output[0], OutputItem::Reasoning(reasoning) if reasoning.summary.is_empty())
+    );
+    let OutputItem::FunctionCall(call) = &response.output[1] else {
+        panic!("unexpected output: {:?}", response.output);
+    };
+    assert_eq!(call.call_id, "call_12345xyz");
+    assert_eq!(call.arguments, r#"{"location":"Paris, France"}"#);
+    assert_eq!(response.output_text, "Let me check the weather in Paris.");
+    let usage = response.usage.unwrap();
+    assert_eq!(usage.prompt_tokens, 328);
+    assert_eq!(usage.cached_tokens, Some(256));
+    assert_eq!(usage.reasoning_tokens, Some(24));
+
+    let request = mock.single_request();
+    assert_eq!(request.header("authorization"), Some("Bearer sk-test"));
+    let body = request.json();
+    assert_eq!(body["instructions"], "Answer briefly.");
+    assert_eq!(
+        body["previous_response_id"],
+        "resp_67ccd2bed1ec8190b14f964abc0542670bb6a6b452d3795b"
+    );
+    assert_eq!(body["input"][0]["type"], "message");
+    assert_eq!(
+        body["input"][0]["content"],
+        "What is the weather like in Paris?"
+    );
+    assert_eq!(body["tools"][0]["name"], "get_weather");
+    assert_eq!(body["reasoning"]["effort"], "low");
+}
+
+#[test]
+fn test_retrieve_and_delete_response() {
+    let id = "resp_67ccd3a9da748190baa7f1570fe91ac604becb25c45c1d41";
+    let path = format!("/v1/responses/{id}");
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        &path,
+        MockResponse::fixture(200, "openai/response.json"),
+    )
+    .on(
+        "DELETE",
+        &path,
+        MockResponse::fixture(200, "openai/response_deleted.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let client = ai.openai.wassemble_openai_api().client();
+    let response = client
+        .call_retrieve_response(&mut ai.store, ai.client, id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(response.id, id);
+    client
+        .call_delete_response(&mut ai.store, ai.client, id)
+        .unwrap()
+        .unwrap();
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[1].method, "DELETE");
+    assert_eq!(requests[1].uri, format!("https://api.openai.com{path}"));
+}
+
+#[test]
+fn test_retrieve_missing_response() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/v1/responses/resp_missing",
+        MockResponse::fixture(404, "openai/response_not_found.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let result = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_retrieve_response(&mut ai.store, ai.client, "resp_missing")
+        .unwrap();
+    let Err(OpenaiError {
+        kind: ErrorKind::NotFound(error),
+        ..
+    }) = result
+    else {
+        panic!("unexpected result: {result:?}");
+    };
+    assert!(error.message.contains("resp_missing"));
+}
+
+#[test]
+fn test_create_moderation() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/moderations",
+        MockResponse::fixture(200, "openai/moderation.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let moderation = Moderation {
+        model: Some("omni-moderation-latest".to_string()),
+        input: ModerationInput::Multimodal(vec![
+            ModerationPart::Text("...text to classify goes here...".to_string()),
+            ModerationPart::ImageUrl("https://example.com/image.png".to_string()),
+        ]),
+    };
+    let response = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_moderation(&mut ai.store, ai.client, &moderation)
+        .unwrap()
+        .unwrap();
+    assert_eq!(response.model, "omni-moderation-2024-09-26");
+    assert_eq!(response.results.len(), 1);
+    let result = &response.results[0];
+    assert!(result.flagged);
+    assert_eq!(result.categories.len(), 13);
+    let violence = result
+        .categories
+        .iter()
+        .find(|category| category.name == "violence")
+        .unwrap();
+    assert!(violence.flagged);
+    assert!(violence.score > 0.9);
+    assert_eq!(violence.applied_input_types, ["text", "image"]);
+
+    let body = mock.single_request().json();
+    assert_eq!(body["model"], "omni-moderation-latest");
+    assert_eq!(body["input"][1]["type"], "image_url");
+    assert_eq!(
+        body["input"][1]["image_url"]["url"],
+        "https://example.com/image.png"
+    );
+}
+
+fn image_generation() -> ImageGeneration {
+    ImageGeneration {
+        model: Some("dall-e-3".to_string()),
+        prompt: "A cat wearing a hat".to_string(),
+        n: None,
+        size: Some("1024x1024".to_string()),
+        quality: Some("hd".to_string()),
+        background: None,
+        output_format: None,
+        response_format: None,
+        user: None,
+    }
+}
+
+#[test]
+fn test_create_image_url() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/images/generations",
+        MockResponse::fixture(200, "openai/image_url.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let response = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_image(&mut ai.store, ai.client, &image_generation())
+        .unwrap()
+        .unwrap();
+    assert_eq!(response.created, 1713833628);
+    let image = &response.images[0];
+    assert!(matches!(&image.data, ImageData::Url(url) if url.ends_with("img-ghi.png")));
+    assert!(
+        image
+            .revised_prompt
+            .as_deref()
+            .unwrap()
+            .starts_with("A watercolor")
+    );
+    assert!(response.usage.is_none());
+
+    let body = mock.single_request().json();
+    assert_eq!(body["model"], "dall-e-3");
+    assert_eq!(body["quality"], "hd");
+    assert!(body.get("n").is_none());
+}
+
+#[test]
+fn test_create_image_bytes() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/images/generations",
+        MockResponse::fixture(200, "openai/image_b64.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let generation = ImageGeneration {
+        model: Some("gpt-image-1".to_string()),
+        quality: Some("low".to_string()),
+        background: Some(ImageBackground::Transparent),
+        output_format: Some(ImageOutputFormat::Png),
+        ..image_generation()
+    };
+    let response = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_image(&mut ai.store, ai.client, &generation)
+        .unwrap()
+        .unwrap();
+    let ImageData::Bytes(bytes) = &response.images[0].data else {
+        panic!("unexpected image: {:?}", response.images[0]);
+    };
+    assert!(bytes.starts_with(b"\x89PNG\r\n"));
+    assert_eq!(response.usage.unwrap().prompt_tokens, 50);
+
+    let body = mock.single_request().json();
+    assert_eq!(body["background"], "transparent");
+    assert_eq!(body["output_format"], "png");
+}
+
+#[test]
+fn test_edit_image() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/images/edits",
+        MockResponse::fixture(200, "openai/image_b64.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let image = FileUpload {
+        filename: "cat.png".to_string(),
+        content_type: "image/png".to_string(),
+        data: b"\x89PNG\r\n\x1a\ncat".to_vec(),
+    };
+    let edit = ImageEdit {
+        model: Some("gpt-image-1".to_string()),
+        prompt: "Add a red hat".to_string(),
+        images: vec![image.clone()],
+        mask: Some(FileUpload {
+            filename: "mask.png".to_string(),
+            ..image
+        }),
+        n: Some(1),
+        size: None,
+        quality: None,
+        background: None,
+        output_format: None,
+        response_format: None,
+        user: None,
+    };
+    let response = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_edit_image(&mut ai.store, ai.client, &edit)
+        .unwrap()
+        .unwrap();
+    assert!(matches!(response.images[0].data, ImageData::Bytes(_)));
+
+    let request = mock.single_request();
+    let content_type = request.header("content-type").unwrap();
+    let boundary = content_type
+        .strip_prefix("multipart/form-data; boundary=")
+        .unwrap();
+    let body = String::from_utf8_lossy(&request.body);
+    assert!(body.starts_with(&format!("--{boundary}\r\n")));
+    assert!(body.ends_with(&format!("--{boundary}--\r\n")));
+    assert!(body.contains("name=\"model\"\r\n\r\ngpt-image-1\r\n"));
+    assert!(body.contains("name=\"prompt\"\r\n\r\nAdd a red hat\r\n"));
+    assert!(body.contains(
+        "name=\"image\"; filename=\"cat.png\"\r\nContent-Type: image/png\r\n\r\n\u{fffd}PNG\r\n\u{1a}\ncat\r\n"
+    ));
+    assert!(body.contains("name=\"mask\"; filename=\"mask.png\""));
+}
+
+fn voice_note() -> FileUpload {
+    FileUpload {
+        filename: "voice-note.ogg".to_string(),
+        content_type: "audio/ogg".to_string(),
+        data: b"OggS\x00\x02voice".to_vec(),
+    }
+}
+
+#[test]
+fn test_create_transcription() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/audio/transcriptions",
+        MockResponse::fixture(200, "openai/transcription_verbose.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let transcription = Transcription {
+        model: "whisper-1".to_string(),
+        file: voice_note(),
+        language: None,
+        prompt: None,
+        response_format: Some(TranscriptionFormat::VerboseJson),
+        temperature: None,
+        timestamp_granularities: vec![TimestampGranularity::Word],
+    };
+    let response = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_transcription(&mut ai.store, ai.client, &transcription)
+        .unwrap()
+        .unwrap();
+    assert_eq!(response.text, "Meet me at the station at noon.");
+    assert_eq!(response.language.as_deref(), Some("english"));
+    assert_eq!(response.words.len(), 7);
+    assert_eq!(response.words[4].word, "station");
+    assert_eq!(response.segments[0].end, 2.0);
+
+    let request = mock.single_request();
+    assert!(
+        request
+            .header("content-type")
+            .unwrap()
+            .starts_with("multipart/form-data; boundary=")
+    );
+    let body = String::from_utf8_lossy(&request.body);
+    assert!(body.contains("name=\"timestamp_granularities[]\"\r\n\r\nword\r\n"));
+    assert!(body.contains("filename=\"voice-note.ogg\"\r\nContent-Type: audio/ogg\r\n"));
+}
+
+#[test]
+fn test_create_translation_as_subtitles() {
+    let vtt = "WEBVTT\n\n00:00:00.000 --> 00:00:02.000\nMeet me at the station at noon.\n";
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/audio/translations",
+        MockResponse::new(200)
+            .header("Content-Type", "text/plain; charset=utf-8")
+            .body(vtt),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let translation = Translation {
+        model: "whisper-1".to_string(),
+        file: voice_note(),
+        prompt: None,
+        response_format: Some(TranscriptionFormat::Vtt),
+        temperature: Some(0.2),
+    };
+    let response = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_translation(&mut ai.store, ai.client, &translation)
+        .unwrap()
+        .unwrap();
+    assert_eq!(response.text, vtt);
+    assert!(response.words.is_empty());
+
+    let body = String::from_utf8_lossy(&mock.single_request().body).into_owned();
+    assert!(body.contains("name=\"response_format\"\r\n\r\nvtt\r\n"));
+    assert!(body.contains("name=\"temperature\"\r\n\r\n0.2\r\n"));
+}
+
+#[test]
+fn test_create_speech() {
+    let audio = b"ID3\x04\x00\x00\x00\x00\x00\x00\xff\xfb\x90\x64".to_vec();
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/audio/speech",
+        MockResponse::new(200)
+            .header("Content-Type", "audio/mpeg")
+            .body(audio.clone()),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let speech = Speech {
+        model: "gpt-4o-mini-tts".to_string(),
+        input: "Your order has shipped.".to_string(),
+        voice: "coral".to_string(),
+        instructions: Some("Speak in a cheerful tone.".to_string()),
+        response_format: Some(SpeechFormat::Mp3),
+        speed: None,
+    };
+    let bytes = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_speech(&mut ai.store, ai.client, &speech)
+        .unwrap()
+        .unwrap();
+    assert_eq!(bytes, audio);
+
+    let body = mock.single_request().json();
+    assert_eq!(body["voice"], "coral");
+    assert_eq!(body["response_format"], "mp3");
+    assert!(body.get("speed").is_none());
+}
+
+#[test]
+fn test_upload_batch_input_and_create_batch() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/files",
+        MockResponse::fixture(200, "openai/file.json"),
+    )
+    .on(
+        "POST",
+        "/v1/batches",
+        MockResponse::fixture(200, "openai/batch.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let client = ai.openai.wassemble_openai_api().client();
+    let content = ai
+        .openai
+        .wassemble_openai_batches()
+        .call_encode_batch_input(
+            &mut ai.store,
+            &[
+                BatchInputLine {
+                    custom_id: "request-1".to_string(),
+                    body: BatchBody::ChatCompletion(completion()),
+                },
+                BatchInputLine {
+                    custom_id: "request-2".to_string(),
+                    body: BatchBody::Embedding(embedding()),
+                },
+            ],
+        )
+        .unwrap()
+        .unwrap();
+    let file = FileUpload {
+        filename: "requests.jsonl".to_string(),
+        content_type: "application/jsonl".to_string(),
+        data: content.clone(),
+    };
+    let file = client
+        .call_upload_file(&mut ai.store, ai.client, &file, "batch")
+        .unwrap()
+        .unwrap();
+    assert_eq!(file.id, "file-abc123");
+    assert_eq!(file.purpose, "batch");
+
+    let request = BatchRequest {
+        input_file_id: file.id,
+        endpoint: BatchEndpoint::ChatCompletions,
+        completion_window: None,
+        metadata: vec![("customer_id".to_string(), "user_123456789".to_string())],
+    };
+    let batch = client
+        .call_create_batch(&mut ai.store, ai.client, &request)
+        .unwrap()
+        .unwrap();
+    assert_eq!(batch.id, "batch_abc123");
+    assert_eq!(batch.output_file_id.as_deref(), Some("file-cvaTdG"));
+
+    let requests = mock.requests();
+    assert_eq!(requests.len(), 2);
+    let upload = String::from_utf8_lossy(&requests[0].body);
+    assert!(upload.contains("name=\"purpose\"\r\n\r\nbatch\r\n"));
+    assert!(upload.contains("name=\"file\"; filename=\"requests.jsonl\""));
+    assert!(upload.contains(std::str::from_utf8(&content).unwrap()));
+    let lines: Vec<&str> = std::str::from_utf8(&content).unwrap().lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].contains(r#""url":"/v1/chat/completions""#));
+    assert!(lines[1].contains(r#""url":"/v1/embeddings""#));
+    assert_eq!(
+        requests[1].json(),
+        serde_json::json!({
+            "input_file_id": "file-abc123",
+            "endpoint": "/v1/chat/completions",
+            "completion_window": "24h",
+            "metadata": {"customer_id": "user_123456789"}
+        })
+    );
+}
+
+#[test]
+fn test_retrieve_batch_output() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/v1/batches/batch_abc123",
+        MockResponse::fixture(200, "openai/batch.json"),
+    )
+    .on(
+        "GET",
+        "/v1/files/file-cvaTdG/content",
+        MockResponse::fixture(200, "openai/batch_output.jsonl"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let client = ai.openai.wassemble_openai_api().client();
+    let batch = client
+        .call_retrieve_batch(&mut ai.store, ai.client, "batch_abc123")
+        .unwrap()
+        .unwrap();
+    assert_eq!(batch.status, "completed");
+    assert_eq!(batch.completed_at, Some(1711493163));
+    let counts = batch.request_counts.unwrap();
+    assert_eq!(
+        (counts.total, counts.completed, counts.failed),
+        (100, 95, 5)
+    );
+    assert!(batch.errors.is_empty());
+
+    let content = client
+        .call_retrieve_file_content(&mut ai.store, ai.client, &batch.output_file_id.unwrap())
+        .unwrap()
+        .unwrap();
+    let lines = ai
+        .openai
+        .wassemble_openai_batches()
+        .call_decode_batch_output(&mut ai.store, &content)
+        .unwrap()
+        .unwrap();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].custom_id, "request-1");
+    let BatchResult::ChatCompletion(response) = &lines[0].outcome else {
+        panic!("unexpected outcome: {:?}", lines[0].outcome);
+    };
+    assert_eq!(response.content, "Hello there!");
+    assert_eq!(response.usage.as_ref().unwrap().total_tokens, 25);
+    assert!(matches!(
+        &lines[1].outcome,
+        BatchResult::Error(OpenaiError { kind: ErrorKind::InsufficientQuota(error), .. }) if error.status == 429
+    ));
+}
+
+#[test]
+fn test_list_and_cancel_batches() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/v1/batches",
+        MockResponse::fixture(200, "openai/batch_list.json"),
+    )
+    .on(
+        "POST",
+        "/v1/batches/batch_def456/cancel",
+        MockResponse::fixture(200, "openai/batch_failed.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let client = ai.openai.wassemble_openai_api().client();
+    let list = client
+        .call_list_batches(&mut ai.store, ai.client, Some("batch_xyz"), Some(2))
+        .unwrap()
+        .unwrap();
+    assert_eq!(list.batches.len(), 2);
+    assert!(list.has_more);
+    assert_eq!(list.last_id.as_deref(), Some("batch_def456"));
+    assert_eq!(list.batches[0].metadata.len(), 2);
+
+    let batch = client
+        .call_cancel_batch(&mut ai.store, ai.client, "batch_def456")
+        .unwrap()
+        .unwrap();
+    assert_eq!(batch.status, "failed");
+    assert!(batch.metadata.is_empty());
+    assert_eq!(batch.errors[0].code.as_deref(), Some("invalid_json_line"));
+    assert_eq!(batch.errors[0].line, Some(3));
+
+    let requests = mock.requests();
+    assert_eq!(
+        requests[0].uri,
+        "https://api.openai.com/v1/batches?after=batch_xyz&limit=2"
+    );
+    assert_eq!(requests[1].method, "POST");
+}
+
+#[test]
+fn test_list_models() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/v1/models",
+        MockResponse::fixture(200, "openai/models.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let models = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_list_models(&mut ai.store, ai.client)
+        .unwrap()
+        .unwrap();
+    assert_eq!(models.len(), 3);
+    assert_eq!(models[0].id, "gpt-4o-mini");
+    assert_eq!(models[0].created, 1721172741);
+    assert_eq!(models[2].owned_by, "acme");
+}
+
+#[test]
+fn test_retrieve_model() {
+    let mock = MockServer::new();
+    mock.on(
+        "GET",
+        "/v1/models/gpt-4o-mini",
+        MockResponse::fixture(200, "openai/model.json"),
+    )
+    .on(
+        "GET",
+        "/v1/models/gpt-5-turbo",
+        MockResponse::fixture(404, "openai/model_not_found.json"),
+    )
+    .on(
+        "GET",
+        "/v1/models/ft%3Agpt-4o-mini-2024-07-18%3Aacme%3A%3A9w3xYz1A",
+        MockResponse::fixture(200, "openai/model.json"),
+    );
+    let mut ai = connect(&mock, "sk-test", &[]);
+
+    let client = ai.openai.wassemble_openai_api().client();
+    let model = client
+        .call_retrieve_model(&mut ai.store, ai.client, "gpt-4o-mini")
+        .unwrap()
+        .unwrap();
+    assert_eq!(model.id, "gpt-4o-mini");
+    assert_eq!(model.owned_by, "system");
+
+    let error = client
+        .call_retrieve_model(&mut ai.store, ai.client, "gpt-5-turbo")
+        .unwrap()
+        .unwrap_err();
+    assert!(
+        matches!(&error, OpenaiError { kind: ErrorKind::NotFound(error), .. } if error.code.as_deref() == Some("model_not_found"))
+    );
+
+    client
+        .call_retrieve_model(
+            &mut ai.store,
+            ai.client,
+            "ft:gpt-4o-mini-2024-07-18:acme::9w3xYz1A",
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        mock.requests()[2].uri,
+        "https://api.openai.com/v1/models/ft%3Agpt-4o-mini-2024-07-18%3Aacme%3A%3A9w3xYz1A"
+    );
+}
+
+fn azure_config(entra_id: bool) -> Config {
+    Config {
+        azure: Some(AzureConfig {
+            endpoint: "https://acme.openai.azure.com".to_string(),
+            api_version: "2024-10-21".to_string(),
+            entra_id,
+        }),
+        ..config("azure-key")
+    }
+}
+
+#[test]
+fn test_azure_chat_completion() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/openai/deployments/gpt-4.1/chat/completions",
+        MockResponse::fixture(200, "openai/chat_completion.json"),
+    );
+    let mut ai = connect_with(&mock, &azure_config(false), &[]);
+
+    ai.openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_chat_completion(&mut ai.store, ai.client, &completion())
+        .unwrap()
+        .unwrap();
+
+    let request = mock.single_request();
+    assert_eq!(
+        request.uri,
+        "https://acme.openai.azure.com/openai/deployments/gpt-4.1/chat/completions?api-version=2024-10-21"
+    );
+    assert_eq!(request.header("api-key"), Some("azure-key"));
+    assert_eq!(request.header("authorization"), None);
+}
+
+#[test]
+fn test_azure_embedding_with_entra_id() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/openai/deployments/text-embedding-3-small/embeddings",
+        MockResponse::fixture(200, "openai/embedding.json"),
+    );
+    let mut ai = connect_with(&mock, &azure_config(true), &[]);
+
+    ai.openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_embedding(&mut ai.store, ai.client, &embedding())
+        .unwrap()
+        .unwrap();
+
+    let request = mock.single_request();
+    assert_eq!(
+        request.uri,
+        "https://acme.openai.azure.com/openai/deployments/text-embedding-3-small/embeddings?api-version=2024-10-21"
+    );
+    assert_eq!(request.header("authorization"), Some("Bearer azure-key"));
+    assert_eq!(request.header("api-key"), None);
+}
+
+#[test]
+fn test_azure_image_and_batch_routes() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/openai/deployments/dall-e-3/images/generations",
+        MockResponse::fixture(200, "openai/image_url.json"),
+    )
+    .on(
+        "GET",
+        "/openai/batches/batch_abc123",
+        MockResponse::fixture(200, "openai/batch.json"),
+    );
+    let mut ai = connect_with(&mock, &azure_config(false), &[]);
+
+    let client = ai.openai.wassemble_openai_api().client();
+    client
+        .call_create_image(&mut ai.store, ai.client, &image_generation())
+        .unwrap()
+        .unwrap();
+    client
+        .call_retrieve_batch(&mut ai.store, ai.client, "batch_abc123")
+        .unwrap()
+        .unwrap();
+
+    let requests = mock.requests();
+    assert_eq!(
+        requests[0].uri,
+        "https://acme.openai.azure.com/openai/deployments/dall-e-3/images/generations?api-version=2024-10-21"
+    );
+    assert_eq!(
+        requests[1].uri,
+        "https://acme.openai.azure.com/openai/batches/batch_abc123?api-version=2024-10-21"
+    );
+}
+
+#[test]
+fn test_azure_moderation_is_unsupported() {
+    let mock = MockServer::new();
+    let mut ai = connect_with(&mock, &azure_config(false), &[]);
+
+    let moderation = Moderation {
+        model: None,
+        input: ModerationInput::Texts(vec!["...text to classify goes here...".to_string()]),
+    };
+    let error = ai
+        .openai
+        .wassemble_openai_api()
+        .client()
+        .call_create_moderation(&mut ai.store, ai.client, &moderation)
+        .unwrap()
+        .unwrap_err();
+    assert!(matches!(error.kind, ErrorKind::Unsupported(_)));
+    assert!(mock.requests().is_empty());
+}
+
+#[test]
+fn test_scoping_headers_and_request_ids() {
+    let mock = MockServer::new();
+    mock.on(
+        "POST",
+        "/v1/chat/completions",
+        MockResponse::fixture(200, "openai/chat_completion.json")
+            .header("x-request-id", "req_0f9a7c3b"),
+    )
+    .on(
+        "POST",
+        "/v1/embeddings",
+        MockResponse::fixture(404, "openai/model_not_found.json")
+            .header("x-request-id", "req_5d2e81aa"),
+    );
+    let config = Config {
+        organization: Some("org-acme".to_string()),
+        project: Some("proj_billing".to_string()),
+        ..config("sk-test")
+    };
+    let mut ai = connect_with(&mock, &config, &[]);
+
+    let client = ai.openai.wassemble_openai_api().client();
+    let completion = ChatCompletion {
+        client_request_id: Some("job-42".to_string()),
+        ..completion()
+    };
+    let response = client
+        .call_create_chat_completion(&mut ai.store, ai.client, &completion)
+        .unwrap()
+        .unwrap();
+    assert_eq!(response.request_id.as_deref(), Some("req_0f9a7c3b"));
+
+    let error = client
+        .call_create_embedding(&mut ai.store, ai.client, &embedding())
+        .unwrap()
+        .unwrap_err();
+    assert!(
+        matches!(&error.kind, ErrorKind::NotFound(error) if error.code.as_deref() == Some("model_not_found"))
+    );
+    assert_eq!(error.request_id.as_deref(), Some("req_5d2e81aa"));
+    assert_eq!(
+        client
+            .call_last_request_id(&mut ai.store, ai.client)
+            .unwrap()
+            .as_deref(),
+        Some("req_5d2e81aa")
+    );
+
+    let requests = mock.requests();
+    for request in &requests {
+        assert_eq!(request.header("openai-organization"), Some("org-acme"));
+        assert_eq!(request.header("openai-project"), Some("proj_billing"));
+    }
+    assert_eq!(requests[0].header("x-client-request-id"), Some("job-42"));
+    assert_eq!(requests[1].header("x-client-request-id"), None);
+}
@@ -0,0 +1,225 @@
+use http_core::{Form, Response};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bindings::wassemble::openai::types::{
+        ErrorKind, Speech, SpeechFormat, TimestampGranularity, Transcription, TranscriptionFormat,
+        TranscriptionResponse, TranscriptionSegment, TranscriptionWord, Translation,
+    },
+    form_fields, upload,
+};
+
+pub(crate) fn transcription_form(transcription: &Transcription) -> Form {
+    let mut form = form_fields([
+        ("model", Some(transcription.model.clone())),
+        ("language", transcription.language.clone()),
+        ("prompt", transcription.prompt.clone()),
+        (
+            "response_format",
+            transcription
+                .response_format
+                .map(transcription_format)
+                .map(String::from),
+        ),
+        (
+            "temperature",
+            transcription.temperature.map(|t| t.to_string()),
+        ),
+    ]);
+    for granularity in &transcription.timestamp_granularities {
+        let granularity = match granularity {
+            TimestampGranularity::Word => "word",
+            TimestampGranularity::Segment => "segment",
+        };
+        form = form.text("timestamp_granularities[]", granularity);
+    }
+    upload(form, "file", &transcription.file)
+}
+
+pub(crate) fn translation_form(translation: &Translation) -> Form {
+    let form = form_fields([
+        ("model", Some(translation.model.clone())),
+        ("prompt", translation.prompt.clone()),
+        (
+            "response_format",
+            translation
+                .response_format
+                .map(transcription_format)
+                .map(String::from),
+        ),
+        (
+            "temperature",
+            translation.temperature.map(|t| t.to_string()),
+        ),
+    ]);
+    upload(form, "file", &translation.file)
+}
+
+fn transcription_format(format: TranscriptionFormat) -> &'static str {
+    match format {
+        TranscriptionFormat::Json => "json",
+        TranscriptionFormat::Text => "text",
+        TranscriptionFormat::Srt => "srt",
+        TranscriptionFormat::VerboseJson => "verbose_json",
+        TranscriptionFormat::Vtt => "vtt",
+    }
+}
+
+/// Reads a transcription, which is only JSON for the JSON formats.
+pub(crate) fn parse_transcription(
+    response: Response,
+    format: Option<TranscriptionFormat>,
+) -> Result<TranscriptionResponse, ErrorKind> {
+    match format {
+        None | Some(TranscriptionFormat::Json | TranscriptionFormat::VerboseJson) => {
+            Ok(response.json::<OpenAITranscription>()?.into())
+        }
+        Some(TranscriptionFormat::Text | TranscriptionFormat::Srt | TranscriptionFormat::Vtt) => {
+            Ok(TranscriptionResponse {
+                text: response.text()?.to_string(),
+                language: None,
+                duration: None,
+                words: Vec::new(),
+                segments: Vec::new(),
+            })
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAITranscription {
+    text: String,
+    language: Option<String>,
+    duration: Option<f64>,
+    #[serde(default)]
+    words: Vec<OpenAITranscriptionWord>,
+    #[serde(default)]
+    segments: Vec<OpenAITranscriptionSegment>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAITranscriptionWord {
+    word: String,
+    start: f64,
+    end: f64,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAITranscriptionSegment {
+    id: u32,
+    start: f64,
+    end: f64,
+    text: String,
+}
+
+impl From<OpenAITranscription> for TranscriptionResponse {
+    fn from(transcription: OpenAITranscription) -> Self {
+        TranscriptionResponse {
+            text: transcription.text,
+            language: transcription.language,
+            duration: transcription.duration,
+            words: transcription
+                .words
+                .into_iter()
+                .map(|word| TranscriptionWord {
+                    word: word.word,
+                    start: word.start,
+                    end: word.end,
+                })
+                .collect(),
+            segments: transcription
+                .segments
+                .into_iter()
+                .map(|segment| TranscriptionSegment {
+                    id: segment.id,
+                    start: segment.start,
+                    end: segment.end,
+                    text: segment.text,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct SerializableSpeech<'a> {
+    model: &'a str,
+    input: &'a str,
+    voice: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instructions: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    speed: Option<f64>,
+}
+
+impl<'a> SerializableSpeech<'a> {
+    pub(crate) fn new(speech: &'a Speech) -> Self {
+        Self {
+            model: &speech.model,
+            input: &speech.input,
+            voice: &speech.voice,
+            instructions: speech.instructions.as_deref(),
+            response_format: speech.response_format.map(|format| match format {
+                SpeechFormat::Mp3 => "mp3",
+                SpeechFormat::Opus => "opus",
+                SpeechFormat::Aac => "aac",
+                SpeechFormat::Flac => "flac",
+                SpeechFormat::Wav => "wav",
+                SpeechFormat::Pcm => "pcm",
+            }),
+            speed: speech.speed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http_core::HeaderMap;
+
+    use super::*;
+    use crate::bindings::wassemble::openai::types::FileUpload;
+
+    fn transcription() -> Transcription {
+        Transcription {
+            model: "whisper-1".to_string(),
+            file: FileUpload {
+                filename: "voice-note.ogg".to_string(),
+                content_type: "audio/ogg".to_string(),
+                data: b"OggS".to_vec(),
+            },
+            language: Some("en".to_string()),
+            prompt: None,
+            response_format: Some(TranscriptionFormat::VerboseJson),
+            temperature: None,
+            timestamp_granularities: vec![
+                TimestampGranularity::Word,
+                TimestampGranularity::Segment,
+            ],
+        }
+    }
+
+    #[test]
+    fn test_transcription_form() {
+        let body = String::from_utf8(transcription_form(&transcription()).encode().0).unwrap();
+        assert!(body.contains("name=\"model\"\r\n\r\nwhisper-1\r\n"));
+        assert!(body.contains("name=\"language\"\r\n\r\nen\r\n"));
+        assert!(body.contains("name=\"response_format\"\r\n\r\nverbose_json\r\n"));
+        assert!(body.contains("name=\"timestamp_granularities[]\"\r\n\r\nword\r\n"));
+        assert!(body.contains("name=\"timestamp_granularities[]\"\r\n\r\nsegment\r\n"));
+        assert!(body.contains(
+            "name=\"file\"; filename=\"voice-note.ogg\"\r\nContent-Type: audio/ogg\r\n\r\nOggS\r\n"
+        ));
+        assert!(!body.contains("name=\"prompt\""));
+    }
+
+    #[test]
+    fn test_parse_subtitles() {
+        let srt = "1\n00:00:00,000 --> 00:00:01,500\nHello there.\n";
+        let response = Response::new(200, HeaderMap::new(), srt.as_bytes().to_vec());
+        let transcription = parse_transcription(response, Some(TranscriptionFormat::Srt)).unwrap();
+        assert_eq!(transcription.text, srt);
+        assert!(transcription.segments.is_empty());
+    }
+}
@@ -0,0 +1,452 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    Component, OpenAIChatResponse, OpenAIEmbeddingResponse, OpenAIErrorBody,
+    SerializableChatCompletion, SerializableEmbedding,
+    bindings::{
+        exports::wassemble::openai::batches::Guest,
+        wassemble::openai::types::{
+            Batch, BatchBody, BatchEndpoint, BatchError, BatchInputLine, BatchList,
+            BatchOutputLine, BatchRequest, BatchRequestCounts, BatchResult, ChatResponse,
+            EmbeddingResponse, ErrorKind, FileObject, OpenaiError,
+        },
+    },
+};
+
+#[derive(Serialize)]
+pub(crate) struct SerializableBatchRequest<'a> {
+    input_file_id: &'a str,
+    endpoint: &'static str,
+    completion_window: &'a str,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    metadata: BTreeMap<&'a str, &'a str>,
+}
+
+impl<'a> SerializableBatchRequest<'a> {
+    pub(crate) fn new(request: &'a BatchRequest) -> Self {
+        Self {
+            input_file_id: &request.input_file_id,
+            endpoint: endpoint_url(request.endpoint),
+            completion_window: request.completion_window.as_deref().unwrap_or("24h"),
+            metadata: request
+                .metadata
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.as_str()))
+                .collect(),
+        }
+    }
+}
+
+fn endpoint_url(endpoint: BatchEndpoint) -> &'static str {
+    match endpoint {
+        BatchEndpoint::ChatCompletions => "/v1/chat/completions",
+        BatchEndpoint::Embeddings => "/v1/embeddings",
+    }
+}
+
+#[derive(Serialize)]
+struct SerializableBatchInputLine<'a> {
+    custom_id: &'a str,
+    method: &'static str,
+    url: &'static str,
+    body: SerializableBatchBody<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum SerializableBatchBody<'a> {
+    ChatCompletion(Box<SerializableChatCompletion<'a>>),
+    Embedding(SerializableEmbedding<'a>),
+}
+
+/// Writes one request per line, in the format of a batch input file.
+pub(crate) fn encode_input(lines: &[BatchInputLine]) -> Result<Vec<u8>, ErrorKind> {
+    let mut content = Vec::new();
+    for line in lines {
+        let (url, body) = match &line.body {
+            BatchBody::ChatCompletion(completion) => (
+                endpoint_url(BatchEndpoint::ChatCompletions),
+                SerializableBatchBody::ChatCompletion(Box::new(SerializableChatCompletion::new(
+                    completion,
+                )?)),
+            ),
+            BatchBody::Embedding(embedding) => (
+                endpoint_url(BatchEndpoint::Embeddings),
+                SerializableBatchBody::Embedding(SerializableEmbedding {
+                    model: &embedding.model,
+                    input: &embedding.input,
+                }),
+            ),
+        };
+        let line = SerializableBatchInputLine {
+            custom_id: &line.custom_id,
+            method: "POST",
+            url,
+            body,
+        };
+        serde_json::to_writer(&mut content, &line)
+            .map_err(|e| ErrorKind::JsonParseError(e.to_string()))?;
+        content.push(b'\n');
+    }
+    Ok(content)
+}
+
+#[derive(Deserialize)]
+struct OpenAIBatchOutputLine {
+    custom_id: String,
+    response: Option<OpenAIBatchResponse>,
+    error: Option<OpenAIBatchLineError>,
+}
+
+#[derive(Deserialize)]
+struct OpenAIBatchResponse {
+    status_code: u16,
+    request_id: Option<String>,
+    body: Value,
+}
+
+#[derive(Deserialize)]
+struct OpenAIBatchLineError {
+    code: Option<String>,
+    message: String,
+}
+
+/// Output lines do not name their endpoint, so the body is told apart by its fields.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OpenAIBatchBody {
+    ChatCompletion(OpenAIChatResponse),
+    Embedding(OpenAIEmbeddingResponse),
+}
+
+/// Reads the lines of a batch output or error file, skipping blank lines.
+pub(crate) fn decode_output(content: &[u8]) -> Result<Vec<BatchOutputLine>, ErrorKind> {
+    let content = std::str::from_utf8(content).map_err(|e| ErrorKind::Utf8Error(e.to_string()))?;
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let line: OpenAIBatchOutputLine = serde_json::from_str(line).map_err(|e| {
+                ErrorKind::JsonParseError(format!("Invalid batch output line {}: {e}", index + 1))
+            })?;
+            Ok(BatchOutputLine {
+                outcome: line.outcome(),
+                custom_id: line.custom_id,
+            })
+        })
+        .collect()
+}
+
+impl OpenAIBatchOutputLine {
+    /// Converts the response of the line, or the error of a request that was never sent.
+    fn outcome(&self) -> BatchResult {
+        let response = match (&self.response, &self.error) {
+            (Some(response), _) => response,
+            (None, Some(error)) => {
+                return BatchResult::Error(
+                    ErrorKind::RequestFailed(match &error.code {
+                        Some(code) => format!("{code}: {}", error.message),
+                        None => error.message.clone(),
+                    })
+                    .into(),
+                );
+            }
+            (None, None) => {
+                return BatchResult::Error(
+                    ErrorKind::RequestFailed(
+                        "Batch line has neither response nor error".to_string(),
+                    )
+                    .into(),
+                );
+            }
+        };
+        response.outcome().unwrap_or_else(|kind| {
+            BatchResult::Error(OpenaiError {
+                kind,
+                request_id: response.request_id.clone(),
+            })
+        })
+    }
+}
+
+impl OpenAIBatchResponse {
+    /// Converts the response, leaving it to the caller to tag an error with the
+    /// request ID.
+    fn outcome(&self) -> Result<BatchResult, ErrorKind> {
+        if !(200..300).contains(&self.status_code) {
+            return Err(
+                match serde_json::from_value::<OpenAIErrorBody>(self.body.clone()) {
+                    Ok(body) => body.error.into_error(self.status_code, None),
+                    Err(_) => ErrorKind::RequestFailed(format!(
+                        "HTTP {}: {}",
+                        self.status_code, self.body
+                    )),
+                },
+            );
+        }
+        let request_id = self.request_id.clone();
+        match serde_json::from_value(self.body.clone()) {
+            Ok(OpenAIBatchBody::ChatCompletion(body)) => {
+                ChatResponse::try_from(body).map(|response| {
+                    BatchResult::ChatCompletion(ChatResponse {
+                        request_id,
+                        ..response
+                    })
+                })
+            }
+            Ok(OpenAIBatchBody::Embedding(body)) => {
+                EmbeddingResponse::try_from(body).map(|response| {
+                    BatchResult::Embedding(EmbeddingResponse {
+                        request_id,
+                        ..response
+                    })
+                })
+            }
+            Err(e) => Err(ErrorKind::JsonParseError(e.to_string())),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub(crate) struct OpenAIFile {
+    id: String,
+    bytes: u64,
+    created_at: u64,
+    filename: String,
+    purpose: String,
+}
+
+impl From<OpenAIFile> for FileObject {
+    fn from(file: OpenAIFile) -> Self {
+        FileObject {
+            id: file.id,
+            bytes: file.bytes,
+            created_at: file.created_at,
+            filename: file.filename,
+            purpose: file.purpose,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub(crate) struct OpenAIBatch {
+    id: String,
+    endpoint: String,
+    input_file_id: String,
+    status: String,
+    output_file_id: Option<String>,
+    error_file_id: Option<String>,
+    created_at: u64,
+    completed_at: Option<u64>,
+    expires_at: Option<u64>,
+    request_counts: Option<OpenAIBatchRequestCounts>,
+    metadata: Option<BTreeMap<String, String>>,
+    errors: Option<OpenAIBatchErrors>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAIBatchRequestCounts {
+    total: u32,
+    completed: u32,
+    failed: u32,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAIBatchErrors {
+    #[serde(default)]
+    data: Vec<OpenAIBatchError>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAIBatchError {
+    code: Option<String>,
+    message: String,
+    line: Option<u32>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub(crate) struct OpenAIBatchList {
+    data: Vec<OpenAIBatch>,
+    #[serde(default)]
+    has_more: bool,
+    last_id: Option<String>,
+}
+
+impl From<OpenAIBatchList> for BatchList {
+    fn from(list: OpenAIBatchList) -> Self {
+        BatchList {
+            batches: list.data.into_iter().map(Into::into).collect(),
+            has_more: list.has_more,
+            last_id: list.last_id,
+        }
+    }
+}
+
+impl From<OpenAIBatch> for Batch {
+    fn from(batch: OpenAIBatch) -> Self {
+        Batch {
+            id: batch.id,
+            endpoint: batch.endpoint,
+            input_file_id: batch.input_file_id,
+            status: batch.status,
+            output_file_id: batch.output_file_id,
+            error_file_id: batch.error_file_id,
+            created_at: batch.created_at,
+            completed_at: batch.completed_at,
+            expires_at: batch.expires_at,
+            request_counts: batch.request_counts.map(|counts| BatchRequestCounts {
+                total: counts.total,
+                completed: counts.completed,
+                failed: counts.failed,
+            }),
+            metadata: batch.metadata.unwrap_or_default().into_iter().collect(),
+            errors: batch
+                .errors
+                .map(|errors| errors.data)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|error| BatchError {
+                    code: error.code,
+                    message: error.message,
+                    line: error.line,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Guest for Component {
+    fn encode_batch_input(lines: Vec<BatchInputLine>) -> Result<Vec<u8>, OpenaiError> {
+        Ok(encode_input(&lines)?)
+    }
+
+    fn decode_batch_output(content: Vec<u8>) -> Result<Vec<BatchOutputLine>, OpenaiError> {
+        Ok(decode_output(&content)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::wassemble::openai::types::{
+        ChatCompletion, ChatMessage, Embedding, MessageContent,
+    };
+
+    fn completion() -> ChatCompletion {
+        ChatCompletion {
+            id: "chat-1".to_string(),
+            model: "gpt-4o-mini".to_string(),
+            messages: vec![ChatMessage {
+                role: "user".to_string(),
+                content: MessageContent::Text("Hello".to_string()),
+                tool_calls: Vec::new(),
+                tool_call_id: None,
+            }],
+            temperature: None,
+            max_tokens: Some(10),
+            max_completion_tokens: None,
+            top_p: None,
+            stop: Vec::new(),
+            seed: None,
+            presence_penalty: None,
+            frequency_penalty: None,
+            logit_bias: Vec::new(),
+            reasoning_effort: None,
+            user: None,
+            tools: Vec::new(),
+            tool_choice: None,
+            parallel_tool_calls: None,
+            response_format: None,
+            n: None,
+            logprobs: None,
+            top_logprobs: None,
+            store: None,
+            metadata: Vec::new(),
+            client_request_id: None,
+        }
+    }
+
+    #[test]
+    fn test_encode_input() {
+        let content = encode_input(&[
+            BatchInputLine {
+                custom_id: "chat-1".to_string(),
+                body: BatchBody::ChatCompletion(completion()),
+            },
+            BatchInputLine {
+                custom_id: "embedding-1".to_string(),
+                body: BatchBody::Embedding(Embedding {
+                    model: "text-embedding-3-small".to_string(),
+                    input: "Hello".to_string(),
+                    client_request_id: None,
+                }),
+            },
+        ])
+        .unwrap();
+        let lines: Vec<Value> = String::from_utf8(content)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                serde_json::json!({
+                    "custom_id": "chat-1",
+                    "method": "POST",
+                    "url": "/v1/chat/completions",
+                    "body": {
+                        "model": "gpt-4o-mini",
+                        "messages": [{"role": "user", "content": "Hello"}],
+                        "max_tokens": 10
+                    }
+                }),
+                serde_json::json!({
+                    "custom_id": "embedding-1",
+                    "method": "POST",
+                    "url": "/v1/embeddings",
+                    "body": {"model": "text-embedding-3-small", "input": "Hello"}
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_output() {
+        let content = concat!(
+            r#"{"id":"batch_req_1","custom_id":"chat-1","response":{"status_code":200,"request_id":"req_1","body":{"id":"chatcmpl-1","object":"chat.completion","created":1711652795,"model":"gpt-4o-mini","choices":[{"index":0,"message":{"role":"assistant","content":"Hi!"},"finish_reason":"stop"}]}},"error":null}"#,
+            "\n",
+            r#"{"id":"batch_req_2","custom_id":"embedding-1","response":{"status_code":200,"request_id":"req_2","body":{"object":"list","model":"text-embedding-3-small","data":[{"object":"embedding","index":0,"embedding":[0.5,-0.25]}]}},"error":null}"#,
+            "\n",
+            r#"{"id":"batch_req_3","custom_id":"chat-2","response":{"status_code":400,"request_id":"req_3","body":{"error":{"message":"Invalid model","type":"invalid_request_error","param":"model","code":"model_not_found"}}},"error":null}"#,
+            "\n",
+            r#"{"id":"batch_req_4","custom_id":"chat-3","response":null,"error":{"code":"batch_expired","message":"This request could not be executed before the completion window expired."}}"#,
+            "\n\n",
+        );
+        let lines = decode_output(content.as_bytes()).unwrap();
+        assert_eq!(lines.len(), 4);
+
+        assert_eq!(lines[0].custom_id, "chat-1");
+        assert!(
+            matches!(&lines[0].outcome, BatchResult::ChatCompletion(response) if response.content == "Hi!" && response.request_id.as_deref() == Some("req_1"))
+        );
+        assert!(
+            matches!(&lines[1].outcome, BatchResult::Embedding(response) if response.embedding == [0.5, -0.25])
+        );
+        assert!(
+            matches!(&lines[2].outcome, BatchResult::Error(OpenaiError { kind: ErrorKind::InvalidRequest(error), request_id }) if error.code.as_deref() == Some("model_not_found") && request_id.as_deref() == Some("req_3"))
+        );
+        assert!(
+            matches!(&lines[3].outcome, BatchResult::Error(OpenaiError { kind: ErrorKind::RequestFailed(message), request_id: None }) if message.starts_with("batch_expired: "))
+        );
+
+        assert!(matches!(
+            decode_output(b"{\"custom_id\":\"chat-1\"}\nnot json\n"),
+            Err(ErrorKind::JsonParseError(message)) if message.starts_with("Invalid batch output line 2")
+        ));
+    }
+}
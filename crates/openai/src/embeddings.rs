@@ -0,0 +1,178 @@
+use std::ops::Range;
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use serde::{Deserialize, Serialize};
+
+use crate::bindings::wassemble::openai::types::{EmbeddingUsage, ErrorKind};
+
+/// Most inputs the API accepts in one request.
+pub(crate) const MAX_INPUTS_PER_REQUEST: u32 = 2048;
+
+/// Most tokens the API accepts in one request, summed over its inputs.
+pub(crate) const MAX_TOKENS_PER_REQUEST: u32 = 300_000;
+
+#[derive(Serialize)]
+pub(crate) struct SerializableEmbeddingBatch<'a> {
+    pub(crate) model: &'a str,
+    pub(crate) input: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) dimensions: Option<u32>,
+    pub(crate) encoding_format: &'static str,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct OpenAIEmbeddingBatchResponse {
+    pub(crate) model: String,
+    pub(crate) data: Vec<OpenAIEmbeddingVector>,
+    pub(crate) usage: Option<OpenAIEmbeddingUsage>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct OpenAIEmbeddingVector {
+    pub(crate) index: u32,
+    pub(crate) embedding: OpenAIEmbeddingValue,
+}
+
+/// Servers that ignore `encoding_format` send plain numbers instead of base64.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum OpenAIEmbeddingValue {
+    Base64(String),
+    Floats(Vec<f32>),
+}
+
+#[derive(Default, Deserialize)]
+pub(crate) struct OpenAIEmbeddingUsage {
+    prompt_tokens: u32,
+    total_tokens: u32,
+}
+
+impl OpenAIEmbeddingUsage {
+    /// Adds the usage of another request, saturating at `u32::MAX` for huge batches.
+    pub(crate) fn add(&mut self, other: &OpenAIEmbeddingUsage) {
+        self.prompt_tokens = self.prompt_tokens.saturating_add(other.prompt_tokens);
+        self.total_tokens = self.total_tokens.saturating_add(other.total_tokens);
+    }
+}
+
+impl From<OpenAIEmbeddingUsage> for EmbeddingUsage {
+    fn from(usage: OpenAIEmbeddingUsage) -> Self {
+        EmbeddingUsage {
+            prompt_tokens: usage.prompt_tokens,
+            total_tokens: usage.total_tokens,
+        }
+    }
+}
+
+impl OpenAIEmbeddingValue {
+    /// Decodes a base64 vector, made of little-endian `f32`s.
+    pub(crate) fn decode(self) -> Result<Vec<f32>, ErrorKind> {
+        let bytes = match self {
+            OpenAIEmbeddingValue::Floats(floats) => return Ok(floats),
+            OpenAIEmbeddingValue::Base64(encoded) => STANDARD
+                .decode(encoded)
+                .map_err(|e| ErrorKind::JsonParseError(format!("Invalid base64 embedding: {e}")))?,
+        };
+        let (floats, rest) = bytes.as_chunks::<4>();
+        if !rest.is_empty() {
+            return Err(ErrorKind::JsonParseError(format!(
+                "Base64 embedding of {} bytes is not a list of 32-bit floats",
+                bytes.len()
+            )));
+        }
+        Ok(floats
+            .iter()
+            .map(|&bytes| f32::from_le_bytes(bytes))
+            .collect())
+    }
+}
+
+/// Splits `inputs` into consecutive ranges, each within both request limits.
+///
+/// Tokens are estimated at one per three bytes, which overestimates for typical
+/// text. An input over the token limit on its own still gets a request, so the API
+/// can report it.
+pub(crate) fn split_batches(
+    inputs: &[String],
+    max_inputs: usize,
+    max_tokens: usize,
+) -> Vec<Range<usize>> {
+    let max_inputs = max_inputs.max(1);
+    let mut batches = Vec::new();
+    let mut start = 0;
+    let mut tokens: usize = 0;
+    for (index, input) in inputs.iter().enumerate() {
+        let input_tokens = input.len().div_ceil(3);
+        if index > start
+            && (index - start == max_inputs || tokens.saturating_add(input_tokens) > max_tokens)
+        {
+            batches.push(start..index);
+            start = index;
+            tokens = 0;
+        }
+        tokens = tokens.saturating_add(input_tokens);
+    }
+    if start < inputs.len() {
+        batches.push(start..inputs.len());
+    }
+    batches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_batches() {
+        let inputs: Vec<String> = ["a", "b", "c", "d", "e"].map(String::from).into();
+        assert_eq!(split_batches(&inputs, 2, 1000), vec![0..2, 2..4, 4..5]);
+        assert_eq!(
+            split_batches(&inputs, 0, 1000),
+            vec![0..1, 1..2, 2..3, 3..4, 4..5]
+        );
+        assert_eq!(split_batches(&inputs, 2048, 1000), vec![0..5]);
+        assert!(split_batches(&[], 2048, 1000).is_empty());
+
+        // 9 bytes count as 3 tokens
+        let inputs: Vec<String> = ["123456789", "123", "123456789", "123456789012"]
+            .map(String::from)
+            .into();
+        assert_eq!(split_batches(&inputs, 2048, 4), vec![0..2, 2..3, 3..4]);
+    }
+
+    #[test]
+    fn test_usage_saturates() {
+        let mut usage = OpenAIEmbeddingUsage {
+            prompt_tokens: u32::MAX - 1,
+            total_tokens: 10,
+        };
+        usage.add(&OpenAIEmbeddingUsage {
+            prompt_tokens: 5,
+            total_tokens: 5,
+        });
+        assert_eq!(usage.prompt_tokens, u32::MAX);
+        assert_eq!(usage.total_tokens, 15);
+    }
+
+    #[test]
+    fn test_decode_embedding() {
+        let encoded = STANDARD.encode(
+            [0.5f32, -1.25, 3.0]
+                .iter()
+                .flat_map(|value| value.to_le_bytes())
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            OpenAIEmbeddingValue::Base64(encoded).decode().unwrap(),
+            vec![0.5, -1.25, 3.0]
+        );
+        assert_eq!(
+            OpenAIEmbeddingValue::Floats(vec![0.25]).decode().unwrap(),
+            vec![0.25]
+        );
+        assert!(matches!(
+            OpenAIEmbeddingValue::Base64("AAAAAAA=".to_string()).decode(),
+            Err(ErrorKind::JsonParseError(_))
+        ));
+    }
+}
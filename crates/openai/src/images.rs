@@ -0,0 +1,255 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
+use http_core::Form;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    OpenAICompletionTokensDetails, OpenAIPromptTokensDetails,
+    bindings::wassemble::openai::types::{
+        ErrorKind, GeneratedImage, ImageBackground, ImageData, ImageEdit, ImageGeneration,
+        ImageOutputFormat, ImageResponse, ImageResponseFormat, Usage,
+    },
+    form_fields, upload,
+};
+
+#[derive(Serialize)]
+pub(crate) struct SerializableImageGeneration<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<&'a str>,
+    prompt: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    n: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quality: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    background: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_format: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<&'a str>,
+}
+
+impl<'a> SerializableImageGeneration<'a> {
+    pub(crate) fn new(generation: &'a ImageGeneration) -> Self {
+        Self {
+            model: generation.model.as_deref(),
+            prompt: &generation.prompt,
+            n: generation.n,
+            size: generation.size.as_deref(),
+            quality: generation.quality.as_deref(),
+            background: generation.background.map(background),
+            output_format: generation.output_format.map(output_format),
+            response_format: generation.response_format.map(response_format),
+            user: generation.user.as_deref(),
+        }
+    }
+}
+
+/// Builds the multipart form of an edit; the endpoint does not take JSON.
+pub(crate) fn edit_form(edit: &ImageEdit) -> Form {
+    let mut form = form_fields([
+        ("model", edit.model.clone()),
+        ("prompt", Some(edit.prompt.clone())),
+        ("n", edit.n.map(|n| n.to_string())),
+        ("size", edit.size.clone()),
+        ("quality", edit.quality.clone()),
+        (
+            "background",
+            edit.background.map(background).map(String::from),
+        ),
+        (
+            "output_format",
+            edit.output_format.map(output_format).map(String::from),
+        ),
+        (
+            "response_format",
+            edit.response_format.map(response_format).map(String::from),
+        ),
+        ("user", edit.user.clone()),
+    ]);
+    // several images are sent as an array field, which dall-e-2 does not accept
+    let image_field = if edit.images.len() > 1 {
+        "image[]"
+    } else {
+        "image"
+    };
+    for image in &edit.images {
+        form = upload(form, image_field, image);
+    }
+    if let Some(mask) = &edit.mask {
+        form = upload(form, "mask", mask);
+    }
+    form
+}
+
+fn background(background: ImageBackground) -> &'static str {
+    match background {
+        ImageBackground::Auto => "auto",
+        ImageBackground::Transparent => "transparent",
+        ImageBackground::Opaque => "opaque",
+    }
+}
+
+fn output_format(format: ImageOutputFormat) -> &'static str {
+    match format {
+        ImageOutputFormat::Png => "png",
+        ImageOutputFormat::Jpeg => "jpeg",
+        ImageOutputFormat::Webp => "webp",
+    }
+}
+
+fn response_format(format: ImageResponseFormat) -> &'static str {
+    match format {
+        ImageResponseFormat::Url => "url",
+        ImageResponseFormat::B64Json => "b64_json",
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub(crate) struct OpenAIImageResponse {
+    created: u64,
+    #[serde(default)]
+    data: Vec<OpenAIImage>,
+    usage: Option<OpenAIImageUsage>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAIImage {
+    url: Option<String>,
+    b64_json: Option<String>,
+    revised_prompt: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAIImageUsage {
+    input_tokens: u32,
+    output_tokens: u32,
+    total_tokens: u32,
+    input_tokens_details: Option<OpenAIPromptTokensDetails>,
+    output_tokens_details: Option<OpenAICompletionTokensDetails>,
+}
+
+impl OpenAIImageResponse {
+    /// Converts the response, decoding images returned as base64.
+    pub(crate) fn decode(self) -> Result<ImageResponse, ErrorKind> {
+        let images = self
+            .data
+            .into_iter()
+            .map(|image| {
+                let data = match (image.b64_json, image.url) {
+                    (Some(encoded), _) => {
+                        ImageData::Bytes(STANDARD.decode(encoded).map_err(|e| {
+                            ErrorKind::JsonParseError(format!("Invalid base64 image: {e}"))
+                        })?)
+                    }
+                    (None, Some(url)) => ImageData::Url(url),
+                    (None, None) => {
+                        return Err(ErrorKind::JsonParseError(
+                            "Image has neither url nor b64_json".to_string(),
+                        ));
+                    }
+                };
+                Ok(GeneratedImage {
+                    data,
+                    revised_prompt: image.revised_prompt,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(ImageResponse {
+            created: self.created,
+            images,
+            usage: self.usage.map(|usage| Usage {
+                prompt_tokens: usage.input_tokens,
+                completion_tokens: usage.output_tokens,
+                total_tokens: usage.total_tokens,
+                cached_tokens: usage
+                    .input_tokens_details
+                    .and_then(|details| details.cached_tokens),
+                reasoning_tokens: usage
+                    .output_tokens_details
+                    .and_then(|details| details.reasoning_tokens),
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::wassemble::openai::types::FileUpload;
+
+    fn image_file(filename: &str) -> FileUpload {
+        FileUpload {
+            filename: filename.to_string(),
+            content_type: "image/png".to_string(),
+            data: vec![0x89, b'P', b'N', b'G'],
+        }
+    }
+
+    fn edit() -> ImageEdit {
+        ImageEdit {
+            model: None,
+            prompt: "Add a hat".to_string(),
+            images: vec![image_file("cat.png")],
+            mask: None,
+            n: Some(2),
+            size: None,
+            quality: None,
+            background: Some(ImageBackground::Transparent),
+            output_format: None,
+            response_format: Some(ImageResponseFormat::B64Json),
+            user: None,
+        }
+    }
+
+    fn encode(form: Form) -> String {
+        String::from_utf8_lossy(&form.encode().0).into_owned()
+    }
+
+    #[test]
+    fn test_edit_form() {
+        let body = encode(edit_form(&edit()));
+        assert!(body.contains("name=\"prompt\"\r\n\r\nAdd a hat\r\n"));
+        assert!(body.contains("name=\"n\"\r\n\r\n2\r\n"));
+        assert!(body.contains("name=\"background\"\r\n\r\ntransparent\r\n"));
+        assert!(body.contains("name=\"response_format\"\r\n\r\nb64_json\r\n"));
+        assert!(body.contains("name=\"image\"; filename=\"cat.png\""));
+        assert!(!body.contains("name=\"model\""));
+        assert!(!body.contains("name=\"mask\""));
+
+        let mut edit = edit();
+        edit.images.push(image_file("dog.png"));
+        edit.mask = Some(image_file("mask.png"));
+        let body = encode(edit_form(&edit));
+        assert_eq!(body.matches("name=\"image[]\"").count(), 2);
+        assert!(body.contains("name=\"mask\"; filename=\"mask.png\""));
+    }
+
+    #[test]
+    fn test_decode_images() {
+        let response: OpenAIImageResponse = serde_json::from_str(
+            r#"{"created":1713833628,"data":[{"b64_json":"iVBORw=="},{"url":"https://example.com/image.png","revised_prompt":"A cat"}]}"#,
+        )
+        .unwrap();
+        let response = response.decode().unwrap();
+        assert!(
+            matches!(&response.images[0].data, ImageData::Bytes(bytes) if bytes == &[0x89, b'P', b'N', b'G'])
+        );
+        assert!(
+            matches!(&response.images[1].data, ImageData::Url(url) if url == "https://example.com/image.png")
+        );
+        assert_eq!(response.images[1].revised_prompt.as_deref(), Some("A cat"));
+        assert!(response.usage.is_none());
+
+        let response: OpenAIImageResponse =
+            serde_json::from_str(r#"{"created":1713833628,"data":[{"b64_json":"not base64"}]}"#)
+                .unwrap();
+        assert!(matches!(
+            response.decode(),
+            Err(ErrorKind::JsonParseError(_))
+        ));
+    }
+}
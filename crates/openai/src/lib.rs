@@ -1,30 +1,228 @@
+mod audio;
+mod batch;
 #[allow(warnings)]
 mod bindings;
-
-use bindings::Guest;
-use serde::{Deserialize, Serialize};
-use waki::Client;
-
-use crate::bindings::{ChatCompletion, ChatResponse, Embedding, EmbeddingResponse, OpenaiError};
+mod embeddings;
+mod images;
+mod moderation;
+mod responses;
+mod schema;
+mod stream;
+
+use std::{collections::BTreeMap, time::Duration};
+
+use http_core::{
+    Backoff, Client, Error, Form, HeaderMap, Method, RequestBuilder, Response, RetryPolicy,
+};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+#[cfg(feature = "wasi-config")]
+use crate::bindings::wasi::config::store;
+use crate::{
+    audio::SerializableSpeech,
+    batch::{OpenAIBatch, OpenAIBatchList, OpenAIFile, SerializableBatchRequest},
+    bindings::{
+        exports::wassemble::openai::api::{ChatStream, Guest, GuestClient},
+        wassemble::openai::types::{
+            ApiError, AudioFormat, Batch, BatchList, BatchRequest, ChatChoice, ChatCompletion,
+            ChatResponse, Config, ContentPart, Embedding, EmbeddingBatch, EmbeddingBatchResponse,
+            EmbeddingResponse, EmbeddingVector, ErrorKind, FileObject, FileUpload, ImageDetail,
+            ImageEdit, ImageGeneration, ImageResponse, InputAudio, JsonSchemaFormat,
+            MessageContent, ModelInfo, Moderation, ModerationResponse, OpenaiError, RateLimit,
+            RateLimitStatus, ReasoningEffort, Response as ModelResponse, ResponseFormat,
+            ResponseRequest, Speech, TokenLogprob, Tool, ToolCall, ToolChoice, TopLogprob,
+            Transcription, TranscriptionResponse, Translation, Usage,
+        },
+    },
+    embeddings::{
+        MAX_INPUTS_PER_REQUEST, MAX_TOKENS_PER_REQUEST, OpenAIEmbeddingBatchResponse,
+        OpenAIEmbeddingUsage, SerializableEmbeddingBatch,
+    },
+    images::{OpenAIImageResponse, SerializableImageGeneration},
+    moderation::{OpenAIModerationResponse, SerializableModeration},
+    responses::{OpenAIResponse, SerializableResponseRequest},
+    stream::OpenAIChatStream,
+};
 
 const OPENAI_API_BASE: &str = "https://api.openai.com/v1";
 
+/// Characters escaped in a path segment: all but the unreserved ones of RFC 3986, so
+/// that IDs such as fine-tuned model names (`ft:gpt-4o-mini:acme::id`) stay one segment.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
 #[derive(Deserialize, Serialize)]
 struct OpenAIChatResponse {
     id: String,
     model: String,
+    #[serde(default)]
+    created: u64,
+    system_fingerprint: Option<String>,
     choices: Vec<OpenAIChoice>,
+    usage: Option<OpenAIUsage>,
 }
 
 #[derive(Deserialize, Serialize)]
 struct OpenAIChoice {
+    #[serde(default)]
+    index: u32,
     message: OpenAIMessage,
     finish_reason: String,
+    logprobs: Option<OpenAILogprobs>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAILogprobs {
+    content: Option<Vec<OpenAITokenLogprob>>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAITokenLogprob {
+    token: String,
+    logprob: f64,
+    bytes: Option<Vec<u8>>,
+    #[serde(default)]
+    top_logprobs: Vec<OpenAITopLogprob>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAITopLogprob {
+    token: String,
+    logprob: f64,
+    bytes: Option<Vec<u8>>,
+}
+
+impl From<OpenAIChoice> for ChatChoice {
+    fn from(choice: OpenAIChoice) -> Self {
+        ChatChoice {
+            index: choice.index,
+            content: choice.message.content.unwrap_or_default(),
+            tool_calls: choice
+                .message
+                .tool_calls
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            refusal: choice.message.refusal,
+            finish_reason: choice.finish_reason,
+            logprobs: choice
+                .logprobs
+                .and_then(|logprobs| logprobs.content)
+                .map(|tokens| tokens.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl From<OpenAITokenLogprob> for TokenLogprob {
+    fn from(token: OpenAITokenLogprob) -> Self {
+        TokenLogprob {
+            token: token.token,
+            logprob: token.logprob,
+            bytes: token.bytes,
+            top_logprobs: token
+                .top_logprobs
+                .into_iter()
+                .map(|top| TopLogprob {
+                    token: top.token,
+                    logprob: top.logprob,
+                    bytes: top.bytes,
+                })
+                .collect(),
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize)]
 struct OpenAIMessage {
-    content: String,
+    content: Option<String>,
+    refusal: Option<String>,
+    #[serde(default)]
+    tool_calls: Vec<OpenAIToolCall>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAIToolCall {
+    id: String,
+    function: OpenAIFunctionCall,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAIFunctionCall {
+    name: String,
+    arguments: String,
+}
+
+impl From<OpenAIToolCall> for ToolCall {
+    fn from(call: OpenAIToolCall) -> Self {
+        ToolCall {
+            id: call.id,
+            name: call.function.name,
+            arguments: call.function.arguments,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAIUsage {
+    prompt_tokens: u32,
+    completion_tokens: u32,
+    total_tokens: u32,
+    prompt_tokens_details: Option<OpenAIPromptTokensDetails>,
+    completion_tokens_details: Option<OpenAICompletionTokensDetails>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAIPromptTokensDetails {
+    cached_tokens: Option<u32>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAICompletionTokensDetails {
+    reasoning_tokens: Option<u32>,
+}
+
+impl From<OpenAIUsage> for Usage {
+    fn from(usage: OpenAIUsage) -> Self {
+        Usage {
+            prompt_tokens: usage.prompt_tokens,
+            completion_tokens: usage.completion_tokens,
+            total_tokens: usage.total_tokens,
+            cached_tokens: usage
+                .prompt_tokens_details
+                .and_then(|details| details.cached_tokens),
+            reasoning_tokens: usage
+                .completion_tokens_details
+                .and_then(|details| details.reasoning_tokens),
+        }
+    }
+}
+
+impl TryFrom<OpenAIChatResponse> for ChatResponse {
+    type Error = ErrorKind;
+
+    fn try_from(response: OpenAIChatResponse) -> Result<Self, ErrorKind> {
+        let choices: Vec<ChatChoice> = response.choices.into_iter().map(Into::into).collect();
+        let first = choices.first().ok_or(ErrorKind::NoChoices)?;
+
+        Ok(ChatResponse {
+            id: response.id,
+            model: response.model,
+            created: response.created,
+            system_fingerprint: response.system_fingerprint,
+            content: first.content.clone(),
+            tool_calls: first.tool_calls.clone(),
+            refusal: first.refusal.clone(),
+            finish_reason: first.finish_reason.clone(),
+            choices,
+            usage: response.usage.map(Into::into),
+            request_id: None,
+        })
+    }
 }
 
 #[derive(Deserialize, Serialize)]
@@ -38,18 +236,270 @@ struct OpenAIEmbeddingData {
     embedding: Vec<f64>,
 }
 
+impl TryFrom<OpenAIEmbeddingResponse> for EmbeddingResponse {
+    type Error = ErrorKind;
+
+    fn try_from(response: OpenAIEmbeddingResponse) -> Result<Self, ErrorKind> {
+        let data = response
+            .data
+            .into_iter()
+            .next()
+            .ok_or(ErrorKind::NoEmbeddingData)?;
+
+        Ok(EmbeddingResponse {
+            model: response.model,
+            embedding: data.embedding,
+            request_id: None,
+        })
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAIModelList {
+    data: Vec<OpenAIModel>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAIModel {
+    id: String,
+    // compatible servers do not always send these
+    #[serde(default)]
+    created: u64,
+    #[serde(default)]
+    owned_by: String,
+}
+
+impl From<OpenAIModel> for ModelInfo {
+    fn from(model: OpenAIModel) -> Self {
+        ModelInfo {
+            id: model.id,
+            created: model.created,
+            owned_by: model.owned_by,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct OpenAIErrorBody {
+    error: OpenAIErrorDetail,
+}
+
+#[derive(Deserialize)]
+struct OpenAIErrorDetail {
+    message: String,
+    r#type: Option<String>,
+    param: Option<String>,
+    // some compatible servers send numeric codes
+    code: Option<Value>,
+}
+
+impl OpenAIErrorDetail {
+    /// Picks the error case for an error the API reported with `status`.
+    ///
+    /// Codes that need a different reaction from the caller win over the status:
+    /// OpenAI reports an exhausted quota with a 429, like a rate limit.
+    fn into_error(self, status: u16, retry_after: Option<Duration>) -> ErrorKind {
+        let error = ApiError {
+            status,
+            message: self.message,
+            type_: self.r#type,
+            param: self.param,
+            code: self.code.and_then(|code| match code {
+                Value::String(code) => Some(code),
+                Value::Null => None,
+                code => Some(code.to_string()),
+            }),
+        };
+        match (status, error.code.as_deref()) {
+            (_, Some("context_length_exceeded")) => ErrorKind::ContextLengthExceeded(error),
+            (_, Some("insufficient_quota")) => ErrorKind::InsufficientQuota(error),
+            (401, _) => ErrorKind::Authentication(error),
+            (403, _) => ErrorKind::PermissionDenied(error),
+            (404, _) => ErrorKind::NotFound(error),
+            (429, _) => ErrorKind::RateLimited(RateLimit {
+                message: error.message,
+                retry_after_ms: retry_after.map(|delay| delay.as_millis() as u64),
+            }),
+            (400..=499, _) => ErrorKind::InvalidRequest(error),
+            (500..=599, _) => ErrorKind::ServerError(error),
+            // errors inside an event stream arrive after its 200
+            (200, _) if error.type_.as_deref() == Some("invalid_request_error") => {
+                ErrorKind::InvalidRequest(error)
+            }
+            (200, _) => ErrorKind::ServerError(error),
+            _ => ErrorKind::RequestFailed(format!(
+                "Request failed with status {status}: {}",
+                error.message
+            )),
+        }
+    }
+}
+
 #[derive(Serialize)]
 struct SerializableChatMessage<'a> {
     role: &'a str,
-    content: &'a str,
+    // null for assistant messages that only call tools
+    content: Option<SerializableContent<'a>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tool_calls: Vec<SerializableToolCall<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_call_id: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum SerializableContent<'a> {
+    Text(&'a str),
+    Parts(Vec<SerializableContentPart<'a>>),
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+enum SerializableContentPart<'a> {
+    Text {
+        text: &'a str,
+    },
+    ImageUrl {
+        image_url: SerializableImageUrl<'a>,
+    },
+    InputAudio {
+        input_audio: SerializableInputAudio<'a>,
+    },
+}
+
+#[derive(Serialize)]
+struct SerializableImageUrl<'a> {
+    url: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+struct SerializableInputAudio<'a> {
+    data: &'a str,
+    format: &'static str,
+}
+
+#[derive(Serialize)]
+struct SerializableToolCall<'a> {
+    id: &'a str,
+    r#type: &'static str,
+    function: SerializableFunctionCall<'a>,
+}
+
+#[derive(Serialize)]
+struct SerializableFunctionCall<'a> {
+    name: &'a str,
+    arguments: &'a str,
+}
+
+#[derive(Serialize)]
+struct SerializableTool<'a> {
+    r#type: &'static str,
+    function: SerializableFunction<'a>,
+}
+
+#[derive(Serialize)]
+struct SerializableFunction<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parameters: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    strict: Option<bool>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum SerializableToolChoice<'a> {
+    Mode(&'static str),
+    Function {
+        r#type: &'static str,
+        function: SerializableFunctionName<'a>,
+    },
+}
+
+#[derive(Serialize)]
+struct SerializableFunctionName<'a> {
+    name: &'a str,
 }
 
 #[derive(Serialize)]
 struct SerializableChatCompletion<'a> {
     model: &'a str,
     messages: Vec<SerializableChatMessage<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     temperature: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_completion_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f64>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    stop: &'a [String],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    presence_penalty: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frequency_penalty: Option<f64>,
+    // token IDs become the string keys of a JSON object
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    logit_bias: BTreeMap<u32, i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reasoning_effort: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tools: Vec<SerializableTool<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<SerializableToolChoice<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parallel_tool_calls: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<SerializableResponseFormat<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    n: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    logprobs: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_logprobs: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<bool>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    metadata: BTreeMap<&'a str, &'a str>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream_options: Option<StreamOptions>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+enum SerializableResponseFormat<'a> {
+    Text,
+    JsonObject,
+    JsonSchema {
+        json_schema: SerializableJsonSchema<'a>,
+    },
+}
+
+#[derive(Serialize)]
+struct SerializableJsonSchema<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    schema: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    strict: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct StreamOptions {
+    /// Asks for a final chunk carrying the token counts
+    include_usage: bool,
 }
 
 #[derive(Serialize)]
@@ -58,101 +508,797 @@ struct SerializableEmbedding<'a> {
     input: &'a str,
 }
 
-struct Component;
-
-impl Guest for Component {
-    fn create_chat_completion(
-        api_key: String,
-        completion: ChatCompletion,
-    ) -> Result<ChatResponse, OpenaiError> {
-        let messages: Vec<SerializableChatMessage> = completion
+impl<'a> SerializableChatCompletion<'a> {
+    fn new(completion: &'a ChatCompletion) -> Result<Self, ErrorKind> {
+        let messages = completion
             .messages
             .iter()
             .map(|m| SerializableChatMessage {
                 role: &m.role,
-                content: &m.content,
+                content: SerializableContent::new(&m.content, !m.tool_calls.is_empty()),
+                tool_calls: m
+                    .tool_calls
+                    .iter()
+                    .map(|call| SerializableToolCall {
+                        id: &call.id,
+                        r#type: "function",
+                        function: SerializableFunctionCall {
+                            name: &call.name,
+                            arguments: &call.arguments,
+                        },
+                    })
+                    .collect(),
+                tool_call_id: m.tool_call_id.as_deref(),
             })
             .collect();
-        let serializable = SerializableChatCompletion {
+        let tools = completion
+            .tools
+            .iter()
+            .map(SerializableTool::new)
+            .collect::<Result<_, _>>()?;
+        let response_format = completion
+            .response_format
+            .as_ref()
+            .map(SerializableResponseFormat::new)
+            .transpose()?;
+        Ok(Self {
             model: &completion.model,
             messages,
             temperature: completion.temperature,
             max_tokens: completion.max_tokens,
+            max_completion_tokens: completion.max_completion_tokens,
+            top_p: completion.top_p,
+            stop: &completion.stop,
+            seed: completion.seed,
+            presence_penalty: completion.presence_penalty,
+            frequency_penalty: completion.frequency_penalty,
+            logit_bias: completion.logit_bias.iter().copied().collect(),
+            reasoning_effort: completion.reasoning_effort.map(reasoning_effort),
+            user: completion.user.as_deref(),
+            tools,
+            tool_choice: completion
+                .tool_choice
+                .as_ref()
+                .map(SerializableToolChoice::new),
+            parallel_tool_calls: completion.parallel_tool_calls,
+            response_format,
+            n: completion.n,
+            logprobs: completion.logprobs,
+            top_logprobs: completion.top_logprobs,
+            store: completion.store,
+            metadata: completion
+                .metadata
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.as_str()))
+                .collect(),
+            stream: false,
+            stream_options: None,
+        })
+    }
+}
+
+impl<'a> SerializableContent<'a> {
+    /// Converts message content, leaving out the empty text of a message calling tools.
+    fn new(content: &'a MessageContent, calls_tools: bool) -> Option<Self> {
+        match content {
+            MessageContent::Text(text) if text.is_empty() && calls_tools => None,
+            MessageContent::Text(text) => Some(SerializableContent::Text(text)),
+            MessageContent::Parts(parts) => Some(SerializableContent::Parts(
+                parts.iter().map(SerializableContentPart::new).collect(),
+            )),
+        }
+    }
+}
+
+impl<'a> SerializableContentPart<'a> {
+    fn new(part: &'a ContentPart) -> Self {
+        match part {
+            ContentPart::Text(text) => SerializableContentPart::Text { text },
+            ContentPart::ImageUrl(image) => SerializableContentPart::ImageUrl {
+                image_url: SerializableImageUrl {
+                    url: &image.url,
+                    detail: image.detail.map(image_detail),
+                },
+            },
+            ContentPart::InputAudio(audio) => SerializableContentPart::InputAudio {
+                input_audio: SerializableInputAudio::new(audio),
+            },
+        }
+    }
+}
+
+impl<'a> SerializableInputAudio<'a> {
+    fn new(audio: &'a InputAudio) -> Self {
+        Self {
+            data: &audio.data,
+            format: match audio.format {
+                AudioFormat::Wav => "wav",
+                AudioFormat::Mp3 => "mp3",
+            },
+        }
+    }
+}
+
+impl<'a> SerializableTool<'a> {
+    fn new(tool: &'a Tool) -> Result<Self, ErrorKind> {
+        // the schema is embedded as JSON rather than sent as a string
+        let parameters = tool
+            .parameters
+            .as_deref()
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|e| {
+                ErrorKind::JsonParseError(format!(
+                    "Invalid parameters schema for tool {}: {e}",
+                    tool.name
+                ))
+            })?;
+        Ok(Self {
+            r#type: "function",
+            function: SerializableFunction {
+                name: &tool.name,
+                description: tool.description.as_deref(),
+                parameters,
+                strict: tool.strict,
+            },
+        })
+    }
+}
+
+impl<'a> SerializableResponseFormat<'a> {
+    fn new(format: &'a ResponseFormat) -> Result<Self, ErrorKind> {
+        Ok(match format {
+            ResponseFormat::Text => SerializableResponseFormat::Text,
+            ResponseFormat::JsonObject => SerializableResponseFormat::JsonObject,
+            ResponseFormat::JsonSchema(format) => SerializableResponseFormat::JsonSchema {
+                json_schema: SerializableJsonSchema {
+                    name: &format.name,
+                    description: format.description.as_deref(),
+                    schema: parse_schema(format)?,
+                    strict: format.strict,
+                },
+            },
+        })
+    }
+}
+
+fn image_detail(detail: ImageDetail) -> &'static str {
+    match detail {
+        ImageDetail::Auto => "auto",
+        ImageDetail::Low => "low",
+        ImageDetail::High => "high",
+    }
+}
+
+fn reasoning_effort(effort: ReasoningEffort) -> &'static str {
+    match effort {
+        ReasoningEffort::Minimal => "minimal",
+        ReasoningEffort::Low => "low",
+        ReasoningEffort::Medium => "medium",
+        ReasoningEffort::High => "high",
+    }
+}
+
+/// Starts a multipart form with the fields that are set.
+fn form_fields<const N: usize>(fields: [(&str, Option<String>); N]) -> Form {
+    fields
+        .into_iter()
+        .fold(Form::new(), |form, (name, value)| match value {
+            Some(value) => form.text(name, value),
+            None => form,
+        })
+}
+
+fn upload(form: Form, name: &str, file: &FileUpload) -> Form {
+    form.file(name, &file.filename, &file.content_type, file.data.clone())
+}
+
+fn parse_schema(format: &JsonSchemaFormat) -> Result<Value, ErrorKind> {
+    serde_json::from_str(&format.schema).map_err(|e| {
+        ErrorKind::JsonParseError(format!(
+            "Invalid schema for response format {}: {e}",
+            format.name
+        ))
+    })
+}
+
+impl<'a> SerializableToolChoice<'a> {
+    fn new(choice: &'a ToolChoice) -> Self {
+        match choice {
+            ToolChoice::None => SerializableToolChoice::Mode("none"),
+            ToolChoice::Auto => SerializableToolChoice::Mode("auto"),
+            ToolChoice::Required => SerializableToolChoice::Mode("required"),
+            ToolChoice::Function(name) => SerializableToolChoice::Function {
+                r#type: "function",
+                function: SerializableFunctionName { name },
+            },
+        }
+    }
+}
+
+impl From<Error> for ErrorKind {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::Build(message) | Error::Connect(message) | Error::Request(message) => {
+                ErrorKind::RequestFailed(message)
+            }
+            Error::ResponseBody(message) => ErrorKind::ResponseBodyError(message),
+            Error::Utf8(message) => ErrorKind::Utf8Error(message),
+            Error::Json(message) => ErrorKind::JsonParseError(message),
+            Error::Status(response) => {
+                let detail = match response.json::<OpenAIErrorBody>() {
+                    Ok(body) => body.error,
+                    Err(_) => OpenAIErrorDetail {
+                        message: String::from_utf8_lossy(response.body()).into_owned(),
+                        r#type: None,
+                        param: None,
+                        code: None,
+                    },
+                };
+                detail.into_error(response.status(), http_core::retry_after(&response))
+            }
+        }
+    }
+}
+
+impl From<Error> for OpenaiError {
+    fn from(error: Error) -> Self {
+        let request_id = match &error {
+            Error::Status(response) => request_id(response.headers()),
+            _ => None,
         };
+        OpenaiError {
+            kind: error.into(),
+            request_id,
+        }
+    }
+}
 
-        let response = Client::new()
-            .post(&format!("{OPENAI_API_BASE}/chat/completions"))
-            .header("Authorization", format!("Bearer {api_key}"))
-            .header("Content-Type", "application/json")
-            .json(&serializable)
-            .send()
-            .map_err(|e| OpenaiError::RequestFailed(format!("Failed to send request: {e}")))?;
+impl From<ErrorKind> for OpenaiError {
+    /// An error raised before a response arrived, so without a request ID.
+    fn from(kind: ErrorKind) -> Self {
+        OpenaiError {
+            kind,
+            request_id: None,
+        }
+    }
+}
 
-        let body = response.body().map_err(|e| {
-            OpenaiError::ResponseBodyError(format!("Failed to get response body: {e}"))
-        })?;
+/// Retries like `Backoff`, except for an exhausted quota, which OpenAI also reports
+/// with a 429 but which no amount of waiting fixes.
+struct OpenAIRetry(Backoff);
+
+impl RetryPolicy for OpenAIRetry {
+    fn retry_delay(
+        &self,
+        attempt: u32,
+        method: &Method,
+        outcome: &Result<Response, Error>,
+    ) -> Option<Duration> {
+        if let Err(Error::Status(response)) = outcome
+            && let Ok(body) = response.json::<OpenAIErrorBody>()
+            && body.error.code == Some(Value::from("insufficient_quota"))
+        {
+            return None;
+        }
+        self.0.retry_delay(attempt, method, outcome)
+    }
+}
 
-        let body_str = String::from_utf8(body).map_err(|e| {
-            OpenaiError::Utf8Error(format!("Failed to parse response as UTF-8: {e}"))
-        })?;
+/// OpenAI's ID for a request, which support asks for when tracing a call.
+fn request_id(headers: &HeaderMap) -> Option<String> {
+    Some(headers.get("x-request-id")?.to_str().ok()?.to_string())
+}
 
-        let openai_response: OpenAIChatResponse = serde_json::from_str(&body_str).map_err(|e| {
-            OpenaiError::JsonParseError(format!("Failed to parse JSON response: {e}"))
-        })?;
+/// Sends `request` and reads its response with `read`, tagging any error with
+/// OpenAI's ID for the request.
+#[allow(clippy::result_large_err)] // returned as is from the exports
+fn send_with<T>(
+    request: RequestBuilder<'_>,
+    read: impl FnOnce(Response) -> Result<T, ErrorKind>,
+) -> Result<T, OpenaiError> {
+    let response = request.send()?;
+    let request_id = request_id(response.headers());
+    read(response).map_err(|kind| OpenaiError { kind, request_id })
+}
 
-        let choice = openai_response
-            .choices
-            .first()
-            .ok_or(OpenaiError::NoChoices)?;
+/// Sends `request` and decodes its JSON response.
+#[allow(clippy::result_large_err)] // returned as is from the exports
+fn send_json<T: DeserializeOwned>(request: RequestBuilder<'_>) -> Result<T, OpenaiError> {
+    send_with(request, |response| Ok(response.json()?))
+}
 
-        Ok(ChatResponse {
-            id: openai_response.id,
-            model: openai_response.model,
-            content: choice.message.content.clone(),
-            finish_reason: choice.finish_reason.clone(),
+/// Tags a request with the caller's own ID for it.
+fn client_request_id<'a>(request: RequestBuilder<'a>, id: &Option<String>) -> RequestBuilder<'a> {
+    match id {
+        Some(id) => request.header("X-Client-Request-Id", id.clone()),
+        None => request,
+    }
+}
+
+fn rate_limit_status(headers: &HeaderMap) -> Option<RateLimitStatus> {
+    let header = |name| headers.get(name)?.to_str().ok();
+    let number = |name| header(name)?.parse().ok();
+    let reset = |name| Some(parse_duration(header(name)?)?.as_millis() as u64);
+    let status = RateLimitStatus {
+        limit_requests: number("x-ratelimit-limit-requests"),
+        limit_tokens: number("x-ratelimit-limit-tokens"),
+        remaining_requests: number("x-ratelimit-remaining-requests"),
+        remaining_tokens: number("x-ratelimit-remaining-tokens"),
+        reset_requests_ms: reset("x-ratelimit-reset-requests"),
+        reset_tokens_ms: reset("x-ratelimit-reset-tokens"),
+    };
+    (status.limit_requests.is_some() || status.limit_tokens.is_some()).then_some(status)
+}
+
+/// Parses the durations OpenAI uses in rate limit headers, such as "20ms", "1s" or "6m0s".
+fn parse_duration(value: &str) -> Option<Duration> {
+    let mut total = Duration::ZERO;
+    let mut rest = value.trim();
+    while !rest.is_empty() {
+        let split = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .filter(|&split| split > 0)?;
+        let (number, unit_and_rest) = rest.split_at(split);
+        let number: f64 = number.parse().ok()?;
+        let unit_len = unit_and_rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(unit_and_rest.len());
+        let (unit, next) = unit_and_rest.split_at(unit_len);
+        let seconds = match unit {
+            "ms" => number / 1000.0,
+            "s" => number,
+            "m" => number * 60.0,
+            "h" => number * 3600.0,
+            _ => return None,
+        };
+        total += Duration::try_from_secs_f64(seconds).ok()?;
+        rest = next;
+    }
+    Some(total)
+}
+
+/// Checks that a structured completion was answered with content matching `schema`, or
+/// with any JSON object when there is no schema.
+fn check_structured(response: &ChatResponse, schema: Option<Value>) -> Result<(), ErrorKind> {
+    if let Some(refusal) = &response.refusal {
+        return Err(ErrorKind::Refusal(refusal.clone()));
+    }
+
+    let content: Value = serde_json::from_str(&response.content)
+        .map_err(|e| ErrorKind::SchemaViolation(format!("Content is not valid JSON: {e}")))?;
+    match schema {
+        Some(schema) => schema::validate(&schema, &content).map_err(|error| {
+            ErrorKind::SchemaViolation(format!("Content does not match the schema: {error}"))
+        }),
+        None if !content.is_object() => Err(ErrorKind::SchemaViolation(
+            "Content is not a JSON object".to_string(),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Returns `value`, or the `key` entry of `wasi:config/store` when `value` is empty.
+#[cfg(feature = "wasi-config")]
+fn config_fallback(value: String, key: &str) -> Result<Option<String>, ErrorKind> {
+    if !value.is_empty() {
+        return Ok(Some(value));
+    }
+    store::get(key).map_err(|error| match error {
+        store::Error::Upstream(message) | store::Error::Io(message) => ErrorKind::ConfigError(
+            format!("Failed to read {key} from wasi:config/store: {message}"),
+        ),
+    })
+}
+
+/// Returns `value`, or `None` when it is empty.
+#[cfg(not(feature = "wasi-config"))]
+fn config_fallback(value: String, _key: &str) -> Result<Option<String>, ErrorKind> {
+    Ok(Some(value).filter(|value| !value.is_empty()))
+}
+
+struct Component;
+
+impl Guest for Component {
+    type ChatStream = OpenAIChatStream;
+    type Client = OpenAIClient;
+}
+
+/// Backs the `client` resource, reusing one configured HTTP client for every call.
+///
+/// A failure to read credentials from `wasi:config/store` can't be reported by the
+/// constructor, so it is kept and returned from every method instead.
+struct OpenAIClient {
+    http: Result<Client, ErrorKind>,
+    /// The `api-version` of the Azure resource, when talking to Azure OpenAI
+    azure_api_version: Option<String>,
+}
+
+impl OpenAIClient {
+    fn build_http(config: Config) -> Result<Client, ErrorKind> {
+        let base_url = match &config.azure {
+            Some(azure) => format!("{}/openai", azure.endpoint.trim_end_matches('/')),
+            None => config
+                .base_url
+                .unwrap_or_else(|| OPENAI_API_BASE.to_string()),
+        };
+        let mut http = Client::new(base_url);
+        // OpenAI-compatible servers often run without authentication
+        if let Some(api_key) = config_fallback(config.api_key, "openai_api_key")? {
+            http = match &config.azure {
+                Some(azure) if !azure.entra_id => http.header("api-key", api_key),
+                _ => http.bearer_auth(&api_key),
+            };
+        }
+        if let Some(user_agent) = config.user_agent {
+            http = http.header("User-Agent", user_agent);
+        }
+        if let Some(organization) = config.organization {
+            http = http.header("OpenAI-Organization", organization);
+        }
+        if let Some(project) = config.project {
+            http = http.header("OpenAI-Project", project);
+        }
+        if let Some(timeout) = config.connect_timeout_ms {
+            http = http.connect_timeout(Duration::from_millis(timeout.into()));
+        }
+        let mut backoff = Backoff::default();
+        if let Some(retry) = config.retry {
+            backoff = backoff
+                .max_attempts(retry.max_attempts)
+                .initial_delay(Duration::from_millis(retry.initial_delay_ms.into()))
+                .max_delay(Duration::from_millis(retry.max_delay_ms.into()))
+                .statuses(retry.retry_statuses);
+        }
+        Ok(http.retry_policy(OpenAIRetry(backoff)))
+    }
+
+    fn http(&self) -> Result<&Client, ErrorKind> {
+        self.http.as_ref().map_err(Clone::clone)
+    }
+
+    /// Starts a request to `path`, with the `api-version` Azure expects on every call.
+    fn request(&self, method: Method, path: &str) -> Result<RequestBuilder<'_>, ErrorKind> {
+        let request = self.http()?.request(method, path);
+        Ok(match &self.azure_api_version {
+            Some(api_version) => request.query("api-version", api_version.clone()),
+            None => request,
+        })
+    }
+
+    /// Starts a POST to `path` for `model`, which Azure serves from the deployment
+    /// of that name.
+    fn post_model(&self, path: &str, model: Option<&str>) -> Result<RequestBuilder<'_>, ErrorKind> {
+        match (&self.azure_api_version, model) {
+            (Some(_), Some(model)) => {
+                self.request(Method::Post, &format!("/deployments/{model}{path}"))
+            }
+            (Some(_), None) => Err(ErrorKind::Unsupported(format!(
+                "{path} needs a model naming the Azure OpenAI deployment"
+            ))),
+            (None, _) => self.request(Method::Post, path),
+        }
+    }
+}
+
+impl GuestClient for OpenAIClient {
+    fn new(config: Config) -> Self {
+        Self {
+            azure_api_version: config.azure.as_ref().map(|azure| azure.api_version.clone()),
+            http: Self::build_http(config),
+        }
+    }
+
+    fn create_chat_completion(
+        &self,
+        completion: ChatCompletion,
+    ) -> Result<ChatResponse, OpenaiError> {
+        let serializable = SerializableChatCompletion::new(&completion)?;
+
+        let request = self.post_model("/chat/completions", Some(&completion.model))?;
+        let request = client_request_id(request, &completion.client_request_id).json(&serializable);
+
+        send_with(request, |response| {
+            let mut chat_response: ChatResponse =
+                response.json::<OpenAIChatResponse>()?.try_into()?;
+            chat_response.request_id = request_id(response.headers());
+            Ok(chat_response)
         })
     }
 
-    fn create_embedding(
-        api_key: String,
-        embedding: Embedding,
-    ) -> Result<EmbeddingResponse, OpenaiError> {
+    fn create_structured_completion(
+        &self,
+        completion: ChatCompletion,
+    ) -> Result<ChatResponse, OpenaiError> {
+        let schema = match &completion.response_format {
+            Some(ResponseFormat::JsonSchema(format)) => Some(parse_schema(format)?),
+            Some(ResponseFormat::JsonObject) => None,
+            _ => {
+                return Err(ErrorKind::SchemaViolation(
+                    "A structured completion needs a json-object or json-schema response format"
+                        .to_string(),
+                )
+                .into());
+            }
+        };
+
+        let response = self.create_chat_completion(completion)?;
+        check_structured(&response, schema).map_err(|kind| OpenaiError {
+            kind,
+            request_id: response.request_id.clone(),
+        })?;
+        Ok(response)
+    }
+
+    fn stream_chat_completion(
+        &self,
+        completion: ChatCompletion,
+    ) -> Result<ChatStream, OpenaiError> {
+        let serializable = SerializableChatCompletion {
+            stream: true,
+            stream_options: Some(StreamOptions {
+                include_usage: true,
+            }),
+            ..SerializableChatCompletion::new(&completion)?
+        };
+
+        let request = self.post_model("/chat/completions", Some(&completion.model))?;
+        let events = client_request_id(request, &completion.client_request_id)
+            .json(&serializable)
+            .send_event_stream()?;
+
+        Ok(ChatStream::new(OpenAIChatStream::new(events)))
+    }
+
+    fn create_embedding(&self, embedding: Embedding) -> Result<EmbeddingResponse, OpenaiError> {
         let serializable = SerializableEmbedding {
             model: &embedding.model,
             input: &embedding.input,
         };
 
-        let response = Client::new()
-            .post(&format!("{OPENAI_API_BASE}/embeddings"))
-            .header("Authorization", format!("Bearer {api_key}"))
-            .header("Content-Type", "application/json")
-            .json(&serializable)
-            .send()
-            .map_err(|e| OpenaiError::RequestFailed(format!("Failed to send request: {e}")))?;
+        let request = self.post_model("/embeddings", Some(&embedding.model))?;
+        let request = client_request_id(request, &embedding.client_request_id).json(&serializable);
 
-        let body = response.body().map_err(|e| {
-            OpenaiError::ResponseBodyError(format!("Failed to get response body: {e}"))
-        })?;
+        send_with(request, |response| {
+            let mut embedding_response: EmbeddingResponse =
+                response.json::<OpenAIEmbeddingResponse>()?.try_into()?;
+            embedding_response.request_id = request_id(response.headers());
+            Ok(embedding_response)
+        })
+    }
 
-        let body_str = String::from_utf8(body).map_err(|e| {
-            OpenaiError::Utf8Error(format!("Failed to parse response as UTF-8: {e}"))
-        })?;
+    fn create_embeddings(
+        &self,
+        batch: EmbeddingBatch,
+    ) -> Result<EmbeddingBatchResponse, OpenaiError> {
+        let batches = embeddings::split_batches(
+            &batch.inputs,
+            batch
+                .max_inputs_per_request
+                .unwrap_or(MAX_INPUTS_PER_REQUEST) as usize,
+            batch
+                .max_tokens_per_request
+                .unwrap_or(MAX_TOKENS_PER_REQUEST) as usize,
+        );
+
+        let mut model = batch.model.clone();
+        let mut vectors = Vec::with_capacity(batch.inputs.len());
+        let mut usage = OpenAIEmbeddingUsage::default();
+        for range in batches {
+            let serializable = SerializableEmbeddingBatch {
+                model: &batch.model,
+                input: &batch.inputs[range.clone()],
+                dimensions: batch.dimensions,
+                encoding_format: "base64",
+            };
 
-        let openai_response: OpenAIEmbeddingResponse =
-            serde_json::from_str(&body_str).map_err(|e| {
-                OpenaiError::JsonParseError(format!("Failed to parse JSON response: {e}"))
+            let request = self
+                .post_model("/embeddings", Some(&batch.model))?
+                .json(&serializable);
+            let (response_model, response_usage) = send_with(request, |response| {
+                let openai_response: OpenAIEmbeddingBatchResponse = response.json()?;
+                if openai_response.data.len() != range.len() {
+                    return Err(ErrorKind::NoEmbeddingData);
+                }
+                for data in openai_response.data {
+                    vectors.push(EmbeddingVector {
+                        index: range.start as u32 + data.index,
+                        embedding: data.embedding.decode()?,
+                    });
+                }
+                Ok((openai_response.model, openai_response.usage))
             })?;
+            if let Some(batch_usage) = &response_usage {
+                usage.add(batch_usage);
+            }
+            model = response_model;
+        }
+        // the API does not promise to answer in input order
+        vectors.sort_by_key(|vector| vector.index);
 
-        let data = openai_response
-            .data
-            .first()
-            .ok_or(OpenaiError::NoEmbeddingData)?;
+        Ok(EmbeddingBatchResponse {
+            model,
+            embeddings: vectors,
+            usage: usage.into(),
+        })
+    }
 
-        Ok(EmbeddingResponse {
+    fn create_moderation(&self, moderation: Moderation) -> Result<ModerationResponse, OpenaiError> {
+        if self.azure_api_version.is_some() {
+            return Err(ErrorKind::Unsupported(
+                "Azure OpenAI has no moderation endpoint".to_string(),
+            )
+            .into());
+        }
+        let openai_response: OpenAIModerationResponse = send_json(
+            self.request(Method::Post, "/moderations")?
+                .json(&SerializableModeration::new(&moderation)),
+        )?;
+
+        Ok(ModerationResponse {
+            id: openai_response.id,
             model: openai_response.model,
-            embedding: data.embedding.clone(),
+            results: openai_response
+                .results
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+
+    fn create_image(&self, generation: ImageGeneration) -> Result<ImageResponse, OpenaiError> {
+        let request = self
+            .post_model("/images/generations", generation.model.as_deref())?
+            .json(&SerializableImageGeneration::new(&generation));
+
+        send_with(request, |response| {
+            response.json::<OpenAIImageResponse>()?.decode()
         })
     }
+
+    fn edit_image(&self, edit: ImageEdit) -> Result<ImageResponse, OpenaiError> {
+        let request = self
+            .post_model("/images/edits", edit.model.as_deref())?
+            .multipart(&images::edit_form(&edit));
+
+        send_with(request, |response| {
+            response.json::<OpenAIImageResponse>()?.decode()
+        })
+    }
+
+    fn create_transcription(
+        &self,
+        transcription: Transcription,
+    ) -> Result<TranscriptionResponse, OpenaiError> {
+        let request = self
+            .post_model("/audio/transcriptions", Some(&transcription.model))?
+            .multipart(&audio::transcription_form(&transcription));
+
+        send_with(request, |response| {
+            audio::parse_transcription(response, transcription.response_format)
+        })
+    }
+
+    fn create_translation(
+        &self,
+        translation: Translation,
+    ) -> Result<TranscriptionResponse, OpenaiError> {
+        let request = self
+            .post_model("/audio/translations", Some(&translation.model))?
+            .multipart(&audio::translation_form(&translation));
+
+        send_with(request, |response| {
+            audio::parse_transcription(response, translation.response_format)
+        })
+    }
+
+    fn create_speech(&self, speech: Speech) -> Result<Vec<u8>, OpenaiError> {
+        let response = self
+            .post_model("/audio/speech", Some(&speech.model))?
+            .json(&SerializableSpeech::new(&speech))
+            .send()?;
+
+        Ok(response.into_body())
+    }
+
+    fn list_models(&self) -> Result<Vec<ModelInfo>, OpenaiError> {
+        let list: OpenAIModelList = send_json(self.request(Method::Get, "/models")?)?;
+
+        Ok(list.data.into_iter().map(Into::into).collect())
+    }
+
+    fn retrieve_model(&self, id: String) -> Result<ModelInfo, OpenaiError> {
+        let id = utf8_percent_encode(&id, PATH_SEGMENT);
+        let model: OpenAIModel = send_json(self.request(Method::Get, &format!("/models/{id}"))?)?;
+
+        Ok(model.into())
+    }
+
+    fn create_response(&self, request: ResponseRequest) -> Result<ModelResponse, OpenaiError> {
+        let serializable = SerializableResponseRequest::new(&request)?;
+        let response: OpenAIResponse = send_json(
+            self.request(Method::Post, "/responses")?
+                .json(&serializable),
+        )?;
+
+        Ok(response.into())
+    }
+
+    fn retrieve_response(&self, id: String) -> Result<ModelResponse, OpenaiError> {
+        let response: OpenAIResponse =
+            send_json(self.request(Method::Get, &format!("/responses/{id}"))?)?;
+
+        Ok(response.into())
+    }
+
+    fn delete_response(&self, id: String) -> Result<(), OpenaiError> {
+        self.request(Method::Delete, &format!("/responses/{id}"))?
+            .send()?;
+
+        Ok(())
+    }
+
+    fn upload_file(&self, file: FileUpload, purpose: String) -> Result<FileObject, OpenaiError> {
+        let form = upload(Form::new().text("purpose", purpose), "file", &file);
+        let file: OpenAIFile = send_json(self.request(Method::Post, "/files")?.multipart(&form))?;
+
+        Ok(file.into())
+    }
+
+    fn retrieve_file_content(&self, file_id: String) -> Result<Vec<u8>, OpenaiError> {
+        let response = self
+            .request(Method::Get, &format!("/files/{file_id}/content"))?
+            .send()?;
+
+        Ok(response.into_body())
+    }
+
+    fn create_batch(&self, request: BatchRequest) -> Result<Batch, OpenaiError> {
+        let batch: OpenAIBatch = send_json(
+            self.request(Method::Post, "/batches")?
+                .json(&SerializableBatchRequest::new(&request)),
+        )?;
+
+        Ok(batch.into())
+    }
+
+    fn retrieve_batch(&self, id: String) -> Result<Batch, OpenaiError> {
+        let batch: OpenAIBatch = send_json(self.request(Method::Get, &format!("/batches/{id}"))?)?;
+
+        Ok(batch.into())
+    }
+
+    fn cancel_batch(&self, id: String) -> Result<Batch, OpenaiError> {
+        let batch: OpenAIBatch =
+            send_json(self.request(Method::Post, &format!("/batches/{id}/cancel"))?)?;
+
+        Ok(batch.into())
+    }
+
+    fn list_batches(
+        &self,
+        after: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<BatchList, OpenaiError> {
+        let mut request = self.request(Method::Get, "/batches")?;
+        if let Some(after) = after {
+            request = request.query("after", after);
+        }
+        if let Some(limit) = limit {
+            request = request.query("limit", limit.to_string());
+        }
+        let list: OpenAIBatchList = send_json(request)?;
+
+        Ok(list.into())
+    }
+
+    fn last_rate_limit(&self) -> Option<RateLimitStatus> {
+        rate_limit_status(&self.http().ok()?.last_response_headers()?)
+    }
+
+    fn last_request_id(&self) -> Option<String> {
+        request_id(&self.http().ok()?.last_response_headers()?)
+    }
 }
 
 bindings::export!(Component with_types_in bindings);
@@ -162,10 +1308,22 @@ mod tests {
     use std::env;
 
     use super::*;
-    use crate::bindings::{ChatCompletion, ChatMessage, Embedding, Guest};
-
-    fn get_api_key() -> Option<String> {
-        env::var("OPENAI_SK").ok()
+    use crate::bindings::wassemble::openai::types::{
+        AzureConfig, ChatCompletion, ChatMessage, Config, Embedding, ImageUrl, JsonSchemaFormat,
+        MessageContent, ResponseFormat, Tool, ToolCall, ToolChoice,
+    };
+
+    fn get_config() -> Option<Config> {
+        Some(Config {
+            api_key: env::var("OPENAI_SK").ok()?,
+            base_url: env::var("OPENAI_BASE_URL").ok(),
+            user_agent: None,
+            organization: None,
+            project: None,
+            connect_timeout_ms: None,
+            retry: None,
+            azure: None,
+        })
     }
 
     fn create_test_chat_completion() -> ChatCompletion {
@@ -175,15 +1333,38 @@ mod tests {
             messages: vec![
                 ChatMessage {
                     role: "system".to_string(),
-                    content: "You are a helpful assistant.".to_string(),
+                    content: MessageContent::Text("You are a helpful assistant.".to_string()),
+                    tool_calls: Vec::new(),
+                    tool_call_id: None,
                 },
                 ChatMessage {
                     role: "user".to_string(),
-                    content: "Say hello in exactly 3 words.".to_string(),
+                    content: MessageContent::Text("Say hello in exactly 3 words.".to_string()),
+                    tool_calls: Vec::new(),
+                    tool_call_id: None,
                 },
             ],
             temperature: Some(0.7),
             max_tokens: Some(50),
+            max_completion_tokens: None,
+            top_p: None,
+            stop: Vec::new(),
+            seed: None,
+            presence_penalty: None,
+            frequency_penalty: None,
+            logit_bias: Vec::new(),
+            reasoning_effort: None,
+            user: None,
+            tools: Vec::new(),
+            tool_choice: None,
+            parallel_tool_calls: None,
+            response_format: None,
+            n: None,
+            logprobs: None,
+            top_logprobs: None,
+            store: None,
+            metadata: Vec::new(),
+            client_request_id: None,
         }
     }
 
@@ -191,16 +1372,18 @@ mod tests {
         Embedding {
             model: "text-embedding-ada-002".to_string(),
             input: "Hello, world!".to_string(),
+            client_request_id: None,
         }
     }
 
     #[test]
     fn test_create_chat_completion_success() {
         run_if_api_key_available(|| {
-            let api_key = get_api_key().unwrap();
+            let config = get_config().unwrap();
             let completion = create_test_chat_completion();
 
-            let response = Component::create_chat_completion(api_key, completion)
+            let response = OpenAIClient::new(config)
+                .create_chat_completion(completion)
                 .expect("Chat completion should succeed");
 
             // Verify response structure
@@ -238,14 +1421,15 @@ mod tests {
     #[test]
     fn test_create_chat_completion_with_different_models() {
         run_if_api_key_available(|| {
-            let api_key = get_api_key().unwrap();
+            let config = get_config().unwrap();
             let models = vec!["gpt-3.5-turbo", "gpt-4"];
 
             for model in models {
                 let mut completion = create_test_chat_completion();
                 completion.model = model.to_string();
 
-                let response = Component::create_chat_completion(api_key.clone(), completion)
+                let response = OpenAIClient::new(config.clone())
+                    .create_chat_completion(completion)
                     .expect("Chat completion should succeed");
 
                 assert!(
@@ -264,14 +1448,15 @@ mod tests {
     #[test]
     fn test_create_chat_completion_with_temperature_variations() {
         run_if_api_key_available(|| {
-            let api_key = get_api_key().unwrap();
+            let config = get_config().unwrap();
             let temperatures = vec![0.0, 0.5, 1.0];
 
             for temp in temperatures {
                 let mut completion = create_test_chat_completion();
                 completion.temperature = Some(temp);
 
-                let response = Component::create_chat_completion(api_key.clone(), completion)
+                let response = OpenAIClient::new(config.clone())
+                    .create_chat_completion(completion)
                     .expect("Chat completion should succeed");
 
                 assert!(
@@ -286,11 +1471,12 @@ mod tests {
     #[test]
     fn test_create_chat_completion_with_max_tokens() {
         run_if_api_key_available(|| {
-            let api_key = get_api_key().unwrap();
+            let config = get_config().unwrap();
             let mut completion = create_test_chat_completion();
             completion.max_tokens = Some(10);
 
-            let response = Component::create_chat_completion(api_key, completion)
+            let response = OpenAIClient::new(config)
+                .create_chat_completion(completion)
                 .expect("Chat completion should succeed");
 
             assert!(
@@ -304,11 +1490,12 @@ mod tests {
     #[test]
     fn test_create_embedding_success() {
         run_if_api_key_available(|| {
-            let api_key = get_api_key().unwrap();
+            let config = get_config().unwrap();
             let embedding = create_test_embedding();
 
-            let response =
-                Component::create_embedding(api_key, embedding).expect("Embedding should succeed");
+            let response = OpenAIClient::new(config)
+                .create_embedding(embedding)
+                .expect("Embedding should succeed");
 
             // Verify response structure
             assert!(
@@ -346,7 +1533,7 @@ mod tests {
     #[test]
     fn test_create_embedding_with_different_inputs() {
         run_if_api_key_available(|| {
-            let api_key = get_api_key().unwrap();
+            let config = get_config().unwrap();
             let inputs = vec![
                 "Short text",
                 "This is a longer piece of text that should still work fine with the embedding API.",
@@ -358,9 +1545,11 @@ mod tests {
                 let embedding = Embedding {
                     model: "text-embedding-ada-002".to_string(),
                     input: input.to_string(),
+                    client_request_id: None,
                 };
 
-                let response = Component::create_embedding(api_key.clone(), embedding)
+                let response = OpenAIClient::new(config.clone())
+                    .create_embedding(embedding)
                     .expect("Embedding should succeed");
 
                 assert!(
@@ -381,12 +1570,14 @@ mod tests {
     #[test]
     fn test_create_embedding_reproducibility() {
         run_if_api_key_available(|| {
-            let api_key = get_api_key().unwrap();
+            let config = get_config().unwrap();
             let embedding = create_test_embedding();
 
-            let response1 = Component::create_embedding(api_key.clone(), embedding.clone())
+            let response1 = OpenAIClient::new(config.clone())
+                .create_embedding(embedding.clone())
                 .expect("First embedding should succeed");
-            let response2 = Component::create_embedding(api_key, embedding)
+            let response2 = OpenAIClient::new(config)
+                .create_embedding(embedding)
                 .expect("Second embedding should succeed");
 
             // Embeddings should be identical for the same input
@@ -403,35 +1594,61 @@ mod tests {
     #[test]
     fn test_create_chat_completion_invalid_api_key() {
         run_if_api_key_available(|| {
-            let invalid_api_key = "invalid_key".to_string();
+            let invalid_config = Config {
+                api_key: "invalid_key".to_string(),
+                base_url: None,
+                user_agent: None,
+                organization: None,
+                project: None,
+                connect_timeout_ms: None,
+                retry: None,
+                azure: None,
+            };
             let completion = create_test_chat_completion();
 
-            let result = std::panic::catch_unwind(|| {
-                let _ = Component::create_chat_completion(invalid_api_key, completion);
-            });
+            let result = OpenAIClient::new(invalid_config).create_chat_completion(completion);
 
-            assert!(result.is_err(), "Should panic with invalid API key");
+            assert!(matches!(
+                result,
+                Err(OpenaiError {
+                    kind: ErrorKind::Authentication(_),
+                    ..
+                })
+            ));
         });
     }
 
     #[test]
     fn test_create_embedding_invalid_api_key() {
         run_if_api_key_available(|| {
-            let invalid_api_key = "invalid_key".to_string();
+            let invalid_config = Config {
+                api_key: "invalid_key".to_string(),
+                base_url: None,
+                user_agent: None,
+                organization: None,
+                project: None,
+                connect_timeout_ms: None,
+                retry: None,
+                azure: None,
+            };
             let embedding = create_test_embedding();
 
-            let result = std::panic::catch_unwind(|| {
-                let _ = Component::create_embedding(invalid_api_key, embedding);
-            });
+            let result = OpenAIClient::new(invalid_config).create_embedding(embedding);
 
-            assert!(result.is_err(), "Should panic with invalid API key");
+            assert!(matches!(
+                result,
+                Err(OpenaiError {
+                    kind: ErrorKind::Authentication(_),
+                    ..
+                })
+            ));
         });
     }
 
     #[test]
     fn test_create_chat_completion_conversation_flow() {
         run_if_api_key_available(|| {
-            let api_key = get_api_key().unwrap();
+            let config = get_config().unwrap();
 
             // First message
             let completion1 = ChatCompletion {
@@ -439,13 +1656,35 @@ mod tests {
                 model: "gpt-3.5-turbo".to_string(),
                 messages: vec![ChatMessage {
                     role: "user".to_string(),
-                    content: "What is 2+2?".to_string(),
+                    content: MessageContent::Text("What is 2+2?".to_string()),
+                    tool_calls: Vec::new(),
+                    tool_call_id: None,
                 }],
                 temperature: Some(0.1),
                 max_tokens: Some(100),
+                max_completion_tokens: None,
+                top_p: None,
+                stop: Vec::new(),
+                seed: None,
+                presence_penalty: None,
+                frequency_penalty: None,
+                logit_bias: Vec::new(),
+                reasoning_effort: None,
+                user: None,
+                tools: Vec::new(),
+                tool_choice: None,
+                parallel_tool_calls: None,
+                response_format: None,
+                n: None,
+                logprobs: None,
+                top_logprobs: None,
+                store: None,
+                metadata: Vec::new(),
+                client_request_id: None,
             };
 
-            let response1 = Component::create_chat_completion(api_key.clone(), completion1)
+            let response1 = OpenAIClient::new(config.clone())
+                .create_chat_completion(completion1)
                 .expect("First chat completion should succeed");
             assert!(!response1.content.is_empty());
 
@@ -456,22 +1695,48 @@ mod tests {
                 messages: vec![
                     ChatMessage {
                         role: "user".to_string(),
-                        content: "What is 2+2?".to_string(),
+                        content: MessageContent::Text("What is 2+2?".to_string()),
+                        tool_calls: Vec::new(),
+                        tool_call_id: None,
                     },
                     ChatMessage {
                         role: "assistant".to_string(),
-                        content: response1.content.clone(),
+                        content: MessageContent::Text(response1.content.clone()),
+                        tool_calls: Vec::new(),
+                        tool_call_id: None,
                     },
                     ChatMessage {
                         role: "user".to_string(),
-                        content: "Now what is 3+3?".to_string(),
+                        content: MessageContent::Text("Now what is 3+3?".to_string()),
+                        tool_calls: Vec::new(),
+                        tool_call_id: None,
                     },
                 ],
                 temperature: Some(0.1),
                 max_tokens: Some(100),
+                max_completion_tokens: None,
+                top_p: None,
+                stop: Vec::new(),
+                seed: None,
+                presence_penalty: None,
+                frequency_penalty: None,
+                logit_bias: Vec::new(),
+                reasoning_effort: None,
+                user: None,
+                tools: Vec::new(),
+                tool_choice: None,
+                parallel_tool_calls: None,
+                response_format: None,
+                n: None,
+                logprobs: None,
+                top_logprobs: None,
+                store: None,
+                metadata: Vec::new(),
+                client_request_id: None,
             };
 
-            let response2 = Component::create_chat_completion(api_key, completion2)
+            let response2 = OpenAIClient::new(config)
+                .create_chat_completion(completion2)
                 .expect("Second chat completion should succeed");
             assert!(!response2.content.is_empty());
             assert_ne!(
@@ -496,11 +1761,301 @@ mod tests {
     #[test]
     fn test_conditional_execution() {
         run_if_api_key_available(|| {
-            let api_key = get_api_key().unwrap();
+            let config = get_config().unwrap();
             let completion = create_test_chat_completion();
-            let response = Component::create_chat_completion(api_key, completion)
+            let response = OpenAIClient::new(config)
+                .create_chat_completion(completion)
                 .expect("Chat completion should succeed");
             assert!(!response.content.is_empty());
         });
     }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("20ms"), Some(Duration::from_millis(20)));
+        assert_eq!(parse_duration("1s"), Some(Duration::from_secs(1)));
+        assert_eq!(parse_duration("6m0s"), Some(Duration::from_secs(360)));
+        assert_eq!(
+            parse_duration("1h2m3.5s"),
+            Some(Duration::from_millis(3_723_500))
+        );
+        assert_eq!(parse_duration("soon"), None);
+        assert_eq!(parse_duration("5"), None);
+    }
+
+    #[test]
+    fn test_rate_limit_status() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-limit-requests", "60".parse().unwrap());
+        headers.insert("x-ratelimit-limit-tokens", "150000".parse().unwrap());
+        headers.insert("x-ratelimit-remaining-requests", "59".parse().unwrap());
+        headers.insert("x-ratelimit-remaining-tokens", "149984".parse().unwrap());
+        headers.insert("x-ratelimit-reset-requests", "1s".parse().unwrap());
+        headers.insert("x-ratelimit-reset-tokens", "6m0s".parse().unwrap());
+
+        let status = rate_limit_status(&headers).unwrap();
+        assert_eq!(status.limit_requests, Some(60));
+        assert_eq!(status.remaining_tokens, Some(149984));
+        assert_eq!(status.reset_requests_ms, Some(1000));
+        assert_eq!(status.reset_tokens_ms, Some(360_000));
+
+        assert!(rate_limit_status(&HeaderMap::new()).is_none());
+    }
+
+    #[test]
+    fn test_classify_api_errors() {
+        let error = |status, body: &str| {
+            let body: OpenAIErrorBody = serde_json::from_str(body).unwrap();
+            body.error.into_error(status, None)
+        };
+        let invalid = r#"{"error":{"message":"bad","type":"invalid_request_error","param":null,"code":null}}"#;
+        assert!(matches!(error(401, invalid), ErrorKind::Authentication(_)));
+        assert!(matches!(
+            error(403, invalid),
+            ErrorKind::PermissionDenied(_)
+        ));
+        assert!(matches!(error(404, invalid), ErrorKind::NotFound(_)));
+        assert!(matches!(error(422, invalid), ErrorKind::InvalidRequest(_)));
+        assert!(matches!(error(503, invalid), ErrorKind::ServerError(_)));
+        assert!(matches!(error(429, invalid), ErrorKind::RateLimited(_)));
+        assert!(matches!(error(200, invalid), ErrorKind::InvalidRequest(_)));
+        assert!(matches!(error(302, invalid), ErrorKind::RequestFailed(_)));
+
+        let quota = r#"{"error":{"message":"quota","type":"insufficient_quota","code":"insufficient_quota"}}"#;
+        assert!(matches!(error(429, quota), ErrorKind::InsufficientQuota(_)));
+        let context = r#"{"error":{"message":"too long","param":"messages","code":"context_length_exceeded"}}"#;
+        assert!(matches!(
+            error(400, context),
+            ErrorKind::ContextLengthExceeded(_)
+        ));
+
+        let ErrorKind::ServerError(numeric) =
+            error(500, r#"{"error":{"message":"oops","code":500}}"#)
+        else {
+            panic!("expected a server error");
+        };
+        assert_eq!(numeric.code.as_deref(), Some("500"));
+        assert!(numeric.type_.is_none());
+    }
+
+    #[test]
+    fn test_serialize_tool_conversation() {
+        let mut completion = create_test_chat_completion();
+        completion.messages = vec![
+            ChatMessage {
+                role: "assistant".to_string(),
+                content: MessageContent::Text(String::new()),
+                tool_calls: vec![ToolCall {
+                    id: "call_1".to_string(),
+                    name: "get_weather".to_string(),
+                    arguments: r#"{"location":"Paris"}"#.to_string(),
+                }],
+                tool_call_id: None,
+            },
+            ChatMessage {
+                role: "tool".to_string(),
+                content: MessageContent::Text("18°C".to_string()),
+                tool_calls: Vec::new(),
+                tool_call_id: Some("call_1".to_string()),
+            },
+        ];
+        completion.tools = vec![Tool {
+            name: "get_weather".to_string(),
+            description: None,
+            parameters: Some(r#"{"type":"object","properties":{}}"#.to_string()),
+            strict: Some(true),
+        }];
+        completion.tool_choice = Some(ToolChoice::Function("get_weather".to_string()));
+
+        let body =
+            serde_json::to_value(SerializableChatCompletion::new(&completion).unwrap()).unwrap();
+        assert_eq!(body["messages"][0]["content"], Value::Null);
+        assert_eq!(body["messages"][0]["tool_calls"][0]["type"], "function");
+        assert_eq!(
+            body["messages"][0]["tool_calls"][0]["function"]["arguments"],
+            r#"{"location":"Paris"}"#
+        );
+        assert_eq!(body["messages"][1]["tool_call_id"], "call_1");
+        assert!(body["messages"][1].get("tool_calls").is_none());
+        assert_eq!(body["tools"][0]["function"]["parameters"]["type"], "object");
+        assert!(body["tools"][0]["function"].get("description").is_none());
+        assert_eq!(body["tool_choice"]["function"]["name"], "get_weather");
+
+        completion.tool_choice = Some(ToolChoice::Required);
+        let body =
+            serde_json::to_value(SerializableChatCompletion::new(&completion).unwrap()).unwrap();
+        assert_eq!(body["tool_choice"], "required");
+    }
+
+    #[test]
+    fn test_invalid_tool_parameters() {
+        let mut completion = create_test_chat_completion();
+        completion.tools = vec![Tool {
+            name: "get_weather".to_string(),
+            description: None,
+            parameters: Some("{not json".to_string()),
+            strict: None,
+        }];
+        let Err(ErrorKind::JsonParseError(message)) = SerializableChatCompletion::new(&completion)
+        else {
+            panic!("invalid schema should be rejected");
+        };
+        assert!(message.contains("get_weather"));
+    }
+
+    #[test]
+    fn test_serialize_response_format() {
+        let mut completion = create_test_chat_completion();
+        completion.response_format = Some(ResponseFormat::JsonSchema(JsonSchemaFormat {
+            name: "greeting".to_string(),
+            description: None,
+            schema: r#"{"type":"object","properties":{"text":{"type":"string"}}}"#.to_string(),
+            strict: Some(true),
+        }));
+        let body =
+            serde_json::to_value(SerializableChatCompletion::new(&completion).unwrap()).unwrap();
+        let format = &body["response_format"];
+        assert_eq!(format["type"], "json_schema");
+        assert_eq!(format["json_schema"]["name"], "greeting");
+        assert_eq!(format["json_schema"]["strict"], true);
+        assert_eq!(format["json_schema"]["schema"]["type"], "object");
+
+        completion.response_format = Some(ResponseFormat::JsonObject);
+        let body =
+            serde_json::to_value(SerializableChatCompletion::new(&completion).unwrap()).unwrap();
+        assert_eq!(
+            body["response_format"],
+            serde_json::json!({"type": "json_object"})
+        );
+    }
+
+    #[test]
+    fn test_serialize_content_parts() {
+        let mut completion = create_test_chat_completion();
+        completion.messages[1].content = MessageContent::Parts(vec![
+            ContentPart::Text("What is in this screenshot?".to_string()),
+            ContentPart::ImageUrl(ImageUrl {
+                url: "data:image/png;base64,iVBORw0KGgo=".to_string(),
+                detail: Some(ImageDetail::High),
+            }),
+            ContentPart::ImageUrl(ImageUrl {
+                url: "https://example.com/screenshot.png".to_string(),
+                detail: None,
+            }),
+            ContentPart::InputAudio(InputAudio {
+                data: "UklGRg==".to_string(),
+                format: AudioFormat::Wav,
+            }),
+        ]);
+
+        let body =
+            serde_json::to_value(SerializableChatCompletion::new(&completion).unwrap()).unwrap();
+        assert_eq!(
+            body["messages"][0]["content"],
+            "You are a helpful assistant."
+        );
+        assert_eq!(
+            body["messages"][1]["content"],
+            serde_json::json!([
+                {"type": "text", "text": "What is in this screenshot?"},
+                {"type": "image_url", "image_url": {"url": "data:image/png;base64,iVBORw0KGgo=", "detail": "high"}},
+                {"type": "image_url", "image_url": {"url": "https://example.com/screenshot.png"}},
+                {"type": "input_audio", "input_audio": {"data": "UklGRg==", "format": "wav"}}
+            ])
+        );
+    }
+
+    #[test]
+    fn test_serialize_sampling_parameters() {
+        let mut completion = create_test_chat_completion();
+        completion.temperature = None;
+        completion.max_tokens = None;
+        let body =
+            serde_json::to_value(SerializableChatCompletion::new(&completion).unwrap()).unwrap();
+        assert_eq!(
+            body.as_object().unwrap().keys().collect::<Vec<_>>(),
+            ["messages", "model"]
+        );
+
+        completion.max_completion_tokens = Some(2000);
+        completion.top_p = Some(0.9);
+        completion.stop = vec!["\n\n".to_string(), "END".to_string()];
+        completion.seed = Some(42);
+        completion.presence_penalty = Some(0.5);
+        completion.frequency_penalty = Some(-0.5);
+        completion.logit_bias = vec![(50256, -100), (1734, 5)];
+        completion.reasoning_effort = Some(ReasoningEffort::Low);
+        completion.user = Some("user-1234".to_string());
+        completion.parallel_tool_calls = Some(false);
+        completion.store = Some(true);
+        completion.metadata = vec![("project".to_string(), "docs".to_string())];
+        let body =
+            serde_json::to_value(SerializableChatCompletion::new(&completion).unwrap()).unwrap();
+        assert_eq!(body["max_completion_tokens"], 2000);
+        assert_eq!(body["top_p"], 0.9);
+        assert_eq!(body["stop"], serde_json::json!(["\n\n", "END"]));
+        assert_eq!(body["seed"], 42);
+        assert_eq!(body["presence_penalty"], 0.5);
+        assert_eq!(body["frequency_penalty"], -0.5);
+        assert_eq!(
+            body["logit_bias"],
+            serde_json::json!({"1734": 5, "50256": -100})
+        );
+        assert_eq!(body["reasoning_effort"], "low");
+        assert_eq!(body["user"], "user-1234");
+        assert_eq!(body["parallel_tool_calls"], false);
+        assert_eq!(body["store"], true);
+        assert_eq!(body["metadata"], serde_json::json!({"project": "docs"}));
+    }
+
+    #[test]
+    fn test_client_base_url() {
+        let config = Config {
+            api_key: "api_key".to_string(),
+            base_url: None,
+            user_agent: None,
+            organization: None,
+            project: None,
+            connect_timeout_ms: None,
+            retry: None,
+            azure: None,
+        };
+        assert_eq!(
+            OpenAIClient::new(config.clone()).http().unwrap().base_url(),
+            OPENAI_API_BASE
+        );
+
+        let config = Config {
+            base_url: Some("http://localhost:11434/v1".to_string()),
+            ..config
+        };
+        assert_eq!(
+            OpenAIClient::new(config).http().unwrap().base_url(),
+            "http://localhost:11434/v1"
+        );
+    }
+
+    #[test]
+    fn test_client_azure_base_url() {
+        let config = Config {
+            api_key: "api_key".to_string(),
+            base_url: Some("http://localhost:11434/v1".to_string()),
+            user_agent: None,
+            organization: None,
+            project: None,
+            connect_timeout_ms: None,
+            retry: None,
+            azure: Some(AzureConfig {
+                endpoint: "https://acme.openai.azure.com/".to_string(),
+                api_version: "2024-10-21".to_string(),
+                entra_id: false,
+            }),
+        };
+        let client = OpenAIClient::new(config);
+        assert_eq!(
+            client.http().unwrap().base_url(),
+            "https://acme.openai.azure.com/openai"
+        );
+        assert_eq!(client.azure_api_version.as_deref(), Some("2024-10-21"));
+    }
 }
@@ -0,0 +1,146 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::bindings::wassemble::openai::types::{
+    Moderation, ModerationCategory, ModerationInput, ModerationPart, ModerationResult,
+};
+
+#[derive(Serialize)]
+pub(crate) struct SerializableModeration<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<&'a str>,
+    input: SerializableModerationInput<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum SerializableModerationInput<'a> {
+    Texts(&'a [String]),
+    Multimodal(Vec<SerializableModerationPart<'a>>),
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+enum SerializableModerationPart<'a> {
+    Text {
+        text: &'a str,
+    },
+    ImageUrl {
+        image_url: SerializableModerationImage<'a>,
+    },
+}
+
+#[derive(Serialize)]
+struct SerializableModerationImage<'a> {
+    url: &'a str,
+}
+
+impl<'a> SerializableModeration<'a> {
+    pub(crate) fn new(moderation: &'a Moderation) -> Self {
+        Self {
+            model: moderation.model.as_deref(),
+            input: match &moderation.input {
+                ModerationInput::Texts(texts) => SerializableModerationInput::Texts(texts),
+                ModerationInput::Multimodal(parts) => SerializableModerationInput::Multimodal(
+                    parts
+                        .iter()
+                        .map(|part| match part {
+                            ModerationPart::Text(text) => SerializableModerationPart::Text { text },
+                            ModerationPart::ImageUrl(url) => SerializableModerationPart::ImageUrl {
+                                image_url: SerializableModerationImage { url },
+                            },
+                        })
+                        .collect(),
+                ),
+            },
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub(crate) struct OpenAIModerationResponse {
+    pub(crate) id: String,
+    pub(crate) model: String,
+    pub(crate) results: Vec<OpenAIModerationResult>,
+}
+
+/// Categories are kept as maps, since OpenAI adds new ones with new models.
+#[derive(Deserialize, Serialize)]
+pub(crate) struct OpenAIModerationResult {
+    flagged: bool,
+    categories: BTreeMap<String, bool>,
+    category_scores: BTreeMap<String, f64>,
+    #[serde(default)]
+    category_applied_input_types: BTreeMap<String, Vec<String>>,
+}
+
+impl From<OpenAIModerationResult> for ModerationResult {
+    fn from(mut result: OpenAIModerationResult) -> Self {
+        ModerationResult {
+            flagged: result.flagged,
+            categories: result
+                .categories
+                .into_iter()
+                .map(|(name, flagged)| ModerationCategory {
+                    score: result.category_scores.remove(&name).unwrap_or_default(),
+                    applied_input_types: result
+                        .category_applied_input_types
+                        .remove(&name)
+                        .unwrap_or_default(),
+                    name,
+                    flagged,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_moderation() {
+        let texts = Moderation {
+            model: None,
+            input: ModerationInput::Texts(vec!["first".to_string(), "second".to_string()]),
+        };
+        assert_eq!(
+            serde_json::to_value(SerializableModeration::new(&texts)).unwrap(),
+            serde_json::json!({"input": ["first", "second"]})
+        );
+
+        let multimodal = Moderation {
+            model: Some("omni-moderation-latest".to_string()),
+            input: ModerationInput::Multimodal(vec![
+                ModerationPart::Text("look at this".to_string()),
+                ModerationPart::ImageUrl("https://example.com/image.png".to_string()),
+            ]),
+        };
+        assert_eq!(
+            serde_json::to_value(SerializableModeration::new(&multimodal)).unwrap(),
+            serde_json::json!({
+                "model": "omni-moderation-latest",
+                "input": [
+                    {"type": "text", "text": "look at this"},
+                    {"type": "image_url", "image_url": {"url": "https://example.com/image.png"}}
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_text_model_result() {
+        // text-moderation models send no applied input types
+        let result: OpenAIModerationResult = serde_json::from_str(
+            r#"{"flagged":false,"categories":{"violence":false,"hate":false},"category_scores":{"violence":0.002,"hate":0.0001}}"#,
+        )
+        .unwrap();
+        let result = ModerationResult::from(result);
+        assert_eq!(result.categories.len(), 2);
+        assert_eq!(result.categories[0].name, "hate");
+        assert_eq!(result.categories[1].score, 0.002);
+        assert!(result.categories[1].applied_input_types.is_empty());
+    }
+}
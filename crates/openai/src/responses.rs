@@ -0,0 +1,519 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    OpenAICompletionTokensDetails, OpenAIPromptTokensDetails, SerializableFunction,
+    SerializableTool,
+    bindings::wassemble::openai::types::{
+        ContentPart, ErrorKind, FunctionCall, InputItem, MessageContent, OutputContent, OutputItem,
+        OutputMessage, Reasoning, Response, ResponseRequest, ResponseTool, ToolChoice, Usage,
+    },
+    image_detail, reasoning_effort,
+};
+
+#[derive(Serialize)]
+pub(crate) struct SerializableResponseRequest<'a> {
+    model: &'a str,
+    input: Vec<SerializableInputItem<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instructions: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous_response_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tools: Vec<SerializableResponseTool<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<SerializableResponseToolChoice<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parallel_tool_calls: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_output_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reasoning: Option<SerializableReasoning>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<bool>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    metadata: BTreeMap<&'a str, &'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+enum SerializableInputItem<'a> {
+    Message {
+        role: &'a str,
+        content: SerializableInputContent<'a>,
+    },
+    FunctionCall {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<&'a str>,
+        call_id: &'a str,
+        name: &'a str,
+        arguments: &'a str,
+    },
+    FunctionCallOutput {
+        call_id: &'a str,
+        output: &'a str,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum SerializableInputContent<'a> {
+    Text(&'a str),
+    Parts(Vec<SerializableInputPart<'a>>),
+}
+
+/// Content parts of the Responses API, which differ from those of chat messages.
+#[derive(Serialize)]
+#[serde(tag = "type")]
+enum SerializableInputPart<'a> {
+    #[serde(rename = "input_text")]
+    Text { text: &'a str },
+    #[serde(rename = "input_image")]
+    Image {
+        image_url: &'a str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        detail: Option<&'static str>,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+enum SerializableResponseTool<'a> {
+    // function tools are flat here, not nested under "function" as in chat completions
+    Function(SerializableFunction<'a>),
+    WebSearch,
+    FileSearch {
+        vector_store_ids: &'a [String],
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_num_results: Option<u32>,
+    },
+    CodeInterpreter {
+        container: SerializableContainer,
+    },
+}
+
+#[derive(Serialize)]
+struct SerializableContainer {
+    r#type: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum SerializableResponseToolChoice<'a> {
+    Mode(&'static str),
+    Function { r#type: &'static str, name: &'a str },
+}
+
+#[derive(Serialize)]
+struct SerializableReasoning {
+    effort: &'static str,
+}
+
+impl<'a> SerializableResponseRequest<'a> {
+    pub(crate) fn new(request: &'a ResponseRequest) -> Result<Self, ErrorKind> {
+        let tools = request
+            .tools
+            .iter()
+            .map(SerializableResponseTool::new)
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            model: &request.model,
+            input: request
+                .input
+                .iter()
+                .map(SerializableInputItem::new)
+                .collect::<Result<_, _>>()?,
+            instructions: request.instructions.as_deref(),
+            previous_response_id: request.previous_response_id.as_deref(),
+            tools,
+            tool_choice: request.tool_choice.as_ref().map(|choice| match choice {
+                ToolChoice::None => SerializableResponseToolChoice::Mode("none"),
+                ToolChoice::Auto => SerializableResponseToolChoice::Mode("auto"),
+                ToolChoice::Required => SerializableResponseToolChoice::Mode("required"),
+                ToolChoice::Function(name) => SerializableResponseToolChoice::Function {
+                    r#type: "function",
+                    name,
+                },
+            }),
+            parallel_tool_calls: request.parallel_tool_calls,
+            temperature: request.temperature,
+            top_p: request.top_p,
+            max_output_tokens: request.max_output_tokens,
+            reasoning: request
+                .reasoning_effort
+                .map(|effort| SerializableReasoning {
+                    effort: reasoning_effort(effort),
+                }),
+            store: request.store,
+            metadata: request
+                .metadata
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.as_str()))
+                .collect(),
+            user: request.user.as_deref(),
+        })
+    }
+}
+
+impl<'a> SerializableInputItem<'a> {
+    fn new(item: &'a InputItem) -> Result<Self, ErrorKind> {
+        Ok(match item {
+            InputItem::Message(message) => SerializableInputItem::Message {
+                role: &message.role,
+                content: match &message.content {
+                    MessageContent::Text(text) => SerializableInputContent::Text(text),
+                    MessageContent::Parts(parts) => SerializableInputContent::Parts(
+                        parts
+                            .iter()
+                            .map(SerializableInputPart::new)
+                            .collect::<Result<_, _>>()?,
+                    ),
+                },
+            },
+            InputItem::FunctionCall(call) => SerializableInputItem::FunctionCall {
+                id: call.id.as_deref(),
+                call_id: &call.call_id,
+                name: &call.name,
+                arguments: &call.arguments,
+            },
+            InputItem::FunctionCallOutput(output) => SerializableInputItem::FunctionCallOutput {
+                call_id: &output.call_id,
+                output: &output.output,
+            },
+        })
+    }
+}
+
+impl<'a> SerializableInputPart<'a> {
+    fn new(part: &'a ContentPart) -> Result<Self, ErrorKind> {
+        match part {
+            ContentPart::Text(text) => Ok(SerializableInputPart::Text { text }),
+            ContentPart::ImageUrl(image) => Ok(SerializableInputPart::Image {
+                image_url: &image.url,
+                detail: image.detail.map(image_detail),
+            }),
+            ContentPart::InputAudio(_) => Err(ErrorKind::Unsupported(
+                "The Responses API does not accept input-audio content parts".to_string(),
+            )),
+        }
+    }
+}
+
+impl<'a> SerializableResponseTool<'a> {
+    fn new(tool: &'a ResponseTool) -> Result<Self, ErrorKind> {
+        Ok(match tool {
+            ResponseTool::Function(tool) => {
+                SerializableResponseTool::Function(SerializableTool::new(tool)?.function)
+            }
+            ResponseTool::WebSearch => SerializableResponseTool::WebSearch,
+            ResponseTool::FileSearch(search) => SerializableResponseTool::FileSearch {
+                vector_store_ids: &search.vector_store_ids,
+                max_num_results: search.max_num_results,
+            },
+            ResponseTool::CodeInterpreter => SerializableResponseTool::CodeInterpreter {
+                container: SerializableContainer { r#type: "auto" },
+            },
+        })
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub(crate) struct OpenAIResponse {
+    id: String,
+    created_at: u64,
+    model: String,
+    status: String,
+    incomplete_details: Option<OpenAIIncompleteDetails>,
+    #[serde(default)]
+    output: Vec<OpenAIOutputItem>,
+    previous_response_id: Option<String>,
+    usage: Option<OpenAIResponseUsage>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAIIncompleteDetails {
+    reason: Option<String>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+enum OpenAIOutputItem {
+    Message {
+        id: String,
+        role: String,
+        #[serde(default)]
+        content: Vec<OpenAIOutputContent>,
+    },
+    FunctionCall {
+        id: Option<String>,
+        call_id: String,
+        name: String,
+        arguments: String,
+    },
+    Reasoning {
+        id: String,
+        #[serde(default)]
+        summary: Vec<OpenAISummaryText>,
+    },
+    // built-in tool calls and item types added to the API later
+    #[serde(untagged)]
+    Other { r#type: String },
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+enum OpenAIOutputContent {
+    OutputText { text: String },
+    Refusal { refusal: String },
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAISummaryText {
+    text: String,
+}
+
+#[derive(Deserialize, Serialize)]
+struct OpenAIResponseUsage {
+    input_tokens: u32,
+    output_tokens: u32,
+    total_tokens: u32,
+    input_tokens_details: Option<OpenAIPromptTokensDetails>,
+    output_tokens_details: Option<OpenAICompletionTokensDetails>,
+}
+
+impl From<OpenAIResponse> for Response {
+    fn from(response: OpenAIResponse) -> Self {
+        let output: Vec<OutputItem> = response.output.into_iter().map(Into::into).collect();
+        let output_text = output
+            .iter()
+            .filter_map(|item| match item {
+                OutputItem::Message(message) => Some(&message.content),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|content| match content {
+                OutputContent::Text(text) => Some(text.as_str()),
+                OutputContent::Refusal(_) => None,
+            })
+            .collect();
+        Response {
+            id: response.id,
+            created_at: response.created_at,
+            model: response.model,
+            status: response.status,
+            incomplete_reason: response
+                .incomplete_details
+                .and_then(|details| details.reason),
+            output,
+            output_text,
+            previous_response_id: response.previous_response_id,
+            usage: response.usage.map(Into::into),
+        }
+    }
+}
+
+impl From<OpenAIOutputItem> for OutputItem {
+    fn from(item: OpenAIOutputItem) -> Self {
+        match item {
+            OpenAIOutputItem::Message { id, role, content } => OutputItem::Message(OutputMessage {
+                id,
+                role,
+                content: content
+                    .into_iter()
+                    .map(|content| match content {
+                        OpenAIOutputContent::OutputText { text } => OutputContent::Text(text),
+                        OpenAIOutputContent::Refusal { refusal } => OutputContent::Refusal(refusal),
+                    })
+                    .collect(),
+            }),
+            OpenAIOutputItem::FunctionCall {
+                id,
+                call_id,
+                name,
+                arguments,
+            } => OutputItem::FunctionCall(FunctionCall {
+                id,
+                call_id,
+                name,
+                arguments,
+            }),
+            OpenAIOutputItem::Reasoning { id, summary } => OutputItem::Reasoning(Reasoning {
+                id,
+                summary: summary.into_iter().map(|summary| summary.text).collect(),
+            }),
+            OpenAIOutputItem::Other { r#type } => OutputItem::Other(r#type),
+        }
+    }
+}
+
+impl From<OpenAIResponseUsage> for Usage {
+    fn from(usage: OpenAIResponseUsage) -> Self {
+        Usage {
+            prompt_tokens: usage.input_tokens,
+            completion_tokens: usage.output_tokens,
+            total_tokens: usage.total_tokens,
+            cached_tokens: usage
+                .input_tokens_details
+                .and_then(|details| details.cached_tokens),
+            reasoning_tokens: usage
+                .output_tokens_details
+                .and_then(|details| details.reasoning_tokens),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bindings::wassemble::openai::types::{
+        AudioFormat, FileSearchTool, FunctionCallOutput, InputAudio, InputMessage, ReasoningEffort,
+        Tool,
+    };
+
+    fn request() -> ResponseRequest {
+        ResponseRequest {
+            model: "gpt-4.1".to_string(),
+            input: vec![InputItem::Message(InputMessage {
+                role: "user".to_string(),
+                content: MessageContent::Text("What is the weather in Paris?".to_string()),
+            })],
+            instructions: None,
+            previous_response_id: None,
+            tools: Vec::new(),
+            tool_choice: None,
+            parallel_tool_calls: None,
+            temperature: None,
+            top_p: None,
+            max_output_tokens: None,
+            reasoning_effort: None,
+            store: None,
+            metadata: Vec::new(),
+            user: None,
+        }
+    }
+
+    #[test]
+    fn test_serialize_response_request() {
+        let mut request = request();
+        let body =
+            serde_json::to_value(SerializableResponseRequest::new(&request).unwrap()).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "model": "gpt-4.1",
+                "input": [{"type": "message", "role": "user", "content": "What is the weather in Paris?"}]
+            })
+        );
+
+        request.input.push(InputItem::FunctionCall(FunctionCall {
+            id: None,
+            call_id: "call_1".to_string(),
+            name: "get_weather".to_string(),
+            arguments: r#"{"city":"Paris"}"#.to_string(),
+        }));
+        request
+            .input
+            .push(InputItem::FunctionCallOutput(FunctionCallOutput {
+                call_id: "call_1".to_string(),
+                output: "18°C".to_string(),
+            }));
+        request.tools = vec![
+            ResponseTool::Function(Tool {
+                name: "get_weather".to_string(),
+                description: None,
+                parameters: Some(r#"{"type":"object"}"#.to_string()),
+                strict: Some(true),
+            }),
+            ResponseTool::WebSearch,
+            ResponseTool::FileSearch(FileSearchTool {
+                vector_store_ids: vec!["vs_1".to_string()],
+                max_num_results: None,
+            }),
+            ResponseTool::CodeInterpreter,
+        ];
+        request.tool_choice = Some(ToolChoice::Function("get_weather".to_string()));
+        request.reasoning_effort = Some(ReasoningEffort::High);
+        let body =
+            serde_json::to_value(SerializableResponseRequest::new(&request).unwrap()).unwrap();
+        assert_eq!(
+            body["input"][1],
+            serde_json::json!({"type": "function_call", "call_id": "call_1", "name": "get_weather", "arguments": "{\"city\":\"Paris\"}"})
+        );
+        assert_eq!(body["input"][2]["type"], "function_call_output");
+        assert_eq!(
+            body["tools"][0],
+            serde_json::json!({"type": "function", "name": "get_weather", "parameters": {"type": "object"}, "strict": true})
+        );
+        assert_eq!(body["tools"][1], serde_json::json!({"type": "web_search"}));
+        assert_eq!(body["tools"][2]["vector_store_ids"][0], "vs_1");
+        assert_eq!(body["tools"][3]["container"]["type"], "auto");
+        assert_eq!(
+            body["tool_choice"],
+            serde_json::json!({"type": "function", "name": "get_weather"})
+        );
+        assert_eq!(body["reasoning"]["effort"], "high");
+    }
+
+    #[test]
+    fn test_input_audio_is_unsupported() {
+        let mut request = request();
+        request.input = vec![InputItem::Message(InputMessage {
+            role: "user".to_string(),
+            content: MessageContent::Parts(vec![
+                ContentPart::Text("What is said here?".to_string()),
+                ContentPart::InputAudio(InputAudio {
+                    data: "UklGRg==".to_string(),
+                    format: AudioFormat::Wav,
+                }),
+            ]),
+        })];
+        assert!(matches!(
+            SerializableResponseRequest::new(&request),
+            Err(ErrorKind::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn test_output_items() {
+        let response: OpenAIResponse = serde_json::from_str(
+            r#"{
+                "id": "resp_1",
+                "object": "response",
+                "created_at": 1741476542,
+                "status": "completed",
+                "model": "o4-mini-2025-04-16",
+                "output": [
+                    {"type": "reasoning", "id": "rs_1", "summary": [{"type": "summary_text", "text": "Looking up the weather."}]},
+                    {"type": "web_search_call", "id": "ws_1", "status": "completed"},
+                    {"type": "function_call", "id": "fc_1", "call_id": "call_1", "name": "get_weather", "arguments": "{}", "status": "completed"},
+                    {"type": "message", "id": "msg_1", "status": "completed", "role": "assistant", "content": [
+                        {"type": "output_text", "text": "It is ", "annotations": []},
+                        {"type": "output_text", "text": "sunny.", "annotations": []}
+                    ]}
+                ],
+                "usage": {"input_tokens": 36, "input_tokens_details": {"cached_tokens": 0}, "output_tokens": 87, "output_tokens_details": {"reasoning_tokens": 64}, "total_tokens": 123}
+            }"#,
+        )
+        .unwrap();
+        let response = Response::from(response);
+        assert_eq!(response.output.len(), 4);
+        assert!(
+            matches!(&response.output[0], OutputItem::Reasoning(reasoning) if reasoning.summary == ["Looking up the weather."])
+        );
+        assert!(
+            matches!(&response.output[1], OutputItem::Other(kind) if kind == "web_search_call")
+        );
+        assert!(
+            matches!(&response.output[2], OutputItem::FunctionCall(call) if call.call_id == "call_1")
+        );
+        assert_eq!(response.output_text, "It is sunny.");
+        assert_eq!(response.usage.unwrap().reasoning_tokens, Some(64));
+    }
+}
@@ -0,0 +1,369 @@
+//! Validation of model output against the JSON schema of a `json-schema` response format.
+//!
+//! Covers the keywords structured outputs accept: types, `enum` and `const`, object
+//! properties, arrays, `anyOf`/`allOf`/`oneOf`, local `$ref`s and the numeric, string
+//! and array bounds. `pattern` and `format` are not checked.
+
+use std::{cell::RefCell, collections::HashSet};
+
+use serde_json::{Map, Value};
+
+/// Checks `instance` against `schema`, describing the first mismatch found.
+pub(crate) fn validate(schema: &Value, instance: &Value) -> Result<(), String> {
+    Validator {
+        root: schema,
+        active_refs: RefCell::default(),
+    }
+    .validate(schema, instance, "$")
+}
+
+struct Validator<'a> {
+    root: &'a Value,
+    /// `$ref`s being followed, with the instance path each was entered at. Meeting one again
+    /// means the schema loops without descending into the instance.
+    active_refs: RefCell<HashSet<(&'a str, String)>>,
+}
+
+impl<'a> Validator<'a> {
+    fn validate(&self, schema: &'a Value, instance: &Value, path: &str) -> Result<(), String> {
+        let schema = match schema {
+            Value::Bool(true) => return Ok(()),
+            Value::Bool(false) => return Err(format!("{path}: no value is allowed here")),
+            Value::Object(schema) => schema,
+            _ => return Err(format!("{path}: schema must be an object or a boolean")),
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            self.validate_ref(reference, instance, path)?;
+        }
+        if let Some(types) = schema.get("type") {
+            check_type(types, instance, path)?;
+        }
+        if let Some(values) = schema.get("enum")
+            && !values
+                .as_array()
+                .is_some_and(|values| values.contains(instance))
+        {
+            return Err(format!("{path}: {instance} is not one of {values}"));
+        }
+        if let Some(value) = schema.get("const")
+            && value != instance
+        {
+            return Err(format!("{path}: expected {value}, got {instance}"));
+        }
+        self.check_combinators(schema, instance, path)?;
+
+        match instance {
+            Value::Object(object) => self.check_object(schema, object, path),
+            Value::Array(items) => self.check_array(schema, items, path),
+            Value::String(string) => check_string(schema, string, path),
+            Value::Number(_) => check_number(schema, instance.as_f64().unwrap_or_default(), path),
+            Value::Bool(_) | Value::Null => Ok(()),
+        }
+    }
+
+    fn validate_ref(&self, reference: &'a str, instance: &Value, path: &str) -> Result<(), String> {
+        let key = (reference, path.to_string());
+        if !self.active_refs.borrow_mut().insert(key.clone()) {
+            return Err(format!("{path}: $ref {reference} refers back to itself"));
+        }
+        let result = self
+            .resolve(reference)
+            .and_then(|schema| self.validate(schema, instance, path));
+        self.active_refs.borrow_mut().remove(&key);
+        result
+    }
+
+    /// Resolves a `$ref` pointing into the root schema, such as `#/$defs/step`.
+    fn resolve(&self, reference: &str) -> Result<&'a Value, String> {
+        let pointer = reference
+            .strip_prefix('#')
+            .ok_or_else(|| format!("unsupported $ref {reference}, only local references are"))?;
+        self.root
+            .pointer(pointer)
+            .ok_or_else(|| format!("$ref {reference} does not point into the schema"))
+    }
+
+    fn check_combinators(
+        &self,
+        schema: &'a Map<String, Value>,
+        instance: &Value,
+        path: &str,
+    ) -> Result<(), String> {
+        if let Some(schemas) = schema.get("allOf").and_then(Value::as_array) {
+            for schema in schemas {
+                self.validate(schema, instance, path)?;
+            }
+        }
+        if let Some(schemas) = schema.get("oneOf").and_then(Value::as_array) {
+            let matches = schemas
+                .iter()
+                .filter(|schema| self.validate(schema, instance, path).is_ok())
+                .count();
+            if matches != 1 {
+                return Err(format!(
+                    "{path}: matches {matches} schemas of oneOf instead of exactly one"
+                ));
+            }
+        }
+        if let Some(schemas) = schema.get("anyOf").and_then(Value::as_array) {
+            let mut errors = Vec::new();
+            for schema in schemas {
+                match self.validate(schema, instance, path) {
+                    Ok(()) => return Ok(()),
+                    Err(error) => errors.push(error),
+                }
+            }
+            return Err(format!(
+                "{path}: matches none of anyOf ({})",
+                errors.join("; ")
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_object(
+        &self,
+        schema: &'a Map<String, Value>,
+        object: &Map<String, Value>,
+        path: &str,
+    ) -> Result<(), String> {
+        let properties = schema.get("properties").and_then(Value::as_object);
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(name) {
+                    return Err(format!("{path}: missing required property {name:?}"));
+                }
+            }
+        }
+        for (name, value) in object {
+            let path = format!("{path}.{name}");
+            match properties.and_then(|properties| properties.get(name)) {
+                Some(schema) => self.validate(schema, value, &path)?,
+                None => match schema.get("additionalProperties") {
+                    Some(Value::Bool(false)) => {
+                        return Err(format!("{path}: property is not allowed"));
+                    }
+                    Some(additional @ Value::Object(_)) => {
+                        self.validate(additional, value, &path)?;
+                    }
+                    _ => {}
+                },
+            }
+        }
+        Ok(())
+    }
+
+    fn check_array(
+        &self,
+        schema: &'a Map<String, Value>,
+        items: &[Value],
+        path: &str,
+    ) -> Result<(), String> {
+        check_bounds(schema, "minItems", "maxItems", items.len(), "items", path)?;
+        if let Some(item_schema) = schema.get("items") {
+            for (index, item) in items.iter().enumerate() {
+                self.validate(item_schema, item, &format!("{path}[{index}]"))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_type(types: &Value, instance: &Value, path: &str) -> Result<(), String> {
+    let matches = |name: &Value| match name.as_str() {
+        Some("object") => instance.is_object(),
+        Some("array") => instance.is_array(),
+        Some("string") => instance.is_string(),
+        Some("number") => instance.is_number(),
+        Some("integer") => instance
+            .as_f64()
+            .is_some_and(|number| number.fract() == 0.0),
+        Some("boolean") => instance.is_boolean(),
+        Some("null") => instance.is_null(),
+        _ => false,
+    };
+    let matched = match types {
+        Value::Array(types) => types.iter().any(matches),
+        name => matches(name),
+    };
+    if matched {
+        Ok(())
+    } else {
+        Err(format!("{path}: expected {types}, got {instance}"))
+    }
+}
+
+fn check_string(schema: &Map<String, Value>, string: &str, path: &str) -> Result<(), String> {
+    check_bounds(
+        schema,
+        "minLength",
+        "maxLength",
+        string.chars().count(),
+        "characters",
+        path,
+    )
+}
+
+fn check_number(schema: &Map<String, Value>, number: f64, path: &str) -> Result<(), String> {
+    let bound = |keyword| schema.get(keyword).and_then(Value::as_f64);
+    if let Some(minimum) = bound("minimum").filter(|&minimum| number < minimum) {
+        return Err(format!(
+            "{path}: {number} is less than the minimum of {minimum}"
+        ));
+    }
+    if let Some(maximum) = bound("maximum").filter(|&maximum| number > maximum) {
+        return Err(format!(
+            "{path}: {number} is greater than the maximum of {maximum}"
+        ));
+    }
+    if let Some(minimum) = bound("exclusiveMinimum").filter(|&minimum| number <= minimum) {
+        return Err(format!("{path}: {number} is not greater than {minimum}"));
+    }
+    if let Some(maximum) = bound("exclusiveMaximum").filter(|&maximum| number >= maximum) {
+        return Err(format!("{path}: {number} is not less than {maximum}"));
+    }
+    if let Some(factor) = bound("multipleOf").filter(|&factor| factor > 0.0) {
+        let quotient = number / factor;
+        if (quotient - quotient.round()).abs() > 1e-9 {
+            return Err(format!("{path}: {number} is not a multiple of {factor}"));
+        }
+    }
+    Ok(())
+}
+
+fn check_bounds(
+    schema: &Map<String, Value>,
+    min_keyword: &str,
+    max_keyword: &str,
+    len: usize,
+    unit: &str,
+    path: &str,
+) -> Result<(), String> {
+    let bound = |keyword| schema.get(keyword).and_then(Value::as_u64);
+    if let Some(min) = bound(min_keyword).filter(|&min| (len as u64) < min) {
+        return Err(format!("{path}: has {len} {unit}, fewer than {min}"));
+    }
+    if let Some(max) = bound(max_keyword).filter(|&max| (len as u64) > max) {
+        return Err(format!("{path}: has {len} {unit}, more than {max}"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn person() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": {"type": "string", "minLength": 1},
+                "age": {"type": ["integer", "null"], "minimum": 0},
+                "role": {"enum": ["admin", "user"]},
+                "tags": {"type": "array", "items": {"$ref": "#/$defs/tag"}, "maxItems": 2}
+            },
+            "required": ["name", "age", "role", "tags"],
+            "additionalProperties": false,
+            "$defs": {
+                "tag": {"type": "string"}
+            }
+        })
+    }
+
+    #[test]
+    fn test_valid_instance() {
+        let instance = json!({"name": "Ada", "age": 36, "role": "admin", "tags": ["math"]});
+        assert_eq!(validate(&person(), &instance), Ok(()));
+        let instance = json!({"name": "Ada", "age": null, "role": "user", "tags": []});
+        assert_eq!(validate(&person(), &instance), Ok(()));
+    }
+
+    #[test]
+    fn test_violations() {
+        let cases = [
+            (
+                json!({"name": "Ada", "age": 36, "role": "admin"}),
+                "$: missing required property \"tags\"",
+            ),
+            (
+                json!({"name": "", "age": 36, "role": "admin", "tags": []}),
+                "$.name: has 0 characters, fewer than 1",
+            ),
+            (
+                json!({"name": "Ada", "age": 3.5, "role": "admin", "tags": []}),
+                "$.age: expected [\"integer\",\"null\"], got 3.5",
+            ),
+            (
+                json!({"name": "Ada", "age": -1, "role": "admin", "tags": []}),
+                "$.age: -1 is less than the minimum of 0",
+            ),
+            (
+                json!({"name": "Ada", "age": 36, "role": "root", "tags": []}),
+                "$.role: \"root\" is not one of [\"admin\",\"user\"]",
+            ),
+            (
+                json!({"name": "Ada", "age": 36, "role": "admin", "tags": [1]}),
+                "$.tags[0]: expected \"string\", got 1",
+            ),
+            (
+                json!({"name": "Ada", "age": 36, "role": "admin", "tags": [], "extra": true}),
+                "$.extra: property is not allowed",
+            ),
+        ];
+        for (instance, error) in cases {
+            assert_eq!(validate(&person(), &instance), Err(error.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_any_of() {
+        let schema = json!({
+            "anyOf": [
+                {"type": "string"},
+                {"type": "object", "properties": {"id": {"type": "integer"}}, "required": ["id"]}
+            ]
+        });
+        assert!(validate(&schema, &json!("text")).is_ok());
+        assert!(validate(&schema, &json!({"id": 1})).is_ok());
+        assert!(validate(&schema, &json!(true)).is_err());
+    }
+
+    #[test]
+    fn test_recursive_ref() {
+        let schema = json!({
+            "$ref": "#/$defs/node",
+            "$defs": {
+                "node": {
+                    "type": "object",
+                    "properties": {"children": {"type": "array", "items": {"$ref": "#/$defs/node"}}}
+                }
+            }
+        });
+        let instance = json!({"children": [{"children": []}, {}]});
+        assert_eq!(validate(&schema, &instance), Ok(()));
+    }
+
+    #[test]
+    fn test_self_referential_ref() {
+        assert_eq!(
+            validate(&json!({"$ref": "#"}), &json!(1)),
+            Err("$: $ref # refers back to itself".to_string())
+        );
+        let schema = json!({
+            "$ref": "#/$defs/loop",
+            "$defs": {
+                "loop": {"anyOf": [{"allOf": [{"$ref": "#/$defs/loop"}]}]}
+            }
+        });
+        assert!(validate(&schema, &json!({})).is_err());
+    }
+
+    #[test]
+    fn test_unresolvable_ref() {
+        let schema = json!({"$ref": "#/$defs/missing"});
+        assert!(validate(&schema, &json!(1)).is_err());
+    }
+}
@@ -0,0 +1,209 @@
+use std::cell::RefCell;
+
+use http_core::EventStream;
+use serde::Deserialize;
+
+use crate::{
+    OpenAIErrorBody, OpenAIUsage,
+    bindings::{
+        exports::wassemble::openai::api::GuestChatStream,
+        wassemble::openai::types::{ChatChunk, ErrorKind, OpenaiError, ToolCallDelta},
+    },
+    request_id,
+};
+
+/// Data of the event OpenAI sends after the last chunk.
+const DONE: &str = "[DONE]";
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OpenAIStreamEvent {
+    // errors after the response started can only be reported inside the stream
+    Error(OpenAIErrorBody),
+    Chunk(OpenAIChatChunk),
+}
+
+#[derive(Deserialize)]
+struct OpenAIChatChunk {
+    id: String,
+    model: String,
+    #[serde(default)]
+    choices: Vec<OpenAIChunkChoice>,
+    usage: Option<OpenAIUsage>,
+}
+
+#[derive(Deserialize)]
+struct OpenAIChunkChoice {
+    #[serde(default)]
+    index: u32,
+    #[serde(default)]
+    delta: OpenAIDelta,
+    finish_reason: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+struct OpenAIDelta {
+    role: Option<String>,
+    content: Option<String>,
+    tool_calls: Option<Vec<OpenAIToolCallDelta>>,
+}
+
+#[derive(Deserialize)]
+struct OpenAIToolCallDelta {
+    index: u32,
+    id: Option<String>,
+    #[serde(default)]
+    function: OpenAIFunctionDelta,
+}
+
+#[derive(Default, Deserialize)]
+struct OpenAIFunctionDelta {
+    name: Option<String>,
+    arguments: Option<String>,
+}
+
+impl From<OpenAIChatChunk> for ChatChunk {
+    fn from(chunk: OpenAIChatChunk) -> Self {
+        // the final usage chunk has no choices
+        let choice = chunk.choices.into_iter().next();
+        let (index, delta, finish_reason) = match choice {
+            Some(choice) => (choice.index, choice.delta, choice.finish_reason),
+            None => (0, OpenAIDelta::default(), None),
+        };
+        ChatChunk {
+            id: chunk.id,
+            model: chunk.model,
+            index,
+            role: delta.role,
+            content: delta.content.unwrap_or_default(),
+            tool_calls: delta
+                .tool_calls
+                .unwrap_or_default()
+                .into_iter()
+                .map(|call| ToolCallDelta {
+                    index: call.index,
+                    id: call.id,
+                    name: call.function.name,
+                    arguments: call.function.arguments.unwrap_or_default(),
+                })
+                .collect(),
+            finish_reason,
+            usage: chunk.usage.map(Into::into),
+        }
+    }
+}
+
+/// Backs the `chat-stream` resource, parsing chunks as their events arrive.
+pub(crate) struct OpenAIChatStream {
+    // dropped once the stream is finished, which also closes the connection
+    events: RefCell<Option<EventStream>>,
+}
+
+impl OpenAIChatStream {
+    pub(crate) fn new(events: EventStream) -> Self {
+        Self {
+            events: RefCell::new(Some(events)),
+        }
+    }
+}
+
+impl GuestChatStream for OpenAIChatStream {
+    fn next_chunk(&self) -> Result<Option<ChatChunk>, OpenaiError> {
+        let mut events = self.events.borrow_mut();
+        let Some(stream) = events.as_mut() else {
+            return Ok(None);
+        };
+        // every error here is raised for the stream's response
+        let request_id = request_id(stream.headers());
+        let tag = |kind: ErrorKind| OpenaiError {
+            kind,
+            request_id: request_id.clone(),
+        };
+        let event = match stream.next_event().map_err(|e| tag(e.into()))? {
+            Some(event) if event.data != DONE => event,
+            _ => {
+                *events = None;
+                return Ok(None);
+            }
+        };
+
+        let chunk = serde_json::from_str(&event.data).map_err(|e| {
+            tag(ErrorKind::JsonParseError(format!(
+                "Failed to parse stream chunk: {e}"
+            )))
+        })?;
+        match chunk {
+            OpenAIStreamEvent::Chunk(chunk) => Ok(Some(chunk.into())),
+            OpenAIStreamEvent::Error(body) => {
+                *events = None;
+                Err(tag(body.error.into_error(200, None)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(data: &str) -> ChatChunk {
+        match serde_json::from_str(data).unwrap() {
+            OpenAIStreamEvent::Chunk(chunk) => chunk.into(),
+            OpenAIStreamEvent::Error(_) => panic!("unexpected error event"),
+        }
+    }
+
+    #[test]
+    fn test_content_chunks() {
+        let first = parse(
+            r#"{"id":"chatcmpl-1","object":"chat.completion.chunk","model":"gpt-4.1","choices":[{"index":0,"delta":{"role":"assistant","content":"","refusal":null},"finish_reason":null}]}"#,
+        );
+        assert_eq!(first.role.as_deref(), Some("assistant"));
+        assert_eq!(first.content, "");
+        assert!(first.finish_reason.is_none());
+
+        let last = parse(
+            r#"{"id":"chatcmpl-1","object":"chat.completion.chunk","model":"gpt-4.1","choices":[{"index":0,"delta":{},"finish_reason":"stop"}],"usage":null}"#,
+        );
+        assert_eq!(last.content, "");
+        assert_eq!(last.finish_reason.as_deref(), Some("stop"));
+        assert!(last.usage.is_none());
+    }
+
+    #[test]
+    fn test_tool_call_chunks() {
+        let first = parse(
+            r#"{"id":"chatcmpl-1","model":"gpt-4.1","choices":[{"index":0,"delta":{"role":"assistant","content":null,"tool_calls":[{"index":0,"id":"call_abc","type":"function","function":{"name":"get_weather","arguments":""}}]},"finish_reason":null}]}"#,
+        );
+        assert_eq!(first.tool_calls.len(), 1);
+        assert_eq!(first.tool_calls[0].id.as_deref(), Some("call_abc"));
+        assert_eq!(first.tool_calls[0].name.as_deref(), Some("get_weather"));
+
+        let next = parse(
+            r#"{"id":"chatcmpl-1","model":"gpt-4.1","choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"function":{"arguments":"{\"city\":"}}]},"finish_reason":null}]}"#,
+        );
+        assert_eq!(next.tool_calls[0].index, 0);
+        assert!(next.tool_calls[0].id.is_none());
+        assert_eq!(next.tool_calls[0].arguments, "{\"city\":");
+    }
+
+    #[test]
+    fn test_usage_chunk() {
+        let chunk = parse(
+            r#"{"id":"chatcmpl-1","model":"gpt-4.1","choices":[],"usage":{"prompt_tokens":9,"completion_tokens":12,"total_tokens":21}}"#,
+        );
+        let usage = chunk.usage.unwrap();
+        assert_eq!(usage.prompt_tokens, 9);
+        assert_eq!(usage.total_tokens, 21);
+        assert!(chunk.finish_reason.is_none());
+    }
+
+    #[test]
+    fn test_error_event() {
+        let event: OpenAIStreamEvent = serde_json::from_str(
+            r#"{"error":{"message":"The server had an error while processing your request.","type":"server_error","param":null,"code":null}}"#,
+        )
+        .unwrap();
+        assert!(matches!(event, OpenAIStreamEvent::Error(_)));
+    }
+}